@@ -0,0 +1,70 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use sql_query_builder as sql;
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+fn in_list(c: &mut Criterion) {
+  let mut group = c.benchmark_group("in_list");
+  for size in SIZES {
+    let ids = (0..size).map(|id| id.to_string()).collect::<Vec<_>>().join(", ");
+    let select = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause(format!("id in ({ids})"))
+      .where_clause("active = true");
+
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(BenchmarkId::from_parameter(size), &select, |b, select| {
+      b.iter(|| select.as_string())
+    });
+  }
+  group.finish();
+}
+
+fn where_conditions(c: &mut Criterion) {
+  let mut group = c.benchmark_group("where_conditions");
+  for size in SIZES {
+    let select = (0..size).fold(sql::Select::new().select("*").from("users"), |select, id| {
+      select.or(format!("id = {id}"))
+    });
+
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(BenchmarkId::from_parameter(size), &select, |b, select| {
+      b.iter(|| select.as_string())
+    });
+  }
+  group.finish();
+}
+
+fn values_rows(c: &mut Criterion) {
+  let mut group = c.benchmark_group("values_rows");
+  for size in SIZES {
+    let insert = (0..size).fold(sql::Insert::new().insert_into("users (id, login)"), |insert, id| {
+      insert.values(format!("({id}, 'login_{id}')"))
+    });
+
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(BenchmarkId::from_parameter(size), &insert, |b, insert| {
+      b.iter(|| insert.as_string())
+    });
+  }
+  group.finish();
+}
+
+fn bound_values_rows(c: &mut Criterion) {
+  let mut group = c.benchmark_group("bound_values_rows");
+  for size in SIZES {
+    let insert = (0..size).fold(sql::Insert::new().insert_into("users (id, login)"), |insert, id| {
+      insert.values_bind("(?, ?)", (id as i64, format!("login_{id}")))
+    });
+
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(BenchmarkId::from_parameter(size), &insert, |b, insert| {
+      b.iter(|| insert.build())
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, in_list, where_conditions, values_rows, bound_values_rows);
+criterion_main!(benches);
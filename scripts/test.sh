@@ -1,4 +1,6 @@
 #!/bin/sh
 
 cargo test
-cargo test --features postgresql --test feature_flag_postgresql
+cargo test --features postgresql --test feature_flag_postgresql --test dialect_spec --test bind_params_spec --test placeholder_renumbering_spec
+cargo test --features sqlite --test feature_flag_sqlite --test dialect_spec --test bind_params_spec --test placeholder_renumbering_spec
+cargo test --features mysql --test feature_flag_mysql --test dialect_spec --test select_builder_api_spec
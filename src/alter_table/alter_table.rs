@@ -0,0 +1,593 @@
+use crate::{
+  behavior::{defined, Concat},
+  duplicate::DuplicatePolicy,
+  error::BuildError,
+  fmt::{self, FormatOptions},
+  sink::{self, IoSink, Sink},
+  sql::Sql,
+  structure::{AlterTable, AlterTableClause},
+};
+
+impl<'a> AlterTable<'a> {
+  /// Adds a column to the table, consecutive calls accumulates the columns in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .add_column("active boolean not null default true")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN age int, ADD COLUMN active boolean not null default true";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int, ADD COLUMN active boolean not null default true
+  /// ```
+  pub fn add_column<'s>(mut self, column_definition: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(AlterTableClause::AddColumn),
+      &mut self._add_column,
+      column_definition.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// Adds a constraint to the table, rendered after the column actions
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_constraint("users_login_key unique (login)")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users ADD CONSTRAINT users_login_key unique (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users ADD CONSTRAINT users_login_key unique (login)
+  /// ```
+  pub fn add_constraint<'s>(mut self, constraint: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(AlterTableClause::AddConstraint),
+      &mut self._add_constraint,
+      constraint.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// Changes the definition of a column, consecutive calls accumulates the changes in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column("login set not null")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users ALTER COLUMN login set not null";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users ALTER COLUMN login set not null
+  /// ```
+  pub fn alter_column<'s>(mut self, expression: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(AlterTableClause::AlterColumn),
+      &mut self._alter_column,
+      expression.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The alter table clause, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users
+  /// ```
+  pub fn alter_table(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._alter_table = table_name.into().trim();
+    self
+  }
+
+  /// Gets the current state of the AlterTable and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("age")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN age
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts).0
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .clear(sql::AlterTableClause::AddColumn)
+  ///   .drop_column("age")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP COLUMN age";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN age
+  /// ```
+  pub fn clear(mut self, clause: AlterTableClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      AlterTableClause::AddColumn => self._add_column.clear(),
+      AlterTableClause::AddConstraint => self._add_constraint.clear(),
+      AlterTableClause::AlterColumn => self._alter_column.clear(),
+      AlterTableClause::AlterTable => self._alter_table = "".into(),
+      AlterTableClause::DropColumn => self._drop_column.clear(),
+      AlterTableClause::DropConstraint => self._drop_constraint.clear(),
+      AlterTableClause::Rename => self._rename = "".into(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
+  }
+
+  /// Prints the current state of the AlterTable into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .drop_column("birthday")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users
+  ///   ADD COLUMN age int,
+  ///   DROP COLUMN birthday
+  /// ```
+  pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [AlterTable] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::multiline();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Drops a column of the table, consecutive calls accumulates the columns in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("age")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP COLUMN age";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN age
+  /// ```
+  pub fn drop_column<'s>(mut self, column_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(AlterTableClause::DropColumn),
+      &mut self._drop_column,
+      column_name.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// Drops a constraint of the table, rendered after the added constraints
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_constraint("users_login_key")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP CONSTRAINT users_login_key";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users DROP CONSTRAINT users_login_key
+  /// ```
+  pub fn drop_constraint<'s>(mut self, constraint_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(AlterTableClause::DropConstraint),
+      &mut self._drop_constraint,
+      constraint_name.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Dedupe)
+  ///   .alter_table("users")
+  ///   .drop_column("age")
+  ///   .drop_column("age")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP COLUMN age";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN age
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn alter_table(table: &str) -> sql::AlterTable<'static> {
+  ///   sql::AlterTable::new()
+  ///     .alter_table(table)
+  ///     .add_column("age int")
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = alter_table(&String::from("users")).as_string();
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN age int";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int
+  /// ```
+  pub fn into_owned(self) -> AlterTable<'static> {
+    AlterTable {
+      _add_column: self._add_column,
+      _add_constraint: self._add_constraint,
+      _alter_column: self._alter_column,
+      _alter_table: self._alter_table.into_owned(),
+      _drop_column: self._drop_column,
+      _drop_constraint: self._drop_constraint,
+      _duplicates: self._duplicates,
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+      _rename: self._rename.into_owned(),
+    }
+  }
+
+  /// Create AlterTable's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the AlterTable into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [AlterTable] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN age int\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* add the age column */";
+  /// let query = sql::AlterTable::new()
+  ///   .raw(raw_query)
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* add the age column */ ALTER TABLE users ADD COLUMN age int";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* add the age column */ ALTER TABLE users ADD COLUMN age int
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause, the raw SQL of the actions is rendered as another action
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "owner to admin";
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("age")
+  ///   .raw_after(sql::AlterTableClause::DropColumn, raw)
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users DROP COLUMN age, owner to admin";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users DROP COLUMN age, owner to admin
+  /// ```
+  pub fn raw_after(mut self, clause: AlterTableClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause, the raw SQL of the actions is rendered as another action
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "alter table if exists";
+  /// let query = sql::AlterTable::new()
+  ///   .raw_before(sql::AlterTableClause::AlterTable, raw)
+  ///   .add_column("age int")
+  ///   .as_string();
+  ///
+  /// # let expected = "alter table if exists ADD COLUMN age int";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// alter table if exists ADD COLUMN age int
+  /// ```
+  pub fn raw_before(mut self, clause: AlterTableClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Renames the table or one of its columns, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename("column login to username")
+  ///   .as_string();
+  ///
+  /// # let expected = "ALTER TABLE users RENAME column login to username";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users RENAME column login to username
+  /// ```
+  pub fn rename(mut self, expression: impl Into<Sql<'a>>) -> Self {
+    self._rename = expression.into().trim();
+    self
+  }
+
+  /// The table of the alter table clause, `None` when the clause is not defined
+  pub fn target_table(&self) -> Option<&Sql<'a>> {
+    defined(&self._alter_table)
+  }
+
+  /// Gets the current state of the [AlterTable] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .drop_column("name")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "alter table users\n  add column age int,\n  drop column name";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// alter table users
+  ///   add column age int,
+  ///   drop column name
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [AlterTable] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [AlterTable::validate] for the checks
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "ALTER TABLE users ADD COLUMN age int";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    Ok(self.as_string())
+  }
+
+  /// Checks the current state of the [AlterTable] without rendering it, returns an error naming the clause
+  /// that makes the query invalid, like an action without the alter table clause.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::AlterTable::new()
+  ///   .add_column("age int")
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::MissingClause {
+  ///     clause: sql::Clause::AlterTable(sql::AlterTableClause::AlterTable),
+  ///     required_by: sql::Clause::AlterTable(sql::AlterTableClause::AddColumn),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()
+  }
+}
+
+impl std::fmt::Display for AlterTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for AlterTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
+  }
+}
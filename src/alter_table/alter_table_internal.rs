@@ -0,0 +1,145 @@
+use crate::{
+  behavior::{
+    append, check_required, concat_raw_before_after, raw_defines, raw_queries, trim_end_from, Concat, ConcatMethods,
+  },
+  error::BuildError,
+  fmt,
+  sql::Sql,
+  structure::{AlterTable, AlterTableClause},
+};
+use std::borrow::Cow;
+
+impl<'a> ConcatMethods<'a, AlterTableClause> for AlterTable<'_> {}
+
+impl Concat for AlterTable<'_> {
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_alter_table(query, fmts);
+    self.concat_actions(query, fmts);
+
+    trim_end_from(query, start);
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let actions = [
+      (self._add_column.is_empty() == false, AlterTableClause::AddColumn),
+      (self._drop_column.is_empty() == false, AlterTableClause::DropColumn),
+      (self._alter_column.is_empty() == false, AlterTableClause::AlterColumn),
+      (
+        self._add_constraint.is_empty() == false,
+        AlterTableClause::AddConstraint,
+      ),
+      (
+        self._drop_constraint.is_empty() == false,
+        AlterTableClause::DropConstraint,
+      ),
+      (self._rename.is_empty() == false, AlterTableClause::Rename),
+    ];
+    let has_alter_table = self._alter_table.is_empty() == false
+      || raw_defines(
+        &self._raw,
+        &self._raw_before,
+        &self._raw_after,
+        AlterTableClause::AlterTable,
+        &["ALTER TABLE"],
+      );
+    check_required((has_alter_table, AlterTableClause::AlterTable), actions)
+  }
+}
+
+impl AlterTable<'_> {
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(AlterTableClause::AddColumn), &self._add_column)?;
+    duplicates.check(Some(AlterTableClause::AddConstraint), &self._add_constraint)?;
+    duplicates.check(Some(AlterTableClause::AlterColumn), &self._alter_column)?;
+    duplicates.check(Some(AlterTableClause::DropColumn), &self._drop_column)?;
+    duplicates.check(Some(AlterTableClause::DropConstraint), &self._drop_constraint)?;
+    duplicates.check(None, &self._raw)
+  }
+
+  /// The actions are rendered separated by comma in the order of the clauses, the raw SQL
+  /// before and after each of them is rendered as another action of the list
+  fn concat_actions(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter {
+      comma,
+      indent,
+      lb,
+      space,
+      ..
+    } = fmts;
+    fn texts<'s>(items: &'s [Sql<'static>]) -> Vec<&'s Sql<'s>> {
+      items.iter().collect()
+    }
+    let rename = match self._rename.is_empty() {
+      true => vec![],
+      false => vec![&self._rename],
+    };
+    let clauses = [
+      (AlterTableClause::AddColumn, "ADD COLUMN", texts(&self._add_column)),
+      (AlterTableClause::DropColumn, "DROP COLUMN", texts(&self._drop_column)),
+      (
+        AlterTableClause::AlterColumn,
+        "ALTER COLUMN",
+        texts(&self._alter_column),
+      ),
+      (
+        AlterTableClause::AddConstraint,
+        "ADD CONSTRAINT",
+        texts(&self._add_constraint),
+      ),
+      (
+        AlterTableClause::DropConstraint,
+        "DROP CONSTRAINT",
+        texts(&self._drop_constraint),
+      ),
+      (AlterTableClause::Rename, "RENAME", rename),
+    ];
+    let actions = clauses.iter().flat_map(|(clause, keyword, items)| {
+      let raw_before = raw_queries(&self._raw_before, clause).map(|raw| ("", "", Cow::Owned(Sql::from(raw))));
+      let raw_after = raw_queries(&self._raw_after, clause).map(|raw| ("", "", Cow::Owned(Sql::from(raw))));
+      let items = items.iter().map(|item| (*keyword, *space, Cow::Borrowed(*item)));
+      raw_before.chain(items).chain(raw_after)
+    });
+
+    let separator = if lb.is_empty() {
+      comma.to_string()
+    } else {
+      format!("{}{lb}{indent}", comma.trim_end())
+    };
+    let mut actions = actions.peekable();
+    if actions.peek().is_none() {
+      return;
+    }
+    query.push_str(indent);
+    for (index, (keyword, space, action)) in actions.enumerate() {
+      if index > 0 {
+        query.push_str(&separator);
+      }
+      append(query, &[keyword, space]);
+      query.append(&action);
+    }
+    append(query, &[space, lb]);
+  }
+
+  fn concat_alter_table(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      AlterTableClause::AlterTable,
+      |query| {
+        if self._alter_table.is_empty() == false {
+          append(query, &["ALTER TABLE", space]);
+          query.append(&self._alter_table);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
+}
@@ -1,148 +1,413 @@
-use crate::fmt;
-use std::cmp::PartialEq;
+use crate::{bind, condition::Condition, dialect::Dialect, error::BuildError, fmt, join::Join, parse, sql::Sql};
+use std::{borrow::Cow, cmp::PartialEq};
 
-pub fn push_unique<T: Eq>(list: &mut Vec<T>, value: T) {
-  let prev_item = list.iter().find(|&item| *item == value);
-  if prev_item.is_none() {
-    list.push(value);
+/// The value of a clause defined by a single text, `None` when the clause is not defined
+pub fn defined<'s, 'a>(sql: &'s Sql<'a>) -> Option<&'s Sql<'a>> {
+  (sql.is_empty() == false).then_some(sql)
+}
+
+/// Trims the text, a borrowed text is kept borrowed and an owned text is reallocated only when it has spaces to trim
+pub fn trim<'a>(text: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
+  match text.into() {
+    Cow::Borrowed(text) => Cow::Borrowed(text.trim()),
+    Cow::Owned(text) if text.trim().len() == text.len() => Cow::Owned(text),
+    Cow::Owned(text) => Cow::Owned(text.trim().to_owned()),
   }
 }
 
-pub fn raw_queries<'a, Clause: PartialEq>(raw_list: &'a Vec<(Clause, String)>, clause: &'a Clause) -> Vec<String> {
+/// Converts a text into a text that doesn't borrow, used by the `into_owned` methods of the builders
+pub fn owned(text: Cow<'_, str>) -> Cow<'static, str> {
+  Cow::Owned(text.into_owned())
+}
+
+/// Groups the conditions of the list and joins the group with the new condition by the `OR` operator
+pub fn push_or(list: &mut Vec<Condition>, condition: Condition) {
+  let conditions = std::mem::take(list);
+  let condition = match <[Condition; 1]>::try_from(conditions) {
+    Ok([Condition::Or(mut items)]) => {
+      items.push(condition);
+      Condition::Or(items)
+    }
+    Ok([first]) => Condition::or([first, condition]),
+    Err(conditions) if conditions.is_empty() => condition,
+    Err(conditions) => Condition::or([Condition::And(conditions), condition]),
+  };
+  list.push(condition);
+}
+
+pub fn raw_queries<'a, Clause: PartialEq>(
+  raw_list: &'a [(Clause, String)],
+  clause: &'a Clause,
+) -> impl Iterator<Item = &'a str> {
   raw_list
     .iter()
-    .filter(|item| item.0 == *clause)
-    .map(|item| item.1.clone())
-    .collect::<Vec<_>>()
+    .filter(move |item| item.0 == *clause)
+    .map(|item| item.1.as_str())
+}
+
+/// The items of a clause appended to the query, the texts and the [Sql] with its fragments
+pub trait Render {
+  fn render_into(&self, query: &mut Sql<'static>);
+}
+
+impl Render for str {
+  fn render_into(&self, query: &mut Sql<'static>) {
+    query.push_str(self);
+  }
+}
+
+impl Render for String {
+  fn render_into(&self, query: &mut Sql<'static>) {
+    query.push_str(self);
+  }
+}
+
+impl Render for Sql<'_> {
+  fn render_into(&self, query: &mut Sql<'static>) {
+    query.append(self);
+  }
+}
+
+impl<T: Render + ?Sized> Render for &T {
+  fn render_into(&self, query: &mut Sql<'static>) {
+    (**self).render_into(query);
+  }
+}
+
+/// Appends the parts at the end of the query
+pub fn append(query: &mut Sql<'static>, parts: &[&str]) {
+  for part in parts {
+    query.push_str(part);
+  }
+}
+
+/// Appends the items separated by the separator, returns the number of items appended
+pub fn append_list<T: Render>(query: &mut Sql<'static>, items: impl IntoIterator<Item = T>, separator: &str) -> usize {
+  let mut count = 0;
+  for item in items {
+    if count > 0 {
+      query.push_str(separator);
+    }
+    item.render_into(query);
+    count += 1;
+  }
+  count
 }
 
-/// Represents all statements that can be used in the with method
-pub trait WithQuery: Concat {}
+/// Removes the whitespace at the end of the query written after the byte position `start`
+pub fn trim_end_from(query: &mut Sql<'static>, start: usize) {
+  query.trim_end_from(start);
+}
 
-pub trait Concat {
-  fn concat(&self, fmts: &fmt::Formatter) -> String;
+/// Represents all statements that can be used in the with method, the queries are shared between threads
+/// so a builder with the with clause can be held across an `.await` or stored in a `static`
+pub trait WithQuery: Concat {
+  /// The query tagged by the builder that constructs it, used to serialize the with clause
+  #[cfg(feature = "serde")]
+  fn tagged(&self) -> crate::serialize::TaggedQuery<'_>;
 }
 
+pub trait Concat: Send + Sync {
+  /// Writes the query at the end of the buffer without trailing whitespace, the clauses and the composed
+  /// builders are written in a single pass into the same buffer
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter);
+
+  fn concat(&self, fmts: &fmt::Formatter) -> Sql<'static> {
+    let mut query = Sql::default();
+    self.concat_into(&mut query, fmts);
+    query
+  }
+
+  /// Renders the query replacing the bound values by numbered placeholders,
+  /// the values are returned in the same order of its placeholders
+  fn render(&self, fmts: &fmt::Formatter) -> (String, Vec<bind::Value>) {
+    let bind::Rendered { sql, values, .. } = bind::resolve(self.concat(fmts), fmts.dialect);
+    (sql, values)
+  }
+
+  /// The same as [Concat::render], returns an error when the placeholders of a `*_bind` method
+  /// don't match the values bound to it
+  fn try_render(&self, fmts: &fmt::Formatter) -> Result<(String, Vec<bind::Value>), BuildError> {
+    let bind::Rendered { sql, values, error, .. } = bind::resolve(self.concat(fmts), fmts.dialect);
+    match error {
+      Some(error) => Err(error),
+      None => Ok((sql, values)),
+    }
+  }
+
+  /// Renders the query and returns how the positional placeholders of the composed builders were renumbered
+  fn placeholders(&self, fmts: &fmt::Formatter) -> bind::PlaceholderMap {
+    bind::resolve(self.concat(fmts), fmts.dialect).placeholders
+  }
+
+  /// Returns an error naming the first clause defined in the query, or in the composed builders,
+  /// that the dialect doesn't support
+  fn check_dialect(&self, _dialect: Dialect) -> Result<(), BuildError> {
+    Ok(())
+  }
+
+  /// Returns an error naming the first clause of the query, or of the composed builders,
+  /// that makes the query invalid regardless of the dialect
+  fn check_query(&self) -> Result<(), BuildError> {
+    Ok(())
+  }
+}
+
+/// A named query of the with clause
+pub type WithItem<'a> = (Cow<'a, str>, std::sync::Arc<dyn WithQuery>);
+
+/// Converts the names of the with clause into names that don't borrow
+pub fn owned_with(items: Vec<WithItem<'_>>) -> Vec<WithItem<'static>> {
+  items.into_iter().map(|(name, query)| (owned(name), query)).collect()
+}
+
+/// Checks a list of `(is_defined, clause, supported_by)`, the clauses not listed are supported by all dialects
+pub fn check_clauses<Clause: Into<crate::structure::Clause>>(
+  dialect: Dialect,
+  clauses: impl IntoIterator<Item = (bool, Clause, &'static [Dialect])>,
+) -> Result<(), BuildError> {
+  let unsupported = clauses
+    .into_iter()
+    .find(|(is_defined, _, supported_by)| *is_defined && supported_by.contains(&dialect) == false);
+
+  match unsupported {
+    Some((_, clause, _)) => Err(BuildError::unsupported_clause(clause, dialect)),
+    None => Ok(()),
+  }
+}
+
+/// Checks that the value of the clause is a non negative integer, a placeholder like `$1` and `?` or a bound value,
+/// an empty value means the clause isn't defined
+pub fn check_number<Clause: Into<crate::structure::Clause>>(clause: Clause, value: &Sql) -> Result<(), BuildError> {
+  let is_integer = |text: &str| text.is_empty() == false && text.bytes().all(|byte| byte.is_ascii_digit());
+  let text = value.text();
+  let is_valid = match text.strip_prefix('$') {
+    _ if value.has_fragments() => value.is_bound_value(),
+    Some(number) => is_integer(number),
+    None => text.is_empty() || text == "?" || is_integer(text),
+  };
+
+  match is_valid {
+    true => Ok(()),
+    false => Err(BuildError::invalid_value(clause, &value.to_string())),
+  }
+}
+
+/// Returns true when the raw texts of the query can stand for the clause, a text added before or after the clause
+/// or a text with one of the `keywords` outside quotes, comments and parentheses, without keywords any raw text
+/// can stand for the clause
+pub fn raw_defines<Clause: PartialEq>(
+  raw: &[String],
+  raw_before: &[(Clause, String)],
+  raw_after: &[(Clause, String)],
+  clause: Clause,
+  keywords: &[&'static str],
+) -> bool {
+  let is_attached = raw_before
+    .iter()
+    .chain(raw_after)
+    .any(|(raw_clause, _)| *raw_clause == clause);
+  let mut texts = raw
+    .iter()
+    .chain(raw_before.iter().chain(raw_after).map(|(_, text)| text));
+  let has_keyword = |text: &String| match parse::split_clauses(text, keywords) {
+    Ok((_, clauses)) => clauses.is_empty() == false,
+    Err(_) => true,
+  };
+
+  match keywords.is_empty() {
+    true => texts.next().is_some(),
+    false => is_attached || texts.any(has_keyword),
+  }
+}
+
+/// Checks that the `required` clause is defined when any clause of the list `(is_defined, clause)` is defined,
+/// the error names the first clause defined
+pub fn check_required<Clause: Into<crate::structure::Clause>>(
+  required: (bool, Clause),
+  clauses: impl IntoIterator<Item = (bool, Clause)>,
+) -> Result<(), BuildError> {
+  let (is_required_defined, required) = required;
+  if is_required_defined {
+    return Ok(());
+  }
+
+  match clauses.into_iter().find(|(is_defined, _)| *is_defined) {
+    Some((_, clause)) => Err(BuildError::missing_clause(required, clause)),
+    None => Ok(()),
+  }
+}
+
+/// Appends the clause surrounded by the raw SQL defined to be before and after it
 pub fn concat_raw_before_after<Clause: PartialEq>(
-  items_before: &Vec<(Clause, String)>,
-  items_after: &Vec<(Clause, String)>,
-  query: String,
+  items_before: &[(Clause, String)],
+  items_after: &[(Clause, String)],
+  query: &mut Sql<'static>,
   fmts: &fmt::Formatter,
   clause: Clause,
-  sql: String,
-) -> String {
+  concat_clause: impl FnOnce(&mut Sql<'static>),
+) {
   let fmt::Formatter { space, .. } = fmts;
-  let raw_before = raw_queries(items_before, &clause).join(space);
-  let raw_after = raw_queries(items_after, &clause).join(space);
-  let space_after = if raw_after.is_empty() == false { space } else { "" };
-  let space_before = if raw_before.is_empty() == false { space } else { "" };
-
-  format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}")
+  if append_list(query, raw_queries(items_before, &clause), space) > 0 {
+    query.push_str(space);
+  }
+  concat_clause(query);
+  if append_list(query, raw_queries(items_after, &clause), space) > 0 {
+    query.push_str(space);
+  }
 }
 
 pub trait ConcatMethods<'a, Clause: PartialEq> {
   fn concat_from(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &[Sql<'static>],
+  ) {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let tables = items.join(comma);
-      format!("FROM{space}{tables}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if items.is_empty() == false {
+        append(query, &["FROM", space]);
+        append_list(query, items, comma);
+        append(query, &[space, lb]);
+      }
+    });
   }
 
-  fn concat_raw(&self, query: String, fmts: &fmt::Formatter, items: &Vec<String>) -> String {
+  fn concat_raw(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter, items: &[String]) {
     if items.is_empty() {
-      return query;
+      return;
     }
     let fmt::Formatter { lb, space, .. } = fmts;
-    let raw_sql = items.join(space);
+    append_list(query, items, space);
+    append(query, &[space, lb]);
+  }
+
+  fn concat_join(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    items: &[Join],
+  ) {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      for join in items {
+        join.concat_into(query, fmts);
+        append(query, &[space, lb]);
+      }
+    });
+  }
+
+  fn concat_limit(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    limit: &Sql,
+  ) {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if limit.is_empty() == false {
+        append(query, &["LIMIT", space]);
+        query.append(limit);
+        append(query, &[space, lb]);
+      }
+    });
+  }
 
-    format!("{query}{raw_sql}{space}{lb}")
+  fn concat_order_by(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    items: &[Sql<'static>],
+  ) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if items.is_empty() == false {
+        append(query, &["ORDER BY", space]);
+        append_list(query, items, comma);
+        append(query, &[space, lb]);
+      }
+    });
   }
 
-  #[cfg(feature = "postgresql")]
   fn concat_returning(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &[Sql<'static>],
+  ) {
     let fmt::Formatter { lb, space, comma, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let output_names = items.join(comma);
-      format!("RETURNING{space}{output_names}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if items.is_empty() == false {
+        append(query, &["RETURNING", space]);
+        append_list(query, items, comma);
+        append(query, &[space, lb]);
+      }
+    });
   }
 
   fn concat_values(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &[Sql<'static>],
+  ) {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let sep = format!("{comma}{lb}");
-      let values = items.join(&sep);
-      format!("VALUES{space}{lb}{values}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if items.is_empty() == false {
+        append(query, &["VALUES", space, lb]);
+        append_list(query, items, &format!("{comma}{lb}"));
+        append(query, &[space, lb]);
+      }
+    });
   }
 
   fn concat_where(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
-  ) -> String {
+    items: &[Condition],
+  ) {
     let fmt::Formatter { lb, space, indent, .. } = fmts;
-    let sql = if items.is_empty() == false {
-      let conditions = items.join(&format!("{space}{lb}{indent}AND{space}"));
-      format!("WHERE{space}{conditions}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if items.is_empty() == false {
+        append(query, &["WHERE", space]);
+        Condition::concat_list(query, items, &format!("{space}{lb}{indent}AND{space}"));
+        append(query, &[space, lb]);
+      }
+    });
   }
 
-  #[cfg(feature = "postgresql")]
   fn concat_with(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
-    query: String,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: &mut Sql<'static>,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<(&'a str, std::sync::Arc<dyn WithQuery>)>,
-  ) -> String {
+    items: &[WithItem<'a>],
+  ) {
     let fmt::Formatter {
       comma,
       lb,
@@ -150,28 +415,28 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
       space,
       ..
     } = fmts;
-    let sql = if items.is_empty() == false {
-      let with = items.iter().fold("".to_owned(), |acc, item| {
-        let (name, query) = item;
-        let inner_lb = format!("{lb}{indent}");
-        let inner_fmts = fmt::Formatter {
-          comma,
-          lb: inner_lb.as_str(),
-          indent,
-          space,
-          ..*fmts
-        };
-        let query_string = query.concat(&inner_fmts);
-
-        format!("{acc}{name}{space}AS{space}({lb}{indent}{query_string}{lb}){comma}{lb}")
-      });
-      let with = &with[..with.len() - comma.len() - lb.len()];
-
-      format!("WITH{space}{lb}{with}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
-    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, |query| {
+      if items.is_empty() {
+        return;
+      }
+      let inner_lb = format!("{lb}{indent}");
+      let inner_fmts = fmt::Formatter {
+        comma,
+        lb: inner_lb.as_str(),
+        indent,
+        space,
+        ..*fmts
+      };
+      append(query, &["WITH", space, lb]);
+      for (index, (name, with_query)) in items.iter().enumerate() {
+        if index > 0 {
+          append(query, &[comma, lb]);
+        }
+        append(query, &[name, space, "AS", space, "(", lb, indent]);
+        bind::scope(query, |query| with_query.concat_into(query, &inner_fmts));
+        append(query, &[lb, ")"]);
+      }
+      append(query, &[space, lb]);
+    });
   }
 }
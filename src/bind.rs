@@ -0,0 +1,380 @@
+use crate::{
+  dialect::Dialect,
+  error::BuildError,
+  sql::{Fragment, Segment, Sql},
+};
+
+/// A typed value bound to a placeholder through the `*_bind` methods of the builders
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let (query, values) = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause_bind("login = ?", "foo")
+///   .build_with(sql::Dialect::Postgres)
+///   .unwrap();
+///
+/// assert_eq!(query, "SELECT * FROM users WHERE login = $1");
+/// assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  Text(String),
+  Bytes(Vec<u8>),
+}
+
+macro_rules! value_from {
+  ($variant:ident, $($ty:ty),+) => {
+    $(
+      impl From<$ty> for Value {
+        fn from(value: $ty) -> Self {
+          Value::$variant(value.into())
+        }
+      }
+    )+
+  };
+}
+
+value_from!(Bool, bool);
+value_from!(Int, i8, i16, i32, i64, u8, u16, u32);
+value_from!(Float, f32, f64);
+value_from!(Text, &str, String, std::borrow::Cow<'_, str>);
+value_from!(Bytes, &[u8], Vec<u8>);
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => value.into(),
+      None => Value::Null,
+    }
+  }
+}
+
+/// Represents the values that can be bound to the `?` placeholders of a `*_bind` method,
+/// a single value binds one placeholder, arrays, vectors of [Value] and tuples binds one placeholder per item
+pub trait Params {
+  fn into_values(self) -> Vec<Value>;
+}
+
+impl<T: Into<Value>> Params for T {
+  fn into_values(self) -> Vec<Value> {
+    vec![self.into()]
+  }
+}
+
+impl<T: Into<Value>, const N: usize> Params for [T; N] {
+  fn into_values(self) -> Vec<Value> {
+    self.into_iter().map(Into::into).collect()
+  }
+}
+
+impl Params for Vec<Value> {
+  fn into_values(self) -> Vec<Value> {
+    self
+  }
+}
+
+macro_rules! params_tuple {
+  ($($name:ident),+) => {
+    impl<$($name: Into<Value>),+> Params for ($($name,)+) {
+      #[allow(non_snake_case)]
+      fn into_values(self) -> Vec<Value> {
+        let ($($name,)+) = self;
+        vec![$($name.into()),+]
+      }
+    }
+  };
+}
+
+params_tuple!(A, B);
+params_tuple!(A, B, C);
+params_tuple!(A, B, C, D);
+params_tuple!(A, B, C, D, E);
+params_tuple!(A, B, C, D, E, F);
+
+/// Replaces each `?` of the text (ignoring the ones inside quotes) by the bound value, a `??` is written as a single `?`,
+/// like the operators of the Postgres jsonb type. The identifiers and literals of the sql are kept as they are,
+/// a number of placeholders different from the number of values is reported by the methods that return a [BuildError]
+pub(crate) fn bind<'s>(sql: impl Into<Sql<'s>>, params: impl Params) -> Sql<'static> {
+  let sql = sql.into().trim();
+  let values = params.into_values();
+  let values_len = values.len();
+  let mut values = values.into_iter();
+  let mut placeholders = 0;
+  let mut quote = None;
+  let mut output = Sql::new(String::with_capacity(sql.len()));
+
+  for segment in sql.segments() {
+    let text = match segment {
+      Segment::Text(text) => text,
+      Segment::Fragment(fragment) => {
+        output.push_fragment(fragment.clone());
+        continue;
+      }
+    };
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+      match (c, quote) {
+        ('\'' | '"', None) => quote = Some(c),
+        (_, Some(q)) if q == c => quote = None,
+        ('?', None) if chars.peek() == Some(&'?') => {
+          chars.next();
+        }
+        ('?', None) => {
+          placeholders += 1;
+          if let Some(value) = values.next() {
+            output.push_fragment(Fragment::Value(value));
+            continue;
+          }
+        }
+        _ => {}
+      }
+      output.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+  }
+
+  if placeholders != values_len {
+    output.push_fragment(Fragment::Unbound {
+      sql: sql.to_string(),
+      placeholders,
+      values: values_len,
+    });
+  }
+
+  output
+}
+
+/// Describes how the positional placeholders (`$1`, `$2`, ...) written by hand in each composed builder
+/// were renumbered in the final query.
+///
+/// The scope `0` is the builder being rendered, its placeholders keeps the original numbers,
+/// the builders composed with `with`, `union`, `except`, `intersect` and [Insert::select](crate::Insert::select)
+/// are the next scopes in the order they appear in the query, and its placeholders are shifted by
+/// the number of placeholders of the previous scopes
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let insert = sql::Insert::new()
+///   .insert_into("users (login, name)")
+///   .select(sql::Select::new().select("login, $2").from("users_bk").where_clause("login = $1"))
+///   .on_conflict("(login) do update set name = $1");
+///
+/// let map = insert.placeholder_map();
+///
+/// assert_eq!(insert.as_string(), "\
+///   INSERT INTO users (login, name) \
+///   SELECT login, $3 FROM users_bk WHERE login = $2 \
+///   ON CONFLICT (login) do update set name = $1\
+/// ");
+/// assert_eq!(map.get(1, 1), Some(2));
+/// assert_eq!(map.arrange(vec![vec!["Foo"], vec!["foo", "Bar"]]), vec!["Foo", "foo", "Bar"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PlaceholderMap {
+  counts: Vec<usize>,
+}
+
+impl PlaceholderMap {
+  /// Returns the number of scopes, the rendered builder plus each composed builder
+  pub fn scopes(&self) -> usize {
+    self.counts.len()
+  }
+
+  /// Returns the number of positional placeholders of the scope, the highest `$n` written on it
+  pub fn count(&self, scope: usize) -> usize {
+    self.counts.get(scope).copied().unwrap_or(0)
+  }
+
+  /// Returns the final number of the placeholder `$original` written in the scope
+  pub fn get(&self, scope: usize, original: usize) -> Option<usize> {
+    if original == 0 || original > self.count(scope) {
+      return None;
+    }
+    Some(self.offset(scope) + original)
+  }
+
+  /// Joins the arguments of each scope into a single list in the order of the final placeholders,
+  /// `args[scope][index]` is the argument of the placeholder `$index + 1` written in the scope
+  ///
+  /// # Panics
+  /// Panics if the number of argument lists or the number of arguments of a scope
+  /// differs from the placeholders found
+  pub fn arrange<T>(&self, args: Vec<Vec<T>>) -> Vec<T> {
+    assert!(
+      args.len() == self.scopes(),
+      "expected the arguments of {} scope(s) but found {}",
+      self.scopes(),
+      args.len()
+    );
+    args
+      .into_iter()
+      .enumerate()
+      .flat_map(|(scope, scope_args)| {
+        assert!(
+          scope_args.len() == self.count(scope),
+          "the scope {scope} has {} placeholder(s) but {} argument(s) was given",
+          self.count(scope),
+          scope_args.len()
+        );
+        scope_args
+      })
+      .collect()
+  }
+
+  fn offset(&self, scope: usize) -> usize {
+    self.counts.iter().take(scope).sum()
+  }
+}
+
+pub(crate) struct Rendered {
+  pub sql: String,
+  pub values: Vec<Value>,
+  pub placeholders: PlaceholderMap,
+  pub error: Option<BuildError>,
+}
+
+/// Wraps the query of a composed builder, the positional placeholders inside it will be shifted
+/// by the number of placeholders of the scopes that comes before it
+pub(crate) fn scope(query: &mut Sql<'static>, concat: impl FnOnce(&mut Sql<'static>)) {
+  query.push_fragment(Fragment::ScopeStart);
+  concat(query);
+  query.push_fragment(Fragment::ScopeEnd);
+}
+
+/// Renders the final query, the positional placeholders of the composed builders are shifted,
+/// then the bound values are replaced by numbered placeholders after the positional ones,
+/// in the order they appear in the query. A query without fragments is returned without being copied
+pub(crate) fn resolve(query: Sql<'_>, dialect: Option<Dialect>) -> Rendered {
+  if query.has_fragments() == false {
+    let counts = vec![positional_count(query.text())];
+    return Rendered {
+      sql: query.into_text(),
+      values: vec![],
+      placeholders: PlaceholderMap { counts },
+      error: None,
+    };
+  }
+
+  let segments = query.segments();
+
+  let mut counts = vec![0];
+  let mut stack = vec![0];
+  for segment in segments.iter() {
+    match segment {
+      Segment::Fragment(Fragment::ScopeStart) => {
+        counts.push(0);
+        stack.push(counts.len() - 1);
+      }
+      Segment::Fragment(Fragment::ScopeEnd) => {
+        stack.pop();
+      }
+      Segment::Text(text) => {
+        let current = *stack.last().unwrap_or(&0);
+        counts[current] = counts[current].max(positional_count(text));
+      }
+      _ => {}
+    }
+  }
+  let placeholders = PlaceholderMap { counts };
+
+  let rendered_dialect = dialect.unwrap_or_else(Dialect::of_features);
+  let mut sql = String::with_capacity(query.len());
+  let mut values = vec![];
+  let mut error = None;
+  let mut next_scope = 0;
+  let mut stack = vec![0];
+  let bound_offset = placeholders.offset(placeholders.scopes());
+  for segment in segments {
+    match segment {
+      Segment::Text(text) => {
+        let current = *stack.last().unwrap_or(&0);
+        let mut last = 0;
+        for (start, end, number) in positionals(text) {
+          sql.push_str(&text[last..start]);
+          sql.push_str(&format!("${}", placeholders.offset(current) + number));
+          last = end;
+        }
+        sql.push_str(&text[last..]);
+      }
+      Segment::Fragment(Fragment::Value(value)) => {
+        values.push(value.clone());
+        sql.push_str(&rendered_dialect.placeholder(bound_offset + values.len()));
+      }
+      Segment::Fragment(Fragment::Ident(ident)) => sql.push_str(&ident.to_sql(rendered_dialect)),
+      Segment::Fragment(Fragment::Literal(literal)) => sql.push_str(&literal.to_sql(rendered_dialect)),
+      Segment::Fragment(Fragment::ScopeStart) => {
+        next_scope += 1;
+        stack.push(next_scope);
+      }
+      Segment::Fragment(Fragment::ScopeEnd) => {
+        stack.pop();
+      }
+      Segment::Fragment(Fragment::Unbound {
+        sql,
+        placeholders,
+        values,
+      }) => {
+        if error.is_none() {
+          error = Some(BuildError::BindMismatch {
+            sql: sql.clone(),
+            placeholders: *placeholders,
+            values: *values,
+          });
+        }
+      }
+    }
+  }
+
+  Rendered {
+    sql,
+    values,
+    placeholders,
+    error,
+  }
+}
+
+fn positional_count(text: &str) -> usize {
+  positionals(text).map(|(_, _, number)| number).max().unwrap_or(0)
+}
+
+/// Iterates over the positional placeholders found outside quotes returning its byte range and number
+fn positionals(text: &str) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+  let bytes = text.as_bytes();
+  let mut quote = None;
+  let mut index = 0;
+
+  std::iter::from_fn(move || {
+    while index < bytes.len() {
+      let byte = bytes[index];
+      index += 1;
+      match (byte, quote) {
+        (b'\'' | b'"', None) => quote = Some(byte),
+        (_, Some(q)) if q == byte => quote = None,
+        (b'$', None) => {
+          let start = index - 1;
+          let is_identifier = start > 0 && (bytes[start - 1].is_ascii_alphanumeric() || bytes[start - 1] == b'_');
+          let digits = bytes[index..].iter().take_while(|b| b.is_ascii_digit()).count();
+          if is_identifier || digits == 0 {
+            continue;
+          }
+          index += digits;
+          if let Ok(number) = text[start + 1..index].parse() {
+            return Some((start, index, number));
+          }
+        }
+        _ => {}
+      }
+    }
+    None
+  })
+}
@@ -0,0 +1,191 @@
+use crate::sql::Sql;
+
+/// A condition of the where and having clauses, the conditions can be nested with [Condition::and],
+/// [Condition::or] and [Condition::not] and are parenthesized when rendered to keep the same meaning
+///
+/// The `&str` and `String` arguments of the `where_clause`, `and`, `or` and `having` methods are converted
+/// into a raw condition
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+/// use sql::Condition;
+///
+/// let query = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause(Condition::or(["login = 'foo'", "login = 'bar'"]))
+///   .and(Condition::not("deleted"))
+///   .as_string();
+///
+/// assert_eq!(query, "SELECT * FROM users WHERE (login = 'foo' OR login = 'bar') AND NOT deleted");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Condition {
+  And(Vec<Condition>),
+  Or(Vec<Condition>),
+  Not(Box<Condition>),
+  Raw(Sql<'static>),
+}
+
+impl Condition {
+  /// Joins the conditions with the `AND` operator
+  pub fn and<C: Into<Condition>>(conditions: impl IntoIterator<Item = C>) -> Self {
+    Self::And(conditions.into_iter().map(Into::into).collect())
+  }
+
+  /// Joins the conditions with the `OR` operator
+  pub fn or<C: Into<Condition>>(conditions: impl IntoIterator<Item = C>) -> Self {
+    Self::Or(conditions.into_iter().map(Into::into).collect())
+  }
+
+  /// Negates the condition with the `NOT` operator
+  pub fn not(condition: impl Into<Condition>) -> Self {
+    Self::Not(Box::new(condition.into()))
+  }
+
+  /// A condition written in raw SQL, the text is trimmed
+  pub fn raw<'a>(condition: impl Into<Sql<'a>>) -> Self {
+    Self::Raw(condition.into().trim().into_owned())
+  }
+
+  /// Returns true when the condition renders nothing, like a raw empty string or an `And` without items
+  pub fn is_empty(&self) -> bool {
+    match self {
+      Self::And(items) | Self::Or(items) => items.iter().all(Condition::is_empty),
+      Self::Not(condition) => condition.is_empty(),
+      Self::Raw(text) => text.is_empty(),
+    }
+  }
+
+  /// Appends a list of conditions joined by the separator, each condition is parenthesized
+  /// when its operator has a lower precedence than `AND`
+  pub(crate) fn concat_list(query: &mut Sql<'static>, conditions: &[Condition], separator: &str) {
+    let mut conditions = conditions.iter().filter(|item| item.is_empty() == false).peekable();
+    let Some(first) = conditions.next() else {
+      return;
+    };
+    if conditions.peek().is_none() {
+      return first.concat_into(query);
+    }
+    first.concat_operand(query, Precedence::And);
+    for condition in conditions {
+      query.push_str(separator);
+      condition.concat_operand(query, Precedence::And);
+    }
+  }
+
+  fn concat_into(&self, query: &mut Sql<'static>) {
+    match self {
+      Self::And(items) => Self::concat_operands(query, items, " AND ", Precedence::And),
+      Self::Or(items) => Self::concat_operands(query, items, " OR ", Precedence::Or),
+      Self::Not(condition) => {
+        query.push_str("NOT ");
+        condition.concat_operand(query, Precedence::Atom);
+      }
+      Self::Raw(sql) => query.append(sql),
+    }
+  }
+
+  fn concat_operands(query: &mut Sql<'static>, items: &[Condition], separator: &str, precedence: Precedence) {
+    let items = items.iter().filter(|item| item.is_empty() == false);
+    for (index, item) in items.enumerate() {
+      if index > 0 {
+        query.push_str(separator);
+      }
+      item.concat_operand(query, precedence);
+    }
+  }
+
+  /// Appends the condition as operand of an operator, adding parentheses when the condition binds less tightly
+  fn concat_operand(&self, query: &mut Sql<'static>, min_precedence: Precedence) {
+    if self.precedence() < min_precedence {
+      query.push_str("(");
+      self.concat_into(query);
+      query.push_str(")");
+    } else {
+      self.concat_into(query);
+    }
+  }
+
+  fn precedence(&self) -> Precedence {
+    match self {
+      Self::And(items) | Self::Or(items) => {
+        let mut items = items.iter().filter(|item| item.is_empty() == false);
+        match (items.next(), items.next(), self) {
+          (Some(item), None, _) => item.precedence(),
+          (Some(_), Some(_), Self::And(_)) => Precedence::And,
+          (Some(_), Some(_), _) => Precedence::Or,
+          (None, ..) => Precedence::Atom,
+        }
+      }
+      Self::Not(_) => Precedence::Not,
+      Self::Raw(sql) => raw_precedence(&sql.to_string()),
+    }
+  }
+}
+
+impl From<&str> for Condition {
+  fn from(condition: &str) -> Self {
+    Self::raw(condition)
+  }
+}
+
+impl From<String> for Condition {
+  fn from(condition: String) -> Self {
+    Self::raw(condition)
+  }
+}
+
+impl From<Sql<'_>> for Condition {
+  fn from(condition: Sql<'_>) -> Self {
+    Self::raw(condition)
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+enum Precedence {
+  Or,
+  And,
+  Not,
+  Atom,
+}
+
+/// The precedence of a raw condition is given by its lowest operator found outside quotes and parentheses,
+/// a condition with spaces is an expression and is parenthesized by the `NOT` operator
+fn raw_precedence(text: &str) -> Precedence {
+  let mut precedence = Precedence::Atom;
+  let mut quote = None;
+  let mut depth = 0;
+  let mut word = String::new();
+
+  for char in text.chars().chain([';']) {
+    if quote.is_none() && (char.is_alphanumeric() || char == '_') {
+      word.push(char);
+      continue;
+    }
+    if depth == 0 {
+      let operator = match word.to_ascii_uppercase().as_str() {
+        "OR" => Precedence::Or,
+        "AND" => Precedence::And,
+        _ if char.is_whitespace() && quote.is_none() => Precedence::Not,
+        _ => Precedence::Atom,
+      };
+      if operator < precedence {
+        precedence = operator;
+      }
+    }
+    word.clear();
+    match (char, quote) {
+      (_, Some(q)) if q == char => quote = None,
+      (_, Some(_)) => {}
+      ('\'' | '"' | '`', None) => quote = Some(char),
+      ('(', None) => depth += 1,
+      (')', None) => depth -= 1,
+      _ => {}
+    }
+  }
+
+  precedence
+}
@@ -0,0 +1,717 @@
+use crate::{
+  behavior::{defined, Concat},
+  condition::Condition,
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::BuildError,
+  fmt::{self, FormatOptions},
+  sink::{self, IoSink, Sink},
+  sql::Sql,
+  structure::{CreateIndex, CreateIndexClause},
+};
+
+impl<'a> CreateIndex<'a> {
+  /// The same as [where_clause](CreateIndex::where_clause) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("deleted_at is null")
+  ///   .and("active = true");
+  /// ```
+  pub fn and(mut self, condition: impl Into<Condition>) -> Self {
+    self = self.where_clause(condition);
+    self
+  }
+
+  /// Gets the current state of the CreateIndex and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts).0
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("active = true")
+  ///   .clear(sql::CreateIndexClause::Where)
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn clear(mut self, clause: CreateIndexClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      CreateIndexClause::Column => self._column.clear(),
+      CreateIndexClause::CreateIndex => {
+        self._create_index = "".into();
+        self._if_not_exists = false;
+        self._unique = false;
+        self._concurrently = false;
+      }
+      CreateIndexClause::On => self._on = "".into(),
+      CreateIndexClause::Where => self._where.clear(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
+  }
+
+  /// Defines a column or expression of the index, consecutive calls accumulates the columns in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .on("users")
+  ///   .column("lower(login)")
+  ///   .column("created_at desc")
+  ///   .as_string();
+  ///
+  /// # let expected = "ON users (lower(login), created_at desc)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ON users (lower(login), created_at desc)
+  /// ```
+  pub fn column<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(CreateIndexClause::Column),
+      &mut self._column,
+      column.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The columns or expressions of the index in the order they were added
+  pub fn column_items(&self) -> &[Sql<'static>] {
+    &self._column
+  }
+
+  /// Builds the index without locking out writes on the table, supported by Postgres
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .concurrently()
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX CONCURRENTLY users_login_idx ON users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX CONCURRENTLY users_login_idx ON users (login)
+  /// ```
+  pub fn concurrently(mut self) -> Self {
+    self._concurrently = true;
+    self
+  }
+
+  /// The create index clause, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx
+  /// ```
+  pub fn create_index(mut self, index_name: impl Into<Sql<'a>>) -> Self {
+    self._create_index = index_name.into().trim();
+    self._if_not_exists = false;
+    self
+  }
+
+  /// The create index clause with the `IF NOT EXISTS` modifier, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index_if_not_exists("users_login_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX IF NOT EXISTS users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX IF NOT EXISTS users_login_idx
+  /// ```
+  pub fn create_index_if_not_exists(mut self, index_name: impl Into<Sql<'a>>) -> Self {
+    self._create_index = index_name.into().trim();
+    self._if_not_exists = true;
+    self
+  }
+
+  /// Prints the current state of the CreateIndex into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("deleted_at is null")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx
+  /// ON users (login)
+  /// WHERE deleted_at is null
+  /// ```
+  pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [CreateIndex] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::multiline();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Dedupe)
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// The name of the index, `None` when the clause is not defined
+  pub fn index_name(&self) -> Option<&Sql<'a>> {
+    defined(&self._create_index)
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn create_index(table: &str) -> sql::CreateIndex<'static> {
+  ///   sql::CreateIndex::new()
+  ///     .create_index("users_login_idx")
+  ///     .on(table)
+  ///     .column("login")
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = create_index(&String::from("users")).as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn into_owned(self) -> CreateIndex<'static> {
+    CreateIndex {
+      _column: self._column,
+      _concurrently: self._concurrently,
+      _create_index: self._create_index.into_owned(),
+      _duplicates: self._duplicates,
+      _if_not_exists: self._if_not_exists,
+      _on: self._on.into_owned(),
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+      _unique: self._unique,
+      _where: self._where,
+    }
+  }
+
+  /// Create CreateIndex's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The table of the index, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn on(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._on = table_name.into().trim();
+    self
+  }
+
+  /// Prints the current state of the CreateIndex into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [CreateIndex] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* speeds up the login */";
+  /// let query = sql::CreateIndex::new()
+  ///   .raw(raw_query)
+  ///   .create_index("users_login_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* speeds up the login */ CREATE INDEX users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* speeds up the login */ CREATE INDEX users_login_idx
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "using gin";
+  /// let query = sql::CreateIndex::new()
+  ///   .on("users")
+  ///   .raw_after(sql::CreateIndexClause::On, raw)
+  ///   .column("tags")
+  ///   .as_string();
+  ///
+  /// # let expected = "ON users using gin (tags)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ON users using gin (tags)
+  /// ```
+  pub fn raw_after(mut self, clause: CreateIndexClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "create index";
+  /// let query = sql::CreateIndex::new()
+  ///   .raw_before(sql::CreateIndexClause::On, raw)
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "create index ON users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// create index ON users (login)
+  /// ```
+  pub fn raw_before(mut self, clause: CreateIndexClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Removes the conditions of the where clause for which the predicate returns `true`,
+  /// the conditions joined by the `or` method are a single [Condition::Or]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("active = true")
+  ///   .remove_where(|_| true)
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn remove_where(mut self, mut predicate: impl FnMut(&Condition) -> bool) -> Self {
+    self._where.retain(|condition| predicate(condition) == false);
+    self._duplicates.clear(Some(CreateIndexClause::Where));
+    self
+  }
+
+  /// Replaces the values of the where clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("active = true")
+  ///   .replace_where("deleted = false")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login) WHERE deleted = false";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login) WHERE deleted = false
+  /// ```
+  pub fn replace_where(mut self, condition: impl Into<Condition>) -> Self {
+    self._where.clear();
+    self._duplicates.clear(Some(CreateIndexClause::Where));
+    self.where_clause(condition)
+  }
+
+  /// The table of the on clause, `None` when the clause is not defined
+  pub fn target_table(&self) -> Option<&Sql<'a>> {
+    defined(&self._on)
+  }
+
+  /// Gets the current state of the [CreateIndex] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .concurrently()
+  ///   .on("users")
+  ///   .column("login")
+  ///   .to_sql(sql::Dialect::Postgres)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "CREATE INDEX CONCURRENTLY users_login_idx ON users (login)");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    Ok(self.render(&fmts).0)
+  }
+
+  /// Gets the current state of the [CreateIndex] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "create index users_login_idx\non users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// create index users_login_idx
+  /// on users (login)
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [CreateIndex] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [CreateIndex::validate] for the checks
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    Ok(self.as_string())
+  }
+
+  /// Defines an unique index
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_key")
+  ///   .unique()
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE UNIQUE INDEX users_login_key ON users (login)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE UNIQUE INDEX users_login_key ON users (login)
+  /// ```
+  pub fn unique(mut self) -> Self {
+    self._unique = true;
+    self
+  }
+
+  /// Checks the current state of the [CreateIndex] without rendering it, returns an error naming the clause
+  /// that makes the query invalid, like an index without the table of the on clause or without the columns.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .column("login")
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::MissingClause {
+  ///     clause: sql::Clause::CreateIndex(sql::CreateIndexClause::On),
+  ///     required_by: sql::Clause::CreateIndex(sql::CreateIndexClause::CreateIndex),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()
+  }
+
+  /// The where clause of a partial index
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("deleted_at is null")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE INDEX users_login_idx ON users (login) WHERE deleted_at is null";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login) WHERE deleted_at is null
+  /// ```
+  pub fn where_clause(mut self, condition: impl Into<Condition>) -> Self {
+    self
+      ._duplicates
+      .push(Some(CreateIndexClause::Where), &mut self._where, condition.into());
+    self
+  }
+
+  /// The conditions of the where clause in the order they were added
+  pub fn where_conditions(&self) -> &[Condition] {
+    &self._where
+  }
+}
+
+impl std::fmt::Display for CreateIndex<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateIndex<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
+  }
+}
@@ -0,0 +1,151 @@
+use crate::{
+  behavior::{
+    append, append_list, check_clauses, check_required, concat_raw_before_after, raw_defines, trim_end_from, Concat,
+    ConcatMethods,
+  },
+  dialect::Dialect,
+  error::BuildError,
+  fmt,
+  sql::Sql,
+  structure::{CreateIndex, CreateIndexClause},
+};
+
+impl<'a> ConcatMethods<'a, CreateIndexClause> for CreateIndex<'_> {}
+
+impl Concat for CreateIndex<'_> {
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_create_index(query, fmts);
+    self.concat_on(query, fmts);
+    self.concat_column(query, fmts);
+    self.concat_where(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::Where,
+      &self._where,
+    );
+
+    trim_end_from(query, start);
+  }
+
+  fn check_dialect(&self, dialect: Dialect) -> Result<(), BuildError> {
+    check_clauses(
+      dialect,
+      [(
+        self._concurrently,
+        CreateIndexClause::CreateIndex,
+        &[Dialect::Postgres][..],
+      )],
+    )
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let has_raw = |clause, keywords: &[&'static str]| {
+      raw_defines(&self._raw, &self._raw_before, &self._raw_after, clause, keywords)
+    };
+    let has_create_index =
+      self._create_index.is_empty() == false || has_raw(CreateIndexClause::CreateIndex, &["CREATE"]);
+    let has_on = self._on.is_empty() == false || has_raw(CreateIndexClause::On, &["ON"]);
+    let has_column = self._column.is_empty() == false || has_raw(CreateIndexClause::Column, &[]);
+    check_required(
+      (has_create_index, CreateIndexClause::CreateIndex),
+      [
+        (self._on.is_empty() == false, CreateIndexClause::On),
+        (self._column.is_empty() == false, CreateIndexClause::Column),
+        (self._where.is_empty() == false, CreateIndexClause::Where),
+      ],
+    )?;
+    check_required(
+      (has_on, CreateIndexClause::On),
+      [
+        (self._create_index.is_empty() == false, CreateIndexClause::CreateIndex),
+        (self._column.is_empty() == false, CreateIndexClause::Column),
+      ],
+    )?;
+    check_required(
+      (has_column, CreateIndexClause::Column),
+      [(self._on.is_empty() == false, CreateIndexClause::On)],
+    )
+  }
+}
+
+impl CreateIndex<'_> {
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(CreateIndexClause::Column), &self._column)?;
+    duplicates.check(Some(CreateIndexClause::Where), &self._where)?;
+    duplicates.check(None, &self._raw)
+  }
+
+  fn concat_column(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::Column,
+      |query| {
+        if self._column.is_empty() == false {
+          query.push_str("(");
+          append_list(query, &self._column, comma);
+          append(query, &[")", space, lb]);
+        }
+      },
+    );
+  }
+
+  fn concat_create_index(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::CreateIndex,
+      |query| {
+        if self._create_index.is_empty() {
+          return;
+        }
+        append(query, &["CREATE", space]);
+        if self._unique {
+          append(query, &["UNIQUE", space]);
+        }
+        append(query, &["INDEX", space]);
+        if self._concurrently {
+          append(query, &["CONCURRENTLY", space]);
+        }
+        if self._if_not_exists {
+          append(query, &["IF NOT EXISTS", space]);
+        }
+        query.append(&self._create_index);
+        append(query, &[space, lb]);
+      },
+    );
+  }
+
+  /// The columns are rendered in the same line of the table
+  fn concat_on(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::On,
+      |query| {
+        if self._on.is_empty() == false {
+          let lb = if self._column.is_empty() { lb } else { "" };
+          append(query, &["ON", space]);
+          query.append(&self._on);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
+}
@@ -0,0 +1,584 @@
+use crate::{
+  behavior::{defined, Concat},
+  duplicate::DuplicatePolicy,
+  error::BuildError,
+  fmt::{self, FormatOptions},
+  sink::{self, IoSink, Sink},
+  sql::Sql,
+  structure::{CreateTable, CreateTableClause},
+};
+
+impl<'a> CreateTable<'a> {
+  /// Gets the current state of the CreateTable and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .column("login varchar(40) not null")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE TABLE users (id serial primary key, login varchar(40) not null)
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts).0
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .primary_key("(id)")
+  ///   .clear(sql::CreateTableClause::PrimaryKey)
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TABLE users (id serial)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial)
+  /// ```
+  pub fn clear(mut self, clause: CreateTableClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      CreateTableClause::Column => self._column.clear(),
+      CreateTableClause::Constraint => self._constraint.clear(),
+      CreateTableClause::CreateTable => {
+        self._create_table = "".into();
+        self._if_not_exists = false;
+      }
+      CreateTableClause::ForeignKey => self._foreign_key.clear(),
+      CreateTableClause::PrimaryKey => self._primary_key = "".into(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
+  }
+
+  /// Defines a column of the table, consecutive calls accumulates the columns in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .column("id serial primary key")
+  ///   .column("login varchar(40) not null")
+  ///   .as_string();
+  ///
+  /// # let expected = "(id serial primary key, login varchar(40) not null)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// (id serial primary key, login varchar(40) not null)
+  /// ```
+  pub fn column<'s>(mut self, column_definition: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(CreateTableClause::Column),
+      &mut self._column,
+      column_definition.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The column definitions in the order they were added
+  pub fn column_items(&self) -> &[Sql<'static>] {
+    &self._column
+  }
+
+  /// Defines a named constraint of the table, rendered after the columns and the primary key
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .column("login varchar(40) not null")
+  ///   .constraint("users_login_key unique (login)")
+  ///   .as_string();
+  ///
+  /// # let expected = "(login varchar(40) not null, CONSTRAINT users_login_key unique (login))";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// (login varchar(40) not null, CONSTRAINT users_login_key unique (login))
+  /// ```
+  pub fn constraint<'s>(mut self, expression: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(CreateTableClause::Constraint),
+      &mut self._constraint,
+      expression.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The constraints in the order they were added
+  pub fn constraint_items(&self) -> &[Sql<'static>] {
+    &self._constraint
+  }
+
+  /// The create table clause, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TABLE users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE TABLE users
+  /// ```
+  pub fn create_table(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._create_table = table_name.into().trim();
+    self._if_not_exists = false;
+    self
+  }
+
+  /// The create table clause with the `IF NOT EXISTS` modifier, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table_if_not_exists("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TABLE IF NOT EXISTS users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE TABLE IF NOT EXISTS users
+  /// ```
+  pub fn create_table_if_not_exists(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._create_table = table_name.into().trim();
+    self._if_not_exists = true;
+    self
+  }
+
+  /// Prints the current state of the CreateTable into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .column("login varchar(40) not null")
+  ///   .primary_key("(id)")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (
+  ///   id serial,
+  ///   login varchar(40) not null,
+  ///   PRIMARY KEY(id)
+  /// )
+  /// ```
+  pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [CreateTable] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::multiline();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Dedupe)
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .column("id serial")
+  ///   .as_string();
+  ///
+  /// # let expected = "CREATE TABLE users (id serial)";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial)
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// Defines a foreign key of the table, rendered after the constraints
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .column("user_id int not null")
+  ///   .foreign_key("(user_id) references users (id)")
+  ///   .as_string();
+  ///
+  /// # let expected = "(user_id int not null, FOREIGN KEY(user_id) references users (id))";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// (user_id int not null, FOREIGN KEY(user_id) references users (id))
+  /// ```
+  pub fn foreign_key<'s>(mut self, expression: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(CreateTableClause::ForeignKey),
+      &mut self._foreign_key,
+      expression.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The foreign keys in the order they were added
+  pub fn foreign_key_items(&self) -> &[Sql<'static>] {
+    &self._foreign_key
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn create_table(table: &str) -> sql::CreateTable<'static> {
+  ///   sql::CreateTable::new()
+  ///     .create_table(table)
+  ///     .column("id serial")
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = create_table(&String::from("users")).as_string();
+  ///
+  /// # let expected = "CREATE TABLE users (id serial)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial)
+  /// ```
+  pub fn into_owned(self) -> CreateTable<'static> {
+    CreateTable {
+      _column: self._column,
+      _constraint: self._constraint,
+      _create_table: self._create_table.into_owned(),
+      _duplicates: self._duplicates,
+      _foreign_key: self._foreign_key,
+      _if_not_exists: self._if_not_exists,
+      _primary_key: self._primary_key.into_owned(),
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+    }
+  }
+
+  /// Create CreateTable's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Defines the primary key of the table, this method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .column("id serial")
+  ///   .primary_key("(id)")
+  ///   .as_string();
+  ///
+  /// # let expected = "(id serial, PRIMARY KEY(id))";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// (id serial, PRIMARY KEY(id))
+  /// ```
+  pub fn primary_key(mut self, column: impl Into<Sql<'a>>) -> Self {
+    self._primary_key = column.into().trim();
+    self
+  }
+
+  /// The value of the primary key, `None` when the clause is not defined
+  pub fn primary_key_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._primary_key)
+  }
+
+  /// Prints the current state of the CreateTable into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [CreateTable] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "CREATE TABLE users (id serial)\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE TABLE users (id serial)
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* create the users table */";
+  /// let query = sql::CreateTable::new()
+  ///   .raw(raw_query)
+  ///   .create_table("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* create the users table */ CREATE TABLE users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* create the users table */ CREATE TABLE users
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "created_at timestamp not null default now()";
+  /// let query = sql::CreateTable::new()
+  ///   .column("id serial")
+  ///   .raw_after(sql::CreateTableClause::Column, raw)
+  ///   .as_string();
+  ///
+  /// # let expected = "(id serial, created_at timestamp not null default now())";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// (id serial, created_at timestamp not null default now())
+  /// ```
+  pub fn raw_after(mut self, clause: CreateTableClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "id serial";
+  /// let query = sql::CreateTable::new()
+  ///   .raw_before(sql::CreateTableClause::Column, raw)
+  ///   .column("login varchar(40)")
+  ///   .as_string();
+  ///
+  /// # let expected = "(id serial, login varchar(40))";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// (id serial, login varchar(40))
+  /// ```
+  pub fn raw_before(mut self, clause: CreateTableClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// The table of the create table clause, `None` when the clause is not defined
+  pub fn target_table(&self) -> Option<&Sql<'a>> {
+    defined(&self._create_table)
+  }
+
+  /// Gets the current state of the [CreateTable] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .column("login varchar(40)")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "create table users (\n  id serial,\n  login varchar(40)\n)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// create table users (
+  ///   id serial,
+  ///   login varchar(40)
+  /// )
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [CreateTable] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [CreateTable::validate] for the checks
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "CREATE TABLE users (id serial)";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE TABLE users (id serial)
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    Ok(self.as_string())
+  }
+
+  /// Checks the current state of the [CreateTable] without rendering it, returns an error naming the clause
+  /// that makes the query invalid, like the definitions without the create table clause or a table without definitions.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::MissingClause {
+  ///     clause: sql::Clause::CreateTable(sql::CreateTableClause::Column),
+  ///     required_by: sql::Clause::CreateTable(sql::CreateTableClause::CreateTable),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()
+  }
+}
+
+impl std::fmt::Display for CreateTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
+  }
+}
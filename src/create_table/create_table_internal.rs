@@ -0,0 +1,148 @@
+use crate::{
+  behavior::{
+    append, check_required, concat_raw_before_after, raw_defines, raw_queries, trim_end_from, Concat, ConcatMethods,
+  },
+  error::BuildError,
+  fmt,
+  sql::Sql,
+  structure::{CreateTable, CreateTableClause},
+};
+use std::borrow::Cow;
+
+impl<'a> ConcatMethods<'a, CreateTableClause> for CreateTable<'_> {}
+
+impl Concat for CreateTable<'_> {
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_create_table(query, fmts);
+    self.concat_definitions(query, fmts);
+
+    trim_end_from(query, start);
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let has_raw = |clause, keywords: &[&'static str]| {
+      raw_defines(&self._raw, &self._raw_before, &self._raw_after, clause, keywords)
+    };
+    let definitions = [
+      (self._column.is_empty() == false, CreateTableClause::Column),
+      (self._primary_key.is_empty() == false, CreateTableClause::PrimaryKey),
+      (self._constraint.is_empty() == false, CreateTableClause::Constraint),
+      (self._foreign_key.is_empty() == false, CreateTableClause::ForeignKey),
+    ];
+    let has_definitions = definitions.iter().any(|(is_defined, _)| *is_defined);
+    check_required(
+      (
+        self._create_table.is_empty() == false || has_raw(CreateTableClause::CreateTable, &["CREATE"]),
+        CreateTableClause::CreateTable,
+      ),
+      definitions,
+    )?;
+    check_required(
+      (
+        has_definitions || has_raw(CreateTableClause::Column, &[]),
+        CreateTableClause::Column,
+      ),
+      [(self._create_table.is_empty() == false, CreateTableClause::CreateTable)],
+    )
+  }
+}
+
+impl CreateTable<'_> {
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(CreateTableClause::Column), &self._column)?;
+    duplicates.check(Some(CreateTableClause::Constraint), &self._constraint)?;
+    duplicates.check(Some(CreateTableClause::ForeignKey), &self._foreign_key)?;
+    duplicates.check(None, &self._raw)
+  }
+
+  fn concat_create_table(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateTableClause::CreateTable,
+      |query| {
+        if self._create_table.is_empty() == false {
+          append(query, &["CREATE TABLE", space]);
+          if self._if_not_exists {
+            append(query, &["IF NOT EXISTS", space]);
+          }
+          query.append(&self._create_table);
+          query.push_str(space);
+        }
+      },
+    );
+  }
+
+  /// Columns, primary key, constraints and foreign keys are rendered inside the parentheses in this order,
+  /// the raw SQL before and after each of them is rendered as another definition of the list
+  fn concat_definitions(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter {
+      comma,
+      indent,
+      lb,
+      space,
+      ..
+    } = fmts;
+    fn prefixed<'q>(
+      keyword: &'q str,
+      separator: &'q str,
+      items: &'q [Sql<'static>],
+    ) -> Vec<(&'q str, &'q str, &'q Sql<'q>)> {
+      items.iter().map(|item| (keyword, separator, item)).collect()
+    }
+    let primary_key = match self._primary_key.is_empty() {
+      true => vec![],
+      false => vec![("PRIMARY KEY", "", &self._primary_key)],
+    };
+    let clauses = [
+      (CreateTableClause::Column, prefixed("", "", &self._column)),
+      (CreateTableClause::PrimaryKey, primary_key),
+      (
+        CreateTableClause::Constraint,
+        prefixed("CONSTRAINT", space, &self._constraint),
+      ),
+      (
+        CreateTableClause::ForeignKey,
+        prefixed("FOREIGN KEY", "", &self._foreign_key),
+      ),
+    ];
+    let mut definitions = clauses
+      .iter()
+      .flat_map(|(clause, items)| {
+        let raw_before = raw_queries(&self._raw_before, clause).map(|raw| ("", "", Cow::Owned(Sql::from(raw))));
+        let raw_after = raw_queries(&self._raw_after, clause).map(|raw| ("", "", Cow::Owned(Sql::from(raw))));
+        let items = items
+          .iter()
+          .map(|(keyword, separator, item)| (*keyword, *separator, Cow::Borrowed(*item)));
+        raw_before.chain(items).chain(raw_after)
+      })
+      .peekable();
+
+    if definitions.peek().is_none() {
+      return;
+    }
+
+    let separator = if lb.is_empty() {
+      comma.to_string()
+    } else {
+      format!("{}{lb}{indent}", comma.trim_end())
+    };
+    append(query, &["(", lb, indent]);
+    for (index, (keyword, separator_of_keyword, definition)) in definitions.enumerate() {
+      if index > 0 {
+        query.push_str(&separator);
+      }
+      append(query, &[keyword, separator_of_keyword]);
+      query.append(&definition);
+    }
+    append(query, &[lb, ")", space]);
+  }
+}
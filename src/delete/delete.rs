@@ -1,8 +1,17 @@
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
+  behavior::{defined, push_or, trim, Concat, WithQuery},
+  bind::{bind, Params, PlaceholderMap, Value},
+  condition::Condition,
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::{BuildError, ParseError},
+  fmt::{self, FormatOptions},
+  parse,
+  sink::{self, IoSink, Sink},
+  sql::Sql,
   structure::{Delete, DeleteClause},
 };
+use std::borrow::Cow;
 
 impl<'a> Delete<'a> {
   /// The same as [where_clause](Delete::where_clause) method, useful to write more idiomatic SQL query
@@ -16,11 +25,56 @@ impl<'a> Delete<'a> {
   ///   .where_clause("created_at < $1")
   ///   .and("active = false");
   /// ```
-  pub fn and(mut self, condition: &'a str) -> Self {
+  pub fn and(mut self, condition: impl Into<Condition>) -> Self {
     self = self.where_clause(condition);
     self
   }
 
+  /// The same as [where_clause_bind](Delete::where_clause_bind) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("created_at < ?", 1_640_995_200)
+  ///   .and_bind("active = ?", false);
+  /// ```
+  pub fn and_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self = self.where_clause_bind(condition, params);
+    self
+  }
+
+  /// Calls the function with the Delete and returns its result, useful to reuse a set of method calls
+  /// without breaking the method chaining
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn active_only(query: sql::Delete) -> sql::Delete {
+  ///   query.where_clause("active = false")
+  /// }
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .apply(active_only)
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE active = false";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE active = false
+  /// ```
+  pub fn apply(self, f: impl FnOnce(Self) -> Self) -> Self {
+    f(self)
+  }
+
   /// Gets the current state of the [Delete] and returns it as string
   ///
   /// # Examples
@@ -39,11 +93,105 @@ impl<'a> Delete<'a> {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    self.render(&fmts).0
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string together with the bound values,
+  /// the placeholders are numbered in the order they appear in the query with the syntax enabled by the feature flags
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 42)
+  ///   .build();
+  ///
+  /// assert_eq!(values, vec![sql::Value::Int(42)]);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DELETE FROM users WHERE id = $1
+  /// ```
+  ///
+  /// # Panics
+  /// Panics when the placeholders of a `*_bind` method don't match the values bound to it,
+  /// use [Delete::build_with] to get the [BuildError::BindMismatch] error instead
+  pub fn build(&self) -> (String, Vec<Value>) {
+    let fmts = fmt::one_line();
+    self.try_render(&fmts).unwrap_or_else(|error| panic!("{error}"))
+  }
+
+  /// Gets the current state of the [Delete] rendered for the given dialect together with the bound values,
+  /// returns an error when a clause defined in the query isn't supported by the dialect, see [Dialect] for the details
+  /// or [BuildError::BindMismatch] when the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 42)
+  ///   .build_with(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE id = ?1");
+  /// assert_eq!(values.len(), 1);
+  /// ```
+  pub fn build_with(&self, dialect: Dialect) -> Result<(String, Vec<Value>), BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    self.try_render(&fmts)
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .raw_before(sql::DeleteClause::Where, "/* by login */")
+  ///   .clear(sql::DeleteClause::Where)
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users
+  /// ```
+  pub fn clear(mut self, clause: DeleteClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      DeleteClause::DeleteFrom => self._delete_from = "".into(),
+      DeleteClause::Where => self._where.clear(),
+      DeleteClause::Returning => self._returning.clear(),
+      DeleteClause::With => self._with.clear(),
+      DeleteClause::Limit => self._limit = "".into(),
+      DeleteClause::OrderBy => self._order_by.clear(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
   }
 
   /// Prints the current state of the [Delete] into console output in a more ease to read version.
-  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
   ///
   /// # Examples
   /// ```
@@ -64,8 +212,27 @@ impl<'a> Delete<'a> {
   /// WHERE login = 'foo'
   /// ```
   pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Delete] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
     self
   }
 
@@ -82,21 +249,255 @@ impl<'a> Delete<'a> {
   ///   .delete_from("address")
   ///   .delete_from("orders");
   /// ```
-  pub fn delete_from(mut self, table_name: &'a str) -> Self {
-    self._delete_from = table_name.trim();
+  pub fn delete_from(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._delete_from = table_name.into().trim();
     self
   }
 
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::Delete::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Error)
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .where_clause("login = 'foo'")
+  ///   .try_as_string();
+  ///
+  /// # assert!(result.is_err());
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn delete_users(table: &str) -> sql::Delete<'static> {
+  ///   sql::Delete::new()
+  ///     .delete_from(table)
+  ///     .where_clause("active = false")
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = delete_users(&String::from("users")).as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE active = false";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE active = false
+  /// ```
+  pub fn into_owned(self) -> Delete<'static> {
+    Delete {
+      _delete_from: self._delete_from.into_owned(),
+      _duplicates: self._duplicates,
+      _limit: self._limit.into_owned(),
+      _order_by: self._order_by,
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+      _returning: self._returning,
+      _where: self._where,
+      _with: crate::behavior::owned_with(self._with),
+    }
+  }
+
+  /// The limit clause, supported by SQLite and MySQL.
+  /// This method overrides the previous value
+  ///
+  /// SQLite only accepts this clause when compiled with the `SQLITE_ENABLE_UPDATE_DELETE_LIMIT` option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("active = false")
+  ///   .limit("100");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE active = false LIMIT 100
+  /// ```
+  pub fn limit(mut self, num: impl crate::pagination::RowCount<'a>) -> Self {
+    self._limit = Sql::from(num.into_row_count()).trim();
+    self
+  }
+
+  /// The limit clause with a bound value, supported by SQLite and MySQL.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("active = false")
+  ///   .limit_bind(10)
+  ///   .build_with(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE active = false LIMIT ?");
+  /// assert_eq!(values, vec![sql::Value::Int(10)]);
+  /// ```
+  pub fn limit_bind(mut self, value: impl Into<Value>) -> Self {
+    self._limit = bind("?", value.into());
+    self
+  }
+
+  /// The value of the limit clause
+  pub fn limit_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._limit)
+  }
+
   /// Create Delete's instance
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// The expressions of the order by clause
+  pub fn order_by_items(&self) -> &[Sql<'static>] {
+    &self._order_by
+  }
+
+  /// Parses a SQL statement into the clauses of the [Delete] builder, so the statement can be changed with the builder methods.
+  /// The text of each clause is kept as written, a comment before the statement is kept as a raw SQL
+  /// and the queries of the `with` clause are parsed by the builder of its statement.
+  /// See [ParseError] for the statements that can't be split into the clauses of the builder.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::parse("DELETE FROM users WHERE login = 'foo'")
+  ///   .unwrap()
+  ///   .or("login = 'bar'");
+  ///
+  /// # let expected = "DELETE FROM users WHERE login = 'foo' OR login = 'bar'";
+  /// # assert_eq!(delete.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DELETE FROM users WHERE login = 'foo' OR login = 'bar'
+  /// ```
+  pub fn parse(sql: &'a str) -> Result<Self, ParseError> {
+    let statement = parse::statement(sql)?;
+    parse::nested(sql, statement, Self::parse_statement)
+  }
+
+  /// Returns how the positional placeholders (`$1`, `$2`, ...) written in each composed builder were renumbered
+  /// in the final query, see [PlaceholderMap] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = $1");
+  ///
+  /// let map = delete.placeholder_map();
+  ///
+  /// assert_eq!(map.get(0, 1), Some(1));
+  /// ```
+  pub fn placeholder_map(&self) -> PlaceholderMap {
+    let fmts = fmt::one_line();
+    self.placeholders(&fmts)
+  }
+
+  /// The or operator of the where clause, the conditions defined so far are grouped and joined with the new
+  /// condition by `OR`, the next conditions are joined with the whole group by `AND`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .or("login = 'bar'")
+  ///   .and("created_at < '2024-01-01'")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "DELETE FROM users WHERE (login = 'foo' OR login = 'bar') AND created_at < '2024-01-01'"
+  /// );
+  /// ```
+  pub fn or(mut self, condition: impl Into<Condition>) -> Self {
+    push_or(&mut self._where, condition.into());
+    self._duplicates.clear(Some(DeleteClause::Where));
+    self
+  }
+
+  /// The order by clause, supported by MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .order_by("created_at desc")
+  ///   .limit("10");
+  /// ```
+  pub fn order_by<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(DeleteClause::OrderBy),
+      &mut self._order_by,
+      column.into().trim().into_owned(),
+    );
+    self
+  }
+
   /// Prints the current state of the [Delete] into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Delete] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = 1")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "DELETE FROM users WHERE id = 1\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DELETE FROM users WHERE id = 1
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
     self
   }
 
@@ -119,8 +520,10 @@ impl<'a> Delete<'a> {
   /// delete from users
   /// WHERE login = 'foo'
   /// ```
-  pub fn raw(mut self, raw_sql: &'a str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
     self
   }
 
@@ -143,8 +546,8 @@ impl<'a> Delete<'a> {
   /// DELETE FROM users
   /// where name = 'Foo'
   /// ```
-  pub fn raw_after(mut self, clause: DeleteClause, raw_sql: &'a str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_after(mut self, clause: DeleteClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
@@ -167,18 +570,254 @@ impl<'a> Delete<'a> {
   /// delete from users
   /// WHERE name = 'Bar'
   /// ```
-  pub fn raw_before(mut self, clause: DeleteClause, raw_sql: &'a str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_before(mut self, clause: DeleteClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
-  /// The returning clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
-  pub fn returning(mut self, output_name: &'a str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_owned());
+  /// Removes the conditions of the where clause for which the predicate returns `true`,
+  /// the conditions joined by the `or` method are a single [Condition::Or]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .where_clause("active = false")
+  ///   .remove_where(|condition| *condition == "login = 'foo'".into())
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE active = false";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE active = false
+  /// ```
+  pub fn remove_where(mut self, mut predicate: impl FnMut(&Condition) -> bool) -> Self {
+    self._where.retain(|condition| predicate(condition) == false);
+    self._duplicates.clear(Some(DeleteClause::Where));
     self
   }
 
+  /// Replaces the values of the where clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .replace_where("login = 'bar'")
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE login = 'bar'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE login = 'bar'
+  /// ```
+  pub fn replace_where(mut self, condition: impl Into<Condition>) -> Self {
+    self._where.clear();
+    self._duplicates.clear(Some(DeleteClause::Where));
+    self.where_clause(condition)
+  }
+
+  /// The returning clause, supported by Postgres and SQLite
+  pub fn returning<'s>(mut self, output_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(DeleteClause::Returning),
+      &mut self._returning,
+      output_name.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The output names of the returning clause
+  pub fn returning_items(&self) -> &[Sql<'static>] {
+    &self._returning
+  }
+
+  /// The table of the delete from clause, `None` when the clause is not defined
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new().delete_from("users").where_clause("active = false");
+  ///
+  /// assert_eq!(delete.target_table().unwrap(), "users");
+  /// ```
+  pub fn target_table(&self) -> Option<&Sql<'a>> {
+    defined(&self._delete_from)
+  }
+
+  /// Gets the current state of the [Delete] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("id = ?", 42)
+  ///   .to_sql(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE id = ?");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.build_with(dialect).map(|(query, _)| query)
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "delete from users\nwhere login = 'foo'";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// delete from users
+  /// where login = 'foo'
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [Delete] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [Delete::validate] for the checks, or [BuildError::BindMismatch] when
+  /// the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "DELETE FROM users WHERE login = 'foo'";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DELETE FROM users WHERE login = 'foo'
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(|(query, _)| query)
+  }
+
+  /// Checks the current state of the [Delete], returns an error naming the clause
+  /// that makes the query invalid, like a where clause without the delete from clause.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::Delete::new()
+  ///   .where_clause("login = 'foo'")
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::MissingClause {
+  ///     clause: sql::Clause::Delete(sql::DeleteClause::DeleteFrom),
+  ///     required_by: sql::Clause::Delete(sql::DeleteClause::Where),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(drop)
+  }
+
+  /// Calls the function with the Delete only when the condition is true, otherwise the Delete is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let only_active = true;
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .when(only_active, |query| query.where_clause("active = false"))
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE active = false";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE active = false
+  /// ```
+  pub fn when(self, condition: bool, f: impl FnOnce(Self) -> Self) -> Self {
+    if condition {
+      f(self)
+    } else {
+      self
+    }
+  }
+
+  /// Calls the function with the Delete and the value of the option only when the option is `Some`,
+  /// otherwise the Delete is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let login = Some("foo");
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .when_some(login, |query, login| query.where_clause(format!("login = '{login}'")))
+  ///   .as_string();
+  ///
+  /// # let expected = "DELETE FROM users WHERE login = 'foo'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM users WHERE login = 'foo'
+  /// ```
+  pub fn when_some<T>(self, value: Option<T>, f: impl FnOnce(Self, T) -> Self) -> Self {
+    match value {
+      Some(value) => f(self, value),
+      None => self,
+    }
+  }
+
   /// The where clause
   ///
   /// # Examples
@@ -189,12 +828,43 @@ impl<'a> Delete<'a> {
   ///   .delete_from("users")
   ///   .where_clause("login = 'foo'");
   /// ```
-  pub fn where_clause(mut self, condition: &'a str) -> Self {
-    push_unique(&mut self._where, condition.trim().to_owned());
+  pub fn where_clause(mut self, condition: impl Into<Condition>) -> Self {
+    self
+      ._duplicates
+      .push(Some(DeleteClause::Where), &mut self._where, condition.into());
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The where clause with bound values, each `?` of the condition is replaced by a placeholder
+  /// numbered at render time, use [build](Delete::build) to get the values in the placeholders order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause_bind("login = ?", "foo");
+  /// ```
+  ///
+  /// The condition with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn where_clause_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self._duplicates.push(
+      Some(DeleteClause::Where),
+      &mut self._where,
+      Condition::Raw(bind(condition, params)),
+    );
+    self
+  }
+
+  /// The conditions of the where clause in the order they were added, the conditions joined
+  /// by the `or` method are a single [Condition::Or]
+  pub fn where_conditions(&self) -> &[Condition] {
+    &self._where
+  }
+
+  /// The with clause
   ///
   /// # Examples
   /// ```
@@ -219,14 +889,23 @@ impl<'a> Delete<'a> {
   /// DELETE FROM users
   /// WHERE id in (select * from deactivated_users)
   /// ```
-  #[cfg(feature = "postgresql")]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Cow<'a, str>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((trim(name), std::sync::Arc::new(query)));
     self
   }
+
+  /// The names of the queries of the with clause in the order they were added
+  pub fn with_ctes(&self) -> impl Iterator<Item = &str> {
+    self._with.iter().map(|(name, _)| name.as_ref())
+  }
 }
 
-impl WithQuery for Delete<'_> {}
+impl WithQuery for Delete<'_> {
+  #[cfg(feature = "serde")]
+  fn tagged(&self) -> crate::serialize::TaggedQuery<'_> {
+    crate::serialize::TaggedQuery::Delete(self)
+  }
+}
 
 impl std::fmt::Display for Delete<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -237,6 +916,6 @@ impl std::fmt::Display for Delete<'_> {
 impl std::fmt::Debug for Delete<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
   }
 }
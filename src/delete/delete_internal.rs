@@ -1,69 +1,178 @@
 use crate::{
-  behavior::{concat_raw_before_after, Concat, ConcatMethods},
-  fmt,
+  behavior::{
+    append, check_clauses, check_required, concat_raw_before_after, raw_defines, trim_end_from, Concat, ConcatMethods,
+  },
+  condition::Condition,
+  dialect::Dialect,
+  error::{BuildError, ParseError},
+  fmt, parse,
+  sql::Sql,
   structure::{Delete, DeleteClause},
 };
 
 impl<'a> ConcatMethods<'a, DeleteClause> for Delete<'_> {}
 
 impl Concat for Delete<'_> {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_owned();
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::With,
-        &self._with,
-      );
-    }
-    query = self.concat_delete_from(query, &fmts);
-    query = self.concat_where(
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_with(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DeleteClause::With,
+      &self._with,
+    );
+    self.concat_delete_from(query, fmts);
+    self.concat_where(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       DeleteClause::Where,
       &self._where,
     );
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_returning(
+    self.concat_returning(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DeleteClause::Returning,
+      &self._returning,
+    );
+
+    self.concat_order_by(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DeleteClause::OrderBy,
+      &self._order_by,
+    );
+    self.concat_limit(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DeleteClause::Limit,
+      &self._limit,
+    );
+
+    trim_end_from(query, start);
+  }
+
+  fn check_dialect(&self, dialect: Dialect) -> Result<(), BuildError> {
+    let clauses: Vec<(bool, DeleteClause, &'static [Dialect])> = vec![
+      (
+        self._returning.is_empty() == false,
+        DeleteClause::Returning,
+        &[Dialect::Postgres, Dialect::Sqlite],
+      ),
+      (
+        self._limit.is_empty() == false,
+        DeleteClause::Limit,
+        &[Dialect::Sqlite, Dialect::Mysql],
+      ),
+      (
+        self._order_by.is_empty() == false,
+        DeleteClause::OrderBy,
+        &[Dialect::Mysql],
+      ),
+    ];
+    check_clauses(dialect, clauses)?;
+    for (_, query) in &self._with {
+      query.check_dialect(dialect)?;
+    }
+
+    Ok(())
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let clauses: Vec<(bool, DeleteClause)> = vec![
+      (self._with.is_empty() == false, DeleteClause::With),
+      (self._where.is_empty() == false, DeleteClause::Where),
+      (self._returning.is_empty() == false, DeleteClause::Returning),
+      (self._order_by.is_empty() == false, DeleteClause::OrderBy),
+      (self._limit.is_empty() == false, DeleteClause::Limit),
+    ];
+    let has_delete_from = self._delete_from.is_empty() == false
+      || raw_defines(
+        &self._raw,
         &self._raw_before,
         &self._raw_after,
-        query,
-        &fmts,
-        DeleteClause::Returning,
-        &self._returning,
+        DeleteClause::DeleteFrom,
+        &["DELETE"],
       );
+    check_required((has_delete_from, DeleteClause::DeleteFrom), clauses)?;
+    crate::behavior::check_number(DeleteClause::Limit, &self._limit)?;
+    for (_, query) in &self._with {
+      query.check_query()?;
     }
 
-    query.trim_end().to_owned()
+    Ok(())
   }
 }
 
 impl Delete<'_> {
-  fn concat_delete_from(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._delete_from.is_empty() == false {
-      let table_name = self._delete_from;
-      format!("DELETE FROM{space}{table_name}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(DeleteClause::OrderBy), &self._order_by)?;
+    duplicates.check(Some(DeleteClause::Returning), &self._returning)?;
+    duplicates.check(Some(DeleteClause::Where), &self._where)?;
+    duplicates.check(None, &self._raw)
+  }
 
+  fn concat_delete_from(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       DeleteClause::DeleteFrom,
-      sql,
-    )
+      |query| {
+        if self._delete_from.is_empty() == false {
+          append(query, &["DELETE FROM", space]);
+          query.append(&self._delete_from);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
+}
+
+const CLAUSES: &[&[&str]] = &[
+  &["WITH"],
+  &["DELETE FROM"],
+  &["WHERE"],
+  &["RETURNING"],
+  &["ORDER BY"],
+  &["LIMIT"],
+];
+
+impl<'a> Delete<'a> {
+  pub(crate) fn parse_statement(sql: &'a str) -> Result<Self, ParseError> {
+    let (leading, clauses) = parse::split_sequence(sql, CLAUSES)?;
+    let mut delete = Self {
+      _raw: parse::raw(leading, "DELETE FROM")?,
+      ..Default::default()
+    };
+
+    for clause in clauses {
+      let body = clause.body;
+      match clause.keyword {
+        "WITH" => delete._with = parse::with_items(sql, &clause)?,
+        "DELETE FROM" => delete._delete_from = body.into(),
+        "WHERE" => delete._where.push(Condition::raw(body)),
+        "RETURNING" => delete._returning = parse::list(body)?,
+        "ORDER BY" => delete._order_by = parse::list(body)?,
+        _ => delete._limit = body.into(),
+      }
+    }
+
+    Ok(delete)
   }
 }
@@ -0,0 +1,80 @@
+/// The SQL dialect used to render a query at runtime, each builder has the `to_sql` and `build_with` methods
+/// that receive a dialect. The dialect controls how identifiers are quoted, the style of the placeholders of the
+/// bound values, how the compound selects are parenthesized and which clauses are allowed.
+///
+/// The clauses of all dialects are available regardless of the feature flags, the `to_sql` and `build_with` methods
+/// return an error when a clause isn't supported by the dialect. The feature flags choose the syntax of the methods
+/// that don't receive a dialect, like the `as_string` and `build` methods
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .where_clause_bind("login = ?", "foo");
+///
+/// assert_eq!(select.to_sql(sql::Dialect::Postgres).unwrap(), "SELECT id FROM users WHERE login = $1");
+/// assert_eq!(select.to_sql(sql::Dialect::Sqlite).unwrap(), "SELECT id FROM users WHERE login = ?1");
+/// assert_eq!(select.to_sql(sql::Dialect::Mysql).unwrap(), "SELECT id FROM users WHERE login = ?");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dialect {
+  Mysql,
+  Postgres,
+  Sqlite,
+}
+
+impl Dialect {
+  /// Quotes an identifier, Postgres and SQLite use double quotes while MySQL uses backticks,
+  /// the quote character inside the identifier is escaped by doubling it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// assert_eq!(sql::Dialect::Postgres.quote("order"), "\"order\"");
+  /// assert_eq!(sql::Dialect::Mysql.quote("order"), "`order`");
+  /// ```
+  pub fn quote(&self, identifier: &str) -> String {
+    let quote = match self {
+      Dialect::Mysql => "`",
+      Dialect::Postgres | Dialect::Sqlite => "\"",
+    };
+    let escaped = identifier.replace(quote, &quote.repeat(2));
+
+    format!("{quote}{escaped}{quote}")
+  }
+
+  /// The dialect of the syntax enabled by the feature flags, used by the queries rendered without a dialect
+  pub(crate) fn of_features() -> Dialect {
+    if cfg!(all(feature = "mysql", not(feature = "postgresql"))) {
+      Dialect::Mysql
+    } else if cfg!(all(feature = "sqlite", not(feature = "postgresql"))) {
+      Dialect::Sqlite
+    } else {
+      Dialect::Postgres
+    }
+  }
+
+  /// The placeholder of a bound value, MySQL only accepts anonymous placeholders
+  pub(crate) fn placeholder(&self, number: usize) -> String {
+    match self {
+      Dialect::Mysql => "?".to_owned(),
+      Dialect::Postgres => format!("${number}"),
+      Dialect::Sqlite => format!("?{number}"),
+    }
+  }
+}
+
+impl std::fmt::Display for Dialect {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let name = match self {
+      Dialect::Mysql => "MySQL",
+      Dialect::Postgres => "PostgreSQL",
+      Dialect::Sqlite => "SQLite",
+    };
+    write!(f, "{name}")
+  }
+}
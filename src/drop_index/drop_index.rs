@@ -0,0 +1,447 @@
+use crate::{
+  behavior::Concat,
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::BuildError,
+  fmt::{self, FormatOptions},
+  sink::{self, IoSink, Sink},
+  sql::Sql,
+  structure::{DropIndex, DropIndexClause},
+};
+
+impl DropIndex {
+  /// Gets the current state of the DropIndex and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts).0
+  }
+
+  /// Drops also the objects that depends on the indexes, like constraints
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX users_login_idx CASCADE";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx CASCADE
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .clear(sql::DropIndexClause::DropIndex)
+  ///   .drop_index("orders_id_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX orders_id_idx";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX orders_id_idx
+  /// ```
+  pub fn clear(mut self, clause: DropIndexClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      DropIndexClause::DropIndex => {
+        self._drop_index.clear();
+        self._cascade = false;
+        self._if_exists = false;
+        self._concurrently = false;
+      }
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
+  }
+
+  /// Drops the index without locking out the operations on its table, supported by Postgres
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .concurrently()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX CONCURRENTLY users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX CONCURRENTLY users_login_idx
+  /// ```
+  pub fn concurrently(mut self) -> Self {
+    self._concurrently = true;
+    self
+  }
+
+  /// Prints the current state of the DropIndex into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_login_idx
+  /// ```
+  pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [DropIndex] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::multiline();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// The drop index clause, consecutive calls accumulates the indexes in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .drop_index("orders_user_id_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX users_login_idx, orders_user_id_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx, orders_user_id_idx
+  /// ```
+  pub fn drop_index<'s>(mut self, index_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(DropIndexClause::DropIndex),
+      &mut self._drop_index,
+      index_name.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The drop index clause with the `IF EXISTS` modifier, the modifier applies to all indexes of the clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index_if_exists("users_login_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX IF EXISTS users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX IF EXISTS users_login_idx
+  /// ```
+  pub fn drop_index_if_exists<'s>(mut self, index_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(DropIndexClause::DropIndex),
+      &mut self._drop_index,
+      index_name.into().trim().into_owned(),
+    );
+    self._if_exists = true;
+    self
+  }
+
+  /// The names of the indexes to be dropped in the order they were added
+  pub fn drop_index_items(&self) -> &[Sql<'static>] {
+    &self._drop_index
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::DropIndex::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Error)
+  ///   .drop_index("users_login_idx")
+  ///   .drop_index("users_login_idx")
+  ///   .try_as_string();
+  ///
+  /// # assert!(result.is_err());
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// Create DropIndex's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the DropIndex into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [DropIndex] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "DROP INDEX users_login_idx\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* remove the legacy index */";
+  /// let query = sql::DropIndex::new()
+  ///   .raw(raw_query)
+  ///   .drop_index("users_login_old_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* remove the legacy index */ DROP INDEX users_login_old_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* remove the legacy index */ DROP INDEX users_login_old_idx
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .raw_after(sql::DropIndexClause::DropIndex, "restrict")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP INDEX users_login_idx restrict";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx restrict
+  /// ```
+  pub fn raw_after(mut self, clause: DropIndexClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .raw_before(sql::DropIndexClause::DropIndex, "/* cleanup */")
+  ///   .drop_index("users_login_idx")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* cleanup */ DROP INDEX users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* cleanup */ DROP INDEX users_login_idx
+  /// ```
+  pub fn raw_before(mut self, clause: DropIndexClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Gets the current state of the [DropIndex] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .concurrently()
+  ///   .to_sql(sql::Dialect::Postgres)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DROP INDEX CONCURRENTLY users_login_idx");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    Ok(self.render(&fmts).0)
+  }
+
+  /// Gets the current state of the [DropIndex] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "drop index users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// drop index users_login_idx
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [DropIndex] and returns it as string, see [DropIndex::validate] for the checks
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "DROP INDEX users_login_idx";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    Ok(self.as_string())
+  }
+
+  /// Checks the current state of the [DropIndex] without rendering it. The clauses of the [DropIndex] don't depend
+  /// on each other, so the query is always valid, the method exists to validate any builder in the same way
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()
+  }
+}
+
+impl std::fmt::Display for DropIndex {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropIndex {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
+  }
+}
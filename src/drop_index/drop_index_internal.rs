@@ -0,0 +1,68 @@
+use crate::{
+  behavior::{append, append_list, check_clauses, concat_raw_before_after, trim_end_from, Concat, ConcatMethods},
+  dialect::Dialect,
+  error::BuildError,
+  fmt,
+  sql::Sql,
+  structure::{DropIndex, DropIndexClause},
+};
+
+impl<'a> ConcatMethods<'a, DropIndexClause> for DropIndex {}
+
+impl Concat for DropIndex {
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_drop_index(query, fmts);
+
+    trim_end_from(query, start);
+  }
+
+  fn check_dialect(&self, dialect: Dialect) -> Result<(), BuildError> {
+    check_clauses(
+      dialect,
+      [(self._concurrently, DropIndexClause::DropIndex, &[Dialect::Postgres][..])],
+    )
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()
+  }
+}
+
+impl DropIndex {
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(DropIndexClause::DropIndex), &self._drop_index)?;
+    duplicates.check(None, &self._raw)
+  }
+
+  fn concat_drop_index(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DropIndexClause::DropIndex,
+      |query| {
+        if self._drop_index.is_empty() {
+          return;
+        }
+        append(query, &["DROP INDEX", space]);
+        if self._concurrently {
+          append(query, &["CONCURRENTLY", space]);
+        }
+        if self._if_exists {
+          append(query, &["IF EXISTS", space]);
+        }
+        append_list(query, &self._drop_index, comma);
+        if self._cascade {
+          append(query, &[space, "CASCADE"]);
+        }
+        append(query, &[space, lb]);
+      },
+    );
+  }
+}
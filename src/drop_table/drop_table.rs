@@ -0,0 +1,397 @@
+use crate::{
+  behavior::Concat,
+  duplicate::DuplicatePolicy,
+  error::BuildError,
+  fmt::{self, FormatOptions},
+  sink::{self, IoSink, Sink},
+  sql::Sql,
+  structure::{DropTable, DropTableClause},
+};
+
+impl DropTable {
+  /// Gets the current state of the DropTable and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts).0
+  }
+
+  /// Drops also the objects that depends on the tables, like views and foreign keys
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE users CASCADE";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users CASCADE
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .clear(sql::DropTableClause::DropTable)
+  ///   .drop_table("orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE orders";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE orders
+  /// ```
+  pub fn clear(mut self, clause: DropTableClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      DropTableClause::DropTable => {
+        self._drop_table.clear();
+        self._cascade = false;
+        self._if_exists = false;
+      }
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
+  }
+
+  /// Prints the current state of the DropTable into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .debug()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [DropTable] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::multiline();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// The drop table clause, consecutive calls accumulates the tables in the same order of the calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .drop_table("orders")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE users, orders";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users, orders
+  /// ```
+  pub fn drop_table<'s>(mut self, table_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(DropTableClause::DropTable),
+      &mut self._drop_table,
+      table_name.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The drop table clause with the `IF EXISTS` modifier, the modifier applies to all tables of the clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table_if_exists("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE IF EXISTS users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE IF EXISTS users
+  /// ```
+  pub fn drop_table_if_exists<'s>(mut self, table_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(DropTableClause::DropTable),
+      &mut self._drop_table,
+      table_name.into().trim().into_owned(),
+    );
+    self._if_exists = true;
+    self
+  }
+
+  /// The names of the tables to be dropped in the order they were added
+  pub fn drop_table_items(&self) -> &[Sql<'static>] {
+    &self._drop_table
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::DropTable::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Error)
+  ///   .drop_table("users")
+  ///   .drop_table("users")
+  ///   .try_as_string();
+  ///
+  /// # assert!(result.is_err());
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// Create DropTable's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the DropTable into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [DropTable] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "DROP TABLE users\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* remove the legacy table */";
+  /// let query = sql::DropTable::new()
+  ///   .raw(raw_query)
+  ///   .drop_table("users_bk")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* remove the legacy table */ DROP TABLE users_bk";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* remove the legacy table */ DROP TABLE users_bk
+  /// ```
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .raw_after(sql::DropTableClause::DropTable, "restrict")
+  ///   .as_string();
+  ///
+  /// # let expected = "DROP TABLE users restrict";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users restrict
+  /// ```
+  pub fn raw_after(mut self, clause: DropTableClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .raw_before(sql::DropTableClause::DropTable, "/* cleanup */")
+  ///   .drop_table("users")
+  ///   .as_string();
+  ///
+  /// # let expected = "/* cleanup */ DROP TABLE users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// /* cleanup */ DROP TABLE users
+  /// ```
+  pub fn raw_before(mut self, clause: DropTableClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
+    self
+  }
+
+  /// Gets the current state of the [DropTable] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "drop table users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// drop table users
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [DropTable] and returns it as string, see [DropTable::validate] for the checks
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "DROP TABLE users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    Ok(self.as_string())
+  }
+
+  /// Checks the current state of the [DropTable] without rendering it. The clauses of the [DropTable] don't depend
+  /// on each other, so the query is always valid, the method exists to validate any builder in the same way
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()
+  }
+}
+
+impl std::fmt::Display for DropTable {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropTable {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
+  }
+}
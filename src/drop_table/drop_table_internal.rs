@@ -0,0 +1,57 @@
+use crate::{
+  behavior::{append, append_list, concat_raw_before_after, trim_end_from, Concat, ConcatMethods},
+  error::BuildError,
+  fmt,
+  sql::Sql,
+  structure::{DropTable, DropTableClause},
+};
+
+impl<'a> ConcatMethods<'a, DropTableClause> for DropTable {}
+
+impl Concat for DropTable {
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_drop_table(query, fmts);
+
+    trim_end_from(query, start);
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()
+  }
+}
+
+impl DropTable {
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(DropTableClause::DropTable), &self._drop_table)?;
+    duplicates.check(None, &self._raw)
+  }
+
+  fn concat_drop_table(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DropTableClause::DropTable,
+      |query| {
+        if self._drop_table.is_empty() {
+          return;
+        }
+        append(query, &["DROP TABLE", space]);
+        if self._if_exists {
+          append(query, &["IF EXISTS", space]);
+        }
+        append_list(query, &self._drop_table, comma);
+        if self._cascade {
+          append(query, &[space, "CASCADE"]);
+        }
+        append(query, &[space, lb]);
+      },
+    );
+  }
+}
@@ -0,0 +1,2 @@
+mod drop_table;
+mod drop_table_internal;
@@ -0,0 +1,163 @@
+use crate::{condition::Condition, error::BuildError, join::Join, sql::Sql};
+use std::{
+  collections::{hash_map::DefaultHasher, HashMap, HashSet},
+  hash::{BuildHasher, BuildHasherDefault, Hash},
+};
+
+/// How the builders handle a value added more than once to the same clause, the policy is defined
+/// by the `duplicate_policy` method of each builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Select::new()
+///   .duplicate_policy(sql::DuplicatePolicy::KeepAll)
+///   .select("random()")
+///   .select("random()")
+///   .as_string();
+///
+/// # assert_eq!(query, "SELECT random(), random()");
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT random(), random()
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DuplicatePolicy {
+  /// The repeated value is ignored, the default of all clauses except the rows of the
+  /// [Insert](crate::Insert) and [Values](crate::Values) builders
+  Dedupe,
+  /// All values are rendered, the default of the rows of the [Insert](crate::Insert) and [Values](crate::Values) builders
+  KeepAll,
+  /// All values are kept and the `try_as_string` and `validate` methods of the builders
+  /// return the error [BuildError::DuplicateValue]
+  Error,
+}
+
+/// The duplicate policy of a builder and the hashes of the values of each clause, the clause `None` holds
+/// the values of the `raw` method. The hashes of a clause are built on the next push after the list is changed
+/// by other methods, like the `clear` and `remove_where` methods of the builders, see [Duplicates::clear]
+#[derive(Clone)]
+pub(crate) struct Duplicates<Clause> {
+  policy: Option<DuplicatePolicy>,
+  index: HashMap<Option<Clause>, HashSet<u64>>,
+}
+
+/// Values that can be added to a clause
+pub(crate) trait Item: Hash + Eq {
+  /// The text used to name the value in the errors
+  fn text(&self) -> String;
+}
+
+impl Item for String {
+  fn text(&self) -> String {
+    self.clone()
+  }
+}
+
+impl Item for Sql<'_> {
+  fn text(&self) -> String {
+    self.to_string()
+  }
+}
+
+impl Item for Join {
+  fn text(&self) -> String {
+    self.to_string()
+  }
+}
+
+impl Item for Condition {
+  fn text(&self) -> String {
+    let mut text = Sql::default();
+    Condition::concat_list(&mut text, std::slice::from_ref(self), " AND ");
+    text.to_string()
+  }
+}
+
+fn hash_of(value: &impl Hash) -> u64 {
+  BuildHasherDefault::<DefaultHasher>::default().hash_one(value)
+}
+
+impl<Clause> Default for Duplicates<Clause> {
+  fn default() -> Self {
+    Self {
+      policy: None,
+      index: HashMap::new(),
+    }
+  }
+}
+
+impl<Clause: Hash + Eq + Into<crate::structure::Clause>> Duplicates<Clause> {
+  pub(crate) fn set_policy(&mut self, policy: DuplicatePolicy) {
+    self.policy = Some(policy);
+  }
+
+  /// Adds the value to the list of the clause, a repeated value is ignored unless
+  /// the policy of the builder is to keep all values
+  pub(crate) fn push<T: Item>(&mut self, clause: Option<Clause>, list: &mut Vec<T>, value: T) {
+    self.push_with(DuplicatePolicy::Dedupe, clause, list, value)
+  }
+
+  /// Adds a row of the values clause, all rows are kept unless the builder defines another policy
+  pub(crate) fn push_row<T: Item>(&mut self, clause: Clause, list: &mut Vec<T>, value: T) {
+    self.push_with(DuplicatePolicy::KeepAll, Some(clause), list, value)
+  }
+
+  /// Discards the hashes of the clause, must be called by every method that changes the list of the clause
+  /// without the push methods
+  pub(crate) fn clear(&mut self, clause: Option<Clause>) {
+    self.index.remove(&clause);
+  }
+
+  fn push_with<T: Item>(&mut self, default: DuplicatePolicy, clause: Option<Clause>, list: &mut Vec<T>, value: T) {
+    if self.policy.unwrap_or(default) != DuplicatePolicy::Dedupe {
+      self.clear(clause);
+      return list.push(value);
+    }
+
+    let hashes = self
+      .index
+      .entry(clause)
+      .or_insert_with(|| list.iter().map(hash_of).collect());
+    let is_duplicate = hashes.insert(hash_of(&value)) == false && list.contains(&value);
+    if is_duplicate == false {
+      list.push(value);
+    }
+  }
+
+  /// Returns the error [BuildError::DuplicateValue] naming the first repeated value of the list
+  /// when the policy of the builder is [DuplicatePolicy::Error]
+  pub(crate) fn check<T: Item>(&self, clause: Option<Clause>, list: &[T]) -> Result<(), BuildError> {
+    if self.policy != Some(DuplicatePolicy::Error) {
+      return Ok(());
+    }
+
+    let mut seen = HashSet::with_capacity(list.len());
+    match list.iter().find(|value| seen.insert(*value) == false) {
+      Some(value) => Err(BuildError::duplicate_value(clause, &value.text())),
+      None => Ok(()),
+    }
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<Clause> serde::Serialize for Duplicates<Clause> {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    self.policy.serialize(serializer)
+  }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Clause> serde::Deserialize<'de> for Duplicates<Clause> {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    Ok(Self {
+      policy: Option::deserialize(deserializer)?,
+      index: HashMap::new(),
+    })
+  }
+}
@@ -0,0 +1,185 @@
+use crate::{dialect::Dialect, structure::Clause};
+
+/// Errors returned by the methods that render a query and can fail, like the `to_sql` and `try_as_string` methods
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+  /// The number of `?` placeholders of the sql given to a `*_bind` method differs from the number of values bound
+  BindMismatch {
+    sql: String,
+    placeholders: usize,
+    values: usize,
+  },
+  /// Both clauses were defined but only one of them can be rendered, like the values and the select of an insert
+  ConflictingClauses { clause: Clause, other: Clause },
+  /// The value was added more than once to the clause of a builder with the policy
+  /// [DuplicatePolicy::Error](crate::DuplicatePolicy::Error), the clause is `None` for the values of the `raw` method
+  DuplicateValue { clause: Option<Clause>, value: String },
+  /// The value of the clause is not valid, like a limit that isn't a number or a placeholder
+  InvalidValue { clause: Clause, value: String },
+  /// The clause is required by another clause defined in the query, like the table of an update with a set clause
+  MissingClause { clause: Clause, required_by: Clause },
+  /// The clause was defined but the dialect used to render the query doesn't support it
+  UnsupportedClause { clause: Clause, dialect: Dialect },
+}
+
+impl BuildError {
+  pub(crate) fn conflicting_clauses(clause: impl Into<Clause>, other: impl Into<Clause>) -> Self {
+    Self::ConflictingClauses {
+      clause: clause.into(),
+      other: other.into(),
+    }
+  }
+
+  pub(crate) fn duplicate_value(clause: Option<impl Into<Clause>>, value: &str) -> Self {
+    Self::DuplicateValue {
+      clause: clause.map(Into::into),
+      value: value.to_owned(),
+    }
+  }
+
+  pub(crate) fn invalid_value(clause: impl Into<Clause>, value: &str) -> Self {
+    Self::InvalidValue {
+      clause: clause.into(),
+      value: value.to_owned(),
+    }
+  }
+
+  pub(crate) fn missing_clause(clause: impl Into<Clause>, required_by: impl Into<Clause>) -> Self {
+    Self::MissingClause {
+      clause: clause.into(),
+      required_by: required_by.into(),
+    }
+  }
+
+  pub(crate) fn unsupported_clause(clause: impl Into<Clause>, dialect: Dialect) -> Self {
+    Self::UnsupportedClause {
+      clause: clause.into(),
+      dialect,
+    }
+  }
+}
+
+impl std::fmt::Display for BuildError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      BuildError::BindMismatch {
+        sql,
+        placeholders,
+        values,
+      } => {
+        write!(
+          f,
+          "the sql `{sql}` has {placeholders} placeholder(s) but {values} value(s) were bound"
+        )
+      }
+      BuildError::ConflictingClauses { clause, other } => {
+        write!(f, "the clause {clause} can't be used together with the clause {other}")
+      }
+      BuildError::DuplicateValue {
+        clause: Some(clause),
+        value,
+      } => {
+        write!(
+          f,
+          "the value `{value}` is defined more than once in the clause {clause}"
+        )
+      }
+      BuildError::DuplicateValue { clause: None, value } => {
+        write!(f, "the value `{value}` is defined more than once by the raw method")
+      }
+      BuildError::InvalidValue { clause, value } => {
+        write!(f, "the value `{value}` is not valid for the clause {clause}")
+      }
+      BuildError::MissingClause { clause, required_by } => {
+        write!(f, "the clause {clause} is required by the clause {required_by}")
+      }
+      BuildError::UnsupportedClause { clause, dialect } => {
+        write!(f, "the clause {clause} is not supported by {dialect}")
+      }
+    }
+  }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Errors returned by the `parse` methods when a statement can't be split into the clauses of the builder,
+/// the positions are byte offsets of the parsed SQL
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+  /// The clause is defined without content, like a `WHERE` followed by the end of the statement
+  EmptyClause { clause: String, position: usize },
+  /// More than one statement separated by semicolon, the position is where the second statement begins
+  MultipleStatements { position: usize },
+  /// A quote, comment or parenthesis opened at the position isn't closed, or a parenthesis is closed without be opened
+  Unbalanced { position: usize },
+  /// The clause is out of the order rendered by the builder or is defined more than once
+  UnexpectedClause { clause: String, position: usize },
+  /// The statement doesn't begin with a clause of the builder, like an `UPDATE` parsed by the [Select](crate::Select)
+  UnexpectedStatement { expected: String },
+  /// The clause can't be represented by the builder, like a `FOR UPDATE` or a `WINDOW`, or would be rendered
+  /// in another place, like the order by of a compound select moved into its last member
+  UnsupportedClause { clause: String, position: usize },
+}
+
+impl ParseError {
+  /// Moves the positions of an error found in a slice of the statement, `offset` is where the slice begins
+  pub(crate) fn shift(self, offset: usize) -> Self {
+    match self {
+      Self::EmptyClause { clause, position } => Self::EmptyClause {
+        clause,
+        position: position + offset,
+      },
+      Self::MultipleStatements { position } => Self::MultipleStatements {
+        position: position + offset,
+      },
+      Self::Unbalanced { position } => Self::Unbalanced {
+        position: position + offset,
+      },
+      Self::UnexpectedClause { clause, position } => Self::UnexpectedClause {
+        clause,
+        position: position + offset,
+      },
+      Self::UnexpectedStatement { expected } => Self::UnexpectedStatement { expected },
+      Self::UnsupportedClause { clause, position } => Self::UnsupportedClause {
+        clause,
+        position: position + offset,
+      },
+    }
+  }
+}
+
+impl std::fmt::Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ParseError::EmptyClause { clause, position } => {
+        write!(f, "the clause {clause} at position {position} is empty")
+      }
+      ParseError::MultipleStatements { position } => {
+        write!(
+          f,
+          "expected a single statement, another one begins at position {position}"
+        )
+      }
+      ParseError::Unbalanced { position } => {
+        write!(f, "unbalanced quote, comment or parenthesis at position {position}")
+      }
+      ParseError::UnexpectedClause { clause, position } => {
+        write!(
+          f,
+          "the clause {clause} at position {position} is repeated or out of the order of the builder"
+        )
+      }
+      ParseError::UnexpectedStatement { expected } => {
+        write!(f, "expected a statement beginning with {expected}")
+      }
+      ParseError::UnsupportedClause { clause, position } => {
+        write!(
+          f,
+          "the clause {clause} at position {position} is not supported by the builder"
+        )
+      }
+    }
+  }
+}
+
+impl std::error::Error for ParseError {}
@@ -1,6 +1,12 @@
+use crate::{
+  dialect::Dialect,
+  lexer::{self, Token},
+};
+
 pub struct Formatter<'a> {
   pub comma: &'a str,
-  pub hr: &'a str, // horizontal rule
+  pub dialect: Option<Dialect>, // None renders the syntax enabled by the feature flags
+  pub hr: &'a str,              // horizontal rule
   pub indent: &'a str,
   pub lb: &'a str, // line break
   pub space: &'a str,
@@ -9,6 +15,7 @@ pub struct Formatter<'a> {
 pub fn one_line<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
+    dialect: None,
     hr: "",
     indent: "",
     lb: "",
@@ -19,82 +26,287 @@ pub fn one_line<'a>() -> Formatter<'a> {
 pub fn multiline<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
-    hr: "-- ------------------------------------------------------------------------------\x1b[0m",
+    dialect: None,
+    hr: "-- ------------------------------------------------------------------------------",
     indent: "  ",
     lb: "\n",
     space: " ",
   }
 }
 
-pub fn colorize(query: String) -> String {
-  let sql_syntax: [(fn(&str) -> String, &str, &str); 46] = [
-    (blue, "AND ", "and "),
-    (blue, "CROSS ", "cross "),
-    (blue, "DELETE ", "delete "),
-    (blue, "EXCEPT ", "except "),
-    (blue, "FROM ", "from "),
-    (blue, "FULL ", "full "),
-    (blue, "GROUP ", "group "),
-    (blue, "HAVING ", "having "),
-    (blue, "INNER ", "inner "),
-    (blue, "INSERT ", "insert "),
-    (blue, "INTERSECT ", "intersect "),
-    (blue, "INTO ", "into "),
-    (blue, "JOIN ", "join "),
-    (blue, "LEFT ", "left "),
-    (blue, "LIMIT ", "limit "),
-    (blue, "OFFSET ", "offset "),
-    (blue, "ORDER ", "order "),
-    (blue, "OVERRIDING ", "overriding "),
-    (blue, "RETURNING ", "returning "),
-    (blue, "RIGHT ", "right "),
-    (blue, "SELECT ", "select "),
-    (blue, "SET ", "set "),
-    (blue, "UNION ", "union "),
-    (blue, "UPDATE ", "update "),
-    (blue, "VALUES ", "values "),
-    (blue, "WHERE ", "where "),
-    (blue, "WITH ", "with "),
-    (blue, " ALL", " all"),
-    (blue, " ASC", " asc"),
-    (blue, " AS", " as"),
-    (blue, " BY", " by"),
-    (blue, " CONFLICT", " CONFLICT"),
-    (blue, " DESC", " desc"),
-    (blue, " DO", " do"),
-    (blue, " DISTINCT", " distinct"),
-    (blue, " FIRST", " first"),
-    (blue, " IN", " in"),
-    (blue, " LAST", " last"),
-    (blue, " NOTHING", " nothing"),
-    (blue, " ON", " on"),
-    (blue, " OR", " or"),
-    (blue, " OUTER", " OUTER"),
-    (blue, " USING", " using"),
-    (comment_start, "--", "--"),
-    (comment_start, "/*", "/*"),
-    (comment_end, "*/", "*/"),
-  ];
-
-  let mut query = sql_syntax.iter().fold(query, |acc, item| {
-    let (color_fn, text_upper, text_lower) = item;
-    acc
-      .replace(text_upper, &color_fn(text_upper))
-      .replace(text_lower, &color_fn(text_lower))
-  });
-
-  for index in 1..=10 {
-    let arg_number = format!("${index}");
-    query = query.replace(&arg_number, &bold(&arg_number))
+/// The case of the SQL keywords rendered by [FormatOptions]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeywordCase {
+  /// Converts the keywords to lowercase, like `select`
+  Lower,
+  /// Keeps the keywords as written, the builders write them in uppercase and the raw SQL is kept as is
+  #[default]
+  Preserve,
+  /// Converts the keywords to uppercase, like `SELECT`
+  Upper,
+}
+
+/// Where the comma is placed when a list is broken into lines by the max width of [FormatOptions]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CommaStyle {
+  /// The comma begins the next line, like `, login`
+  Leading,
+  /// The comma ends the current line, like `id,`
+  #[default]
+  Trailing,
+}
+
+/// The layout used by the `to_string_with` method of the builders, the default options render the same
+/// output of the `as_string` method
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let options = sql::FormatOptions::new()
+///   .keyword_case(sql::KeywordCase::Lower)
+///   .line_breaks(true);
+///
+/// let query = sql::Select::new()
+///   .select("id, login")
+///   .from("users")
+///   .where_clause("login = 'foo'")
+///   .to_string_with(&options);
+///
+/// # let expected = "select id, login\nfrom users\nwhere login = 'foo'";
+/// # assert_eq!(query, expected);
+/// ```
+///
+/// Output
+/// ```sql
+/// select id, login
+/// from users
+/// where login = 'foo'
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+  comma_style: CommaStyle,
+  indent: usize,
+  keyword_case: KeywordCase,
+  line_breaks: bool,
+  max_width: Option<usize>,
+}
+
+impl Default for FormatOptions {
+  fn default() -> Self {
+    Self {
+      comma_style: CommaStyle::Trailing,
+      indent: 2,
+      keyword_case: KeywordCase::Preserve,
+      line_breaks: false,
+      max_width: None,
+    }
+  }
+}
+
+impl FormatOptions {
+  /// Defines where the comma is placed when a list is broken by the max width, the default is [CommaStyle::Trailing]
+  pub fn comma_style(mut self, style: CommaStyle) -> Self {
+    self.comma_style = style;
+    self
   }
 
-  query
+  /// The number of spaces used to indent the nested lines, like the columns of a create table
+  /// and the continuation of the lines broken by the max width, the default is 2
+  pub fn indent(mut self, width: usize) -> Self {
+    self.indent = width;
+    self
+  }
+
+  /// Defines the case of the SQL keywords, the keywords inside quotes and comments are not changed,
+  /// the default is [KeywordCase::Preserve]
+  pub fn keyword_case(mut self, case: KeywordCase) -> Self {
+    self.keyword_case = case;
+    self
+  }
+
+  /// Renders each clause in its own line, the default is false
+  pub fn line_breaks(mut self, enabled: bool) -> Self {
+    self.line_breaks = enabled;
+    self
+  }
+
+  /// Breaks the lines longer than the width, the lines are broken after the commas of the lists when possible
+  /// and after the spaces otherwise, the words are never split. The default is no limit
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .max_width(20)
+  ///   .comma_style(sql::CommaStyle::Leading);
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id, login, name, email")
+  ///   .from("users")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "SELECT id, login\n  , name\n  , email FROM users";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// SELECT id, login
+  ///   , name
+  ///   , email FROM users
+  /// ```
+  pub fn max_width(mut self, width: usize) -> Self {
+    self.max_width = Some(width);
+    self
+  }
+
+  /// Creates the options with the default values
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Renders the query with the formatter described by the options, then applies the keyword case and the max width
+  pub(crate) fn format(&self, render: impl FnOnce(&Formatter) -> String) -> String {
+    let indent = " ".repeat(self.indent);
+    let fmts = Formatter {
+      indent: if self.line_breaks { &indent } else { "" },
+      lb: if self.line_breaks { "\n" } else { "" },
+      ..one_line()
+    };
+    let query = render(&fmts);
+    let query = match self.keyword_case {
+      KeywordCase::Preserve => query,
+      case => change_keyword_case(&query, case),
+    };
+
+    lines(&query)
+      .into_iter()
+      .map(str::trim_end)
+      .map(|line| match self.max_width {
+        Some(width) => wrap(line, width, &indent, self.comma_style),
+        None => line.to_owned(),
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+/// Splits the query at the line breaks outside the quotes and the comments, a string or a quoted identifier
+/// with line breaks is kept whole in a single line
+fn lines(query: &str) -> Vec<&str> {
+  let mut lines = vec![];
+  let mut start = 0;
+  let mut position = 0;
+  for token in lexer::tokenize(query) {
+    if token == Token::Punctuation("\n") {
+      lines.push(&query[start..position]);
+      start = position + 1;
+    }
+    position += token.text().len();
+  }
+  lines.push(&query[start..]);
+  lines
+}
+
+fn change_keyword_case(query: &str, case: KeywordCase) -> String {
+  lexer::tokenize(query)
+    .into_iter()
+    .map(|token| match (token, case) {
+      (Token::Keyword(text), KeywordCase::Lower) => text.to_ascii_lowercase(),
+      (Token::Keyword(text), KeywordCase::Upper) => text.to_ascii_uppercase(),
+      (token, _) => token.text().to_owned(),
+    })
+    .collect()
+}
+
+/// Breaks the line where it exceeds the width, the breaks after a comma outside parentheses are preferred,
+/// then the spaces outside parentheses, then the commas and the spaces inside them
+fn wrap(line: &str, width: usize, indent: &str, comma_style: CommaStyle) -> String {
+  // (position of the comma or space, is comma, depth of parentheses)
+  let mut breaks = vec![];
+  let mut depth = 0_usize;
+  let mut position = 0;
+  let leading_spaces = line.len() - line.trim_start().len();
+  for token in lexer::tokenize(line) {
+    if let Token::Punctuation(text) = token {
+      match text {
+        "(" => depth += 1,
+        ")" => depth = depth.saturating_sub(1),
+        "," if line[position + 1..].starts_with(' ') => breaks.push((position, true, depth)),
+        " " if position > leading_spaces && line[..position].ends_with(',') == false => {
+          breaks.push((position, false, depth))
+        }
+        _ => {}
+      }
+    }
+    position += token.text().len();
+  }
+
+  let continuation = format!("{}{indent}", &line[..leading_spaces]);
+  let mut output = String::new();
+  let mut prefix = "".to_owned();
+  let mut start = 0;
+  loop {
+    let available = width.saturating_sub(prefix.chars().count());
+    if line[start..].chars().count() <= available {
+      break;
+    }
+    // (end of the current line, beginning of the next line)
+    let cut = |&(position, is_comma, _): &(usize, bool, usize)| match (is_comma, comma_style) {
+      (true, CommaStyle::Trailing) => (position + 1, position + 2),
+      (true, CommaStyle::Leading) => (position, position + 2),
+      (false, _) => (position, position + 1),
+    };
+    let fits = |item: &&(usize, bool, usize)| {
+      let (end, _) = cut(item);
+      item.0 > start && line[start..end].chars().count() <= available
+    };
+    let best = [(true, Some(0)), (false, Some(0)), (true, None), (false, None)]
+      .into_iter()
+      .find_map(|(is_comma, depth)| {
+        breaks
+          .iter()
+          .rfind(|item| fits(item) && item.1 == is_comma && depth.is_none_or(|depth| item.2 == depth))
+      })
+      .or_else(|| breaks.iter().find(|item| item.0 > start));
+    let Some(item) = best else {
+      break;
+    };
+    let (end, next) = cut(item);
+    output.push_str(&prefix);
+    output.push_str(&line[start..end]);
+    output.push('\n');
+    prefix = match (item.1, comma_style) {
+      (true, CommaStyle::Leading) => format!("{continuation}, "),
+      _ => continuation.clone(),
+    };
+    start = next;
+  }
+  output.push_str(&prefix);
+  output.push_str(&line[start..]);
+
+  output
 }
 
-pub fn format(query: String, fmts: &Formatter) -> String {
-  let template = format!("{0}{1}{0}{query}{0}{1}{0}", fmts.lb, fmts.hr);
-  let template = colorize(template);
-  template
+/// Highlights the query by the type of each token, the identifiers and the punctuation are not colored
+pub fn highlight(query: &str) -> String {
+  lexer::tokenize(query)
+    .into_iter()
+    .map(|token| match token {
+      Token::Comment(text) => comment(text),
+      Token::Keyword(text) => blue(text),
+      Token::Literal(text) => yellow(text),
+      Token::Placeholder(text) => bold(text),
+      Token::Identifier(text) | Token::Punctuation(text) => text.to_owned(),
+    })
+    .collect()
+}
+
+/// Surrounds the query by the horizontal rules of the formatter
+pub fn frame(query: String, fmts: &Formatter) -> String {
+  format!("{0}{1}{0}{query}{0}{1}{0}", fmts.lb, fmts.hr)
 }
 
 fn blue(text: &str) -> String {
@@ -105,10 +317,10 @@ fn bold(text: &str) -> String {
   format!("\x1b[0;1m{text}\x1b[0m")
 }
 
-fn comment_start(text: &str) -> String {
-  format!("\x1b[32;2m{text}")
+fn comment(text: &str) -> String {
+  format!("\x1b[32;2m{text}\x1b[0m")
 }
 
-fn comment_end(text: &str) -> String {
-  format!("\x1b[32;2m{text}\x1b[0m")
+fn yellow(text: &str) -> String {
+  format!("\x1b[33m{text}\x1b[0m")
 }
@@ -0,0 +1,115 @@
+use crate::dialect::Dialect;
+
+/// A table, column or schema name quoted when the query is rendered, Postgres and SQLite use double quotes
+/// while MySQL uses backticks, the quote character inside the name is escaped by doubling it.
+///
+/// The builder methods receive an `Ident` anywhere a text is accepted and the identifier can be composed with other
+/// texts with the [Sql::push](crate::Sql::push) method, the quotes follow the dialect of the `to_sql` and `build_with`
+/// methods, the other methods quote like the syntax enabled by the feature flags
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select(sql::Ident::new("userId"))
+///   .from(sql::Ident::path(["public", "order"]));
+///
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Postgres).unwrap(),
+///   r#"SELECT "userId" FROM "public"."order""#
+/// );
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Mysql).unwrap(),
+///   "SELECT `userId` FROM `public`.`order`"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ident {
+  path: Vec<String>,
+}
+
+impl Ident {
+  /// Creates an identifier of a single name, a dot inside the name is part of the name
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new().delete_from(sql::Ident::new("my.table")).as_string();
+  ///
+  /// # #[cfg(not(all(feature = "mysql", not(feature = "postgresql"))))]
+  /// # assert_eq!(query, r#"DELETE FROM "my.table""#);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DELETE FROM "my.table"
+  /// ```
+  pub fn new(name: impl Into<String>) -> Self {
+    Self {
+      path: vec![name.into()],
+    }
+  }
+
+  /// Creates a qualified identifier like `schema.table` or `schema.table.column`, each name is quoted
+  /// separately and joined by dots
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update(sql::Ident::path(["sales", "order"]))
+  ///   .set("status = 'paid'")
+  ///   .as_string();
+  ///
+  /// # #[cfg(not(all(feature = "mysql", not(feature = "postgresql"))))]
+  /// # assert_eq!(query, r#"UPDATE "sales"."order" SET status = 'paid'"#);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE "sales"."order" SET status = 'paid'
+  /// ```
+  pub fn path<Name: Into<String>>(names: impl IntoIterator<Item = Name>) -> Self {
+    Self {
+      path: names.into_iter().map(Into::into).collect(),
+    }
+  }
+
+  /// Returns the quoted identifier in the syntax of the dialect
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let ident = sql::Ident::path(["public", "user\"s"]);
+  ///
+  /// assert_eq!(ident.to_sql(sql::Dialect::Sqlite), r#""public"."user""s""#);
+  /// assert_eq!(ident.to_sql(sql::Dialect::Mysql), "`public`.`user\"s`");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    quote_path(&self.path, Some(dialect))
+  }
+
+  /// The names of the path, unquoted
+  #[cfg(feature = "serde")]
+  pub(crate) fn names(&self) -> &[String] {
+    &self.path
+  }
+}
+
+/// Quotes each name of the path, a query rendered without a dialect uses the quotes of the feature flags
+pub(crate) fn quote_path(path: &[impl AsRef<str>], dialect: Option<Dialect>) -> String {
+  let dialect = dialect.unwrap_or_else(Dialect::of_features);
+
+  path
+    .iter()
+    .map(|name| dialect.quote(name.as_ref()))
+    .collect::<Vec<_>>()
+    .join(".")
+}
@@ -1,10 +1,48 @@
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
+  behavior::{defined, trim, Concat, WithQuery},
+  bind::{bind, Params, PlaceholderMap, Value},
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::{BuildError, ParseError},
+  fmt::{self, FormatOptions},
+  parse,
+  sink::{self, IoSink, Sink},
+  sql::Sql,
   structure::{Insert, InsertClause, Select},
 };
+use std::borrow::Cow;
 
 impl<'a> Insert<'a> {
+  /// Calls the function with the Insert and returns its result, useful to reuse a set of method calls
+  /// without breaking the method chaining
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn second_row(query: sql::Insert) -> sql::Insert {
+  ///   query.values("('bar')")
+  /// }
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .apply(second_row)
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo'), ('bar')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo'), ('bar')
+  /// ```
+  pub fn apply(self, f: impl FnOnce(Self) -> Self) -> Self {
+    f(self)
+  }
+
   /// Gets the current state of the Insert and returns it as string
   ///
   /// # Examples
@@ -23,11 +61,110 @@ impl<'a> Insert<'a> {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    self.render(&fmts).0
+  }
+
+  /// Gets the current state of the Insert and returns it as string together with the bound values,
+  /// the placeholders are numbered in the order they appear in the query with the syntax enabled by the feature flags
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("(?, ?)", ("foo", "Foo"))
+  ///   .build();
+  ///
+  /// assert_eq!(values.len(), 2);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ($1, $2)
+  /// ```
+  ///
+  /// # Panics
+  /// Panics when the placeholders of a `*_bind` method don't match the values bound to it,
+  /// use [Insert::build_with] to get the [BuildError::BindMismatch] error instead
+  pub fn build(&self) -> (String, Vec<Value>) {
+    let fmts = fmt::one_line();
+    self.try_render(&fmts).unwrap_or_else(|error| panic!("{error}"))
+  }
+
+  /// Gets the current state of the [Insert] rendered for the given dialect together with the bound values,
+  /// returns an error when a clause defined in the query isn't supported by the dialect, see [Dialect] for the details
+  /// or [BuildError::BindMismatch] when the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("(?, ?)", ("foo", "Foo"))
+  ///   .build_with(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, name) VALUES (?1, ?2)");
+  /// assert_eq!(values.len(), 2);
+  /// ```
+  pub fn build_with(&self, dialect: Dialect) -> Result<(String, Vec<Value>), BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    self.try_render(&fmts)
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .raw_after(sql::InsertClause::Values, "/* foo */")
+  ///   .clear(sql::InsertClause::Values)
+  ///   .select(sql::Select::new().select("login").from("users_bk"))
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) SELECT login FROM users_bk";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) SELECT login FROM users_bk
+  /// ```
+  pub fn clear(mut self, clause: InsertClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      InsertClause::InsertInto => self._insert_into = "".into(),
+      InsertClause::OnConflict => self._on_conflict = "".into(),
+      InsertClause::Overriding => self._overriding = "".into(),
+      InsertClause::Select => self._select = None,
+      InsertClause::Values => self._values.clear(),
+      InsertClause::Returning => self._returning.clear(),
+      InsertClause::With => self._with.clear(),
+      InsertClause::InsertOr => self._insert_or = "".into(),
+      InsertClause::InsertIgnoreInto => self._insert_ignore_into = "".into(),
+      InsertClause::OnDuplicateKeyUpdate => self._on_duplicate_key_update.clear(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
   }
 
   /// Prints the current state of the Insert into console output in a more ease to read version.
-  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
   ///
   /// # Examples
   /// ```
@@ -48,8 +185,55 @@ impl<'a> Insert<'a> {
   /// VALUES ('foo', 'Foo')
   /// ```
   pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Insert] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Dedupe)
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .values("('foo')")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
     self
   }
 
@@ -66,36 +250,272 @@ impl<'a> Insert<'a> {
   ///   .insert_into("address (state, country)")
   ///   .insert_into("users (login, name)");
   /// ```
-  pub fn insert_into(mut self, table_name: &'a str) -> Self {
-    self._insert_into = table_name.trim();
+  pub fn insert_into(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._insert_into = table_name.into().trim();
     self
   }
 
+  /// The insert ignore into clause, supported by MySQL.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_ignore_into("users (login, name)")
+  ///   .values("('foo', 'Foo')");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT IGNORE INTO users (login, name) VALUES ('foo', 'Foo')
+  /// ```
+  pub fn insert_ignore_into(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._insert_ignore_into = table_name.into().trim();
+    self
+  }
+
+  /// The insert or clause, supported by SQLite.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_or("replace into users (login, name)")
+  ///   .values("('foo', 'Foo')");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT OR replace into users (login, name) VALUES ('foo', 'Foo')
+  /// ```
+  pub fn insert_or(mut self, expression: impl Into<Sql<'a>>) -> Self {
+    self._insert_or = expression.into().trim();
+    self
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn insert_user(table: &str) -> sql::Insert<'static> {
+  ///   sql::Insert::new()
+  ///     .insert_into(table)
+  ///     .values("('foo')")
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = insert_user(&String::from("users (login)")).as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn into_owned(self) -> Insert<'static> {
+    Insert {
+      _duplicates: self._duplicates,
+      _insert_ignore_into: self._insert_ignore_into.into_owned(),
+      _insert_into: self._insert_into.into_owned(),
+      _insert_or: self._insert_or.into_owned(),
+      _on_conflict: self._on_conflict.into_owned(),
+      _on_duplicate_key_update: self._on_duplicate_key_update,
+      _overriding: self._overriding.into_owned(),
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+      _returning: self._returning,
+      _select: self._select.map(Select::into_owned),
+      _values: self._values,
+      _with: crate::behavior::owned_with(self._with),
+    }
+  }
+
   /// Create Insert's instance
   pub fn new() -> Self {
     Self::default()
   }
 
   /// The on conflict clause. This method overrides the previous value
-  pub fn on_conflict(mut self, conflict: &'a str) -> Self {
-    self._on_conflict = conflict.trim();
+  pub fn on_conflict(mut self, conflict: impl Into<Sql<'a>>) -> Self {
+    self._on_conflict = conflict.into().trim();
+    self
+  }
+
+  /// The value of the on conflict clause, `None` when the clause is not defined
+  pub fn on_conflict_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._on_conflict)
+  }
+
+  /// The on duplicate key update clause, supported by MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_duplicate_key_update("name = VALUES(name)")
+  ///   .on_duplicate_key_update("updated_at = now()");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login, name)
+  /// VALUES ('foo', 'Foo')
+  /// ON DUPLICATE KEY UPDATE name = VALUES(name), updated_at = now()
+  /// ```
+  pub fn on_duplicate_key_update<'s>(mut self, assignment: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(InsertClause::OnDuplicateKeyUpdate),
+      &mut self._on_duplicate_key_update,
+      assignment.into().trim().into_owned(),
+    );
     self
   }
 
+  /// The assignments of the on duplicate key update clause
+  pub fn on_duplicate_key_update_items(&self) -> &[Sql<'static>] {
+    &self._on_duplicate_key_update
+  }
+
   /// The overriding clause. This method overrides the previous value
-  pub fn overriding(mut self, option: &'a str) -> Self {
-    self._overriding = option.trim();
+  pub fn overriding(mut self, option: impl Into<Sql<'a>>) -> Self {
+    self._overriding = option.into().trim();
     self
   }
 
+  /// The value of the overriding clause, `None` when the clause is not defined
+  pub fn overriding_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._overriding)
+  }
+
+  /// Parses a SQL statement into the clauses of the [Insert] builder, so the statement can be changed with the builder methods.
+  /// The text of each clause is kept as written, a comment before the statement is kept as a raw SQL
+  /// and the queries of the `with` clause are parsed by the builder of its statement.
+  /// See [ParseError] for the statements that can't be split into the clauses of the builder.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::parse("INSERT INTO users (login, name) VALUES ('foo', 'Foo')")
+  ///   .unwrap()
+  ///   .values("('bar', 'Bar')");
+  ///
+  /// # let expected = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')";
+  /// # assert_eq!(insert.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')
+  /// ```
+  pub fn parse(sql: &'a str) -> Result<Self, ParseError> {
+    let statement = parse::statement(sql)?;
+    parse::nested(sql, statement, Self::parse_statement)
+  }
+
+  /// Returns how the positional placeholders (`$1`, `$2`, ...) written in each composed builder were renumbered
+  /// in the final query, see [PlaceholderMap] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .select(sql::Select::new().select("login").from("users_bk").where_clause("id = $1"))
+  ///   .on_conflict("do nothing");
+  ///
+  /// let map = insert.placeholder_map();
+  ///
+  /// assert_eq!(map.get(1, 1), Some(1));
+  /// ```
+  pub fn placeholder_map(&self) -> PlaceholderMap {
+    let fmts = fmt::one_line();
+    self.placeholders(&fmts)
+  }
+
   /// Prints the current state of the Insert into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Insert] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo')\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
     self
   }
 
+  /// Replaces the values of the values clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .replace_values("('bar')")
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('bar')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('bar')
+  /// ```
+  pub fn replace_values<'s>(mut self, value: impl Into<Sql<'s>>) -> Self {
+    self._values.clear();
+    self._duplicates.clear(Some(InsertClause::Values));
+    self.values(value)
+  }
+
+  /// The output names of the returning clause
+  pub fn returning_items(&self) -> &[Sql<'static>] {
+    &self._returning
+  }
+
   /// The select clause. This method overrides the previous value
   ///
   /// # Examples
@@ -145,8 +565,10 @@ impl<'a> Insert<'a> {
   /// insert into users (login, name)
   /// VALUES ('bar', 'Bar')
   /// ```
-  pub fn raw(mut self, raw_sql: &'a str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
     self
   }
 
@@ -169,8 +591,8 @@ impl<'a> Insert<'a> {
   /// INSERT INTO users (login, name)
   /// values ('foo', 'Foo')
   /// ```
-  pub fn raw_after(mut self, clause: InsertClause, raw_sql: &'a str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_after(mut self, clause: InsertClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
@@ -193,25 +615,254 @@ impl<'a> Insert<'a> {
   /// insert into users (login, name)
   /// VALUES ('bar', 'Bar')
   /// ```
-  pub fn raw_before(mut self, clause: InsertClause, raw_sql: &'a str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_before(mut self, clause: InsertClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
-  /// The returning clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
-  pub fn returning(mut self, output_name: &'a str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_owned());
+  /// The returning clause, supported by Postgres and SQLite
+  pub fn returning<'s>(mut self, output_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(InsertClause::Returning),
+      &mut self._returning,
+      output_name.into().trim().into_owned(),
+    );
     self
   }
 
+  /// The select used as the values of the insert, `None` when the clause is not defined
+  pub fn select_query(&self) -> Option<&Select<'a>> {
+    self._select.as_ref()
+  }
+
+  /// The table of the insert into clause without the list of columns, `None` when the clause is not defined.
+  /// The tables of the `insert_or` and `insert_ignore_into` methods are returned as well
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')");
+  ///
+  /// assert_eq!(insert.target_table().unwrap(), "users");
+  /// ```
+  pub fn target_table(&self) -> Option<Sql<'static>> {
+    let target = defined(&self._insert_into)
+      .map(|target| target.slice(0..target.len()))
+      .or_else(|| {
+        let lowercase = self._insert_or.text().to_ascii_lowercase();
+        let start = lowercase.find(" into ")? + " into ".len();
+        Some(self._insert_or.slice(start..self._insert_or.len()))
+      })
+      .or_else(|| defined(&self._insert_ignore_into).map(|target| target.slice(0..target.len())))?;
+    let end = target.text().find('(').unwrap_or(target.len());
+    let table = target.slice(0..end).trim().into_owned();
+
+    (table.is_empty() == false).then_some(table)
+  }
+
+  /// Gets the current state of the [Insert] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("(?, ?)", ("foo", "Foo"))
+  ///   .to_sql(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, name) VALUES (?, ?)");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.build_with(dialect).map(|(query, _)| query)
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "insert into users (login, name)\nvalues\n('foo', 'Foo')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// insert into users (login, name)
+  /// values
+  /// ('foo', 'Foo')
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [Insert] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [Insert::validate] for the checks, or [BuildError::BindMismatch] when
+  /// the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo')
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(|(query, _)| query)
+  }
+
+  /// Checks the current state of the [Insert], returns an error naming the clause
+  /// that makes the query invalid, like the values and the select defined together, or the values without the insert into clause.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .select(sql::Select::new().select("login").from("users_bk"))
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::ConflictingClauses {
+  ///     clause: sql::Clause::Insert(sql::InsertClause::Values),
+  ///     other: sql::Clause::Insert(sql::InsertClause::Select),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(drop)
+  }
+
   /// The values clause
-  pub fn values(mut self, value: &'a str) -> Self {
-    push_unique(&mut self._values, value.trim().to_owned());
+  pub fn values<'s>(mut self, value: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push_row(
+      InsertClause::Values,
+      &mut self._values,
+      value.into().trim().into_owned(),
+    );
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The values clause with bound values, each `?` of the value is replaced by a placeholder
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("(?, ?)", ("foo", "Foo"))
+  ///   .values_bind("(?, ?)", ("bar", "Bar"));
+  /// ```
+  ///
+  /// The value with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn values_bind<'s>(mut self, value: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self
+      ._duplicates
+      .push_row(InsertClause::Values, &mut self._values, bind(value, params));
+    self
+  }
+
+  /// The rows of the values clause in the order they were added
+  pub fn values_items(&self) -> &[Sql<'static>] {
+    &self._values
+  }
+
+  /// Calls the function with the Insert only when the condition is true, otherwise the Insert is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let condition = true;
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .when(condition, |query| query.values("('bar')"))
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo'), ('bar')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo'), ('bar')
+  /// ```
+  pub fn when(self, condition: bool, f: impl FnOnce(Self) -> Self) -> Self {
+    if condition {
+      f(self)
+    } else {
+      self
+    }
+  }
+
+  /// Calls the function with the Insert and the value of the option only when the option is `Some`,
+  /// otherwise the Insert is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let login = Some("foo");
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .when_some(login, |query, login| query.values(format!("('{login}')")))
+  ///   .as_string();
+  ///
+  /// # let expected = "INSERT INTO users (login) VALUES ('foo'), ('foo')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// INSERT INTO users (login) VALUES ('foo'), ('foo')
+  /// ```
+  pub fn when_some<T>(self, value: Option<T>, f: impl FnOnce(Self, T) -> Self) -> Self {
+    match value {
+      Some(value) => f(self, value),
+      None => self,
+    }
+  }
+
+  /// The with clause, supported by Postgres and SQLite
   ///
   /// # Examples
   /// ```
@@ -237,14 +888,23 @@ impl<'a> Insert<'a> {
   /// SELECT *
   /// FROM active_users
   /// ```
-  #[cfg(feature = "postgresql")]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Cow<'a, str>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((trim(name), std::sync::Arc::new(query)));
     self
   }
+
+  /// The names of the queries of the with clause in the order they were added
+  pub fn with_ctes(&self) -> impl Iterator<Item = &str> {
+    self._with.iter().map(|(name, _)| name.as_ref())
+  }
 }
 
-impl WithQuery for Insert<'_> {}
+impl WithQuery for Insert<'_> {
+  #[cfg(feature = "serde")]
+  fn tagged(&self) -> crate::serialize::TaggedQuery<'_> {
+    crate::serialize::TaggedQuery::Insert(self)
+  }
+}
 
 impl std::fmt::Display for Insert<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -255,6 +915,6 @@ impl std::fmt::Display for Insert<'_> {
 impl std::fmt::Debug for Insert<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
   }
 }
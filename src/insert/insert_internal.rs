@@ -1,130 +1,345 @@
 use crate::{
-  behavior::{concat_raw_before_after, Concat, ConcatMethods},
-  fmt,
-  structure::{Insert, InsertClause},
+  behavior::{
+    append, check_clauses, check_required, concat_raw_before_after, raw_defines, trim_end_from, Concat, ConcatMethods,
+  },
+  bind,
+  dialect::Dialect,
+  error::{BuildError, ParseError},
+  fmt, parse,
+  sql::Sql,
+  structure::{Insert, InsertClause, Select},
 };
 
 impl<'a> ConcatMethods<'a, InsertClause> for Insert<'_> {}
 
 impl Concat for Insert<'_> {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_owned();
-
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        InsertClause::With,
-        &self._with,
-      );
-    }
-    query = self.concat_insert_into(query, &fmts);
-    query = self.concat_overriding(query, &fmts);
-    query = self.concat_values(
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_with(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::With,
+      &self._with,
+    );
+    self.concat_insert_into(query, fmts);
+    self.concat_insert_or(query, fmts);
+    self.concat_insert_ignore_into(query, fmts);
+    self.concat_overriding(query, fmts);
+    self.concat_values(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       InsertClause::Values,
       &self._values,
     );
-    query = self.concat_select(query, &fmts);
-    query = self.concat_on_conflict(query, &fmts);
-
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
+    self.concat_select(query, fmts);
+    self.concat_on_conflict(query, fmts);
+    self.concat_on_duplicate_key_update(query, fmts);
+
+    self.concat_returning(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::Returning,
+      &self._returning,
+    );
+
+    trim_end_from(query, start);
+  }
+
+  fn check_dialect(&self, dialect: Dialect) -> Result<(), BuildError> {
+    let clauses: Vec<(bool, InsertClause, &'static [Dialect])> = vec![
+      (
+        self._on_conflict.is_empty() == false,
+        InsertClause::OnConflict,
+        &[Dialect::Postgres, Dialect::Sqlite],
+      ),
+      (
+        self._overriding.is_empty() == false,
+        InsertClause::Overriding,
+        &[Dialect::Postgres],
+      ),
+      (
+        self._returning.is_empty() == false,
         InsertClause::Returning,
-        &self._returning,
-      );
+        &[Dialect::Postgres, Dialect::Sqlite],
+      ),
+      (
+        self._with.is_empty() == false,
+        InsertClause::With,
+        &[Dialect::Postgres, Dialect::Sqlite],
+      ),
+      (
+        self._insert_or.is_empty() == false,
+        InsertClause::InsertOr,
+        &[Dialect::Sqlite],
+      ),
+      (
+        self._insert_ignore_into.is_empty() == false,
+        InsertClause::InsertIgnoreInto,
+        &[Dialect::Mysql],
+      ),
+      (
+        self._on_duplicate_key_update.is_empty() == false,
+        InsertClause::OnDuplicateKeyUpdate,
+        &[Dialect::Mysql],
+      ),
+    ];
+    check_clauses(dialect, clauses)?;
+    for (_, query) in &self._with {
+      query.check_dialect(dialect)?;
+    }
+    if let Some(select) = &self._select {
+      select.check_dialect(dialect)?;
+    }
+
+    Ok(())
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let heads: Vec<(bool, InsertClause)> = vec![
+      (self._insert_into.is_empty() == false, InsertClause::InsertInto),
+      (self._insert_or.is_empty() == false, InsertClause::InsertOr),
+      (
+        self._insert_ignore_into.is_empty() == false,
+        InsertClause::InsertIgnoreInto,
+      ),
+    ];
+    let mut defined_heads = heads.into_iter().filter(|(is_defined, _)| *is_defined);
+    if let (Some((_, head)), Some((_, other))) = (defined_heads.next(), defined_heads.next()) {
+      return Err(BuildError::conflicting_clauses(head, other));
+    }
+    if self._values.is_empty() == false && self._select.is_some() {
+      return Err(BuildError::conflicting_clauses(
+        InsertClause::Values,
+        InsertClause::Select,
+      ));
+    }
+
+    let has_raw = |clause, keywords: &[&'static str]| {
+      raw_defines(&self._raw, &self._raw_before, &self._raw_after, clause, keywords)
+    };
+    let has_head = self._insert_into.is_empty() == false
+      || self._insert_or.is_empty() == false
+      || self._insert_ignore_into.is_empty() == false;
+    let has_values = self._values.is_empty() == false || self._select.is_some();
+    let clauses: Vec<(bool, InsertClause)> = vec![
+      (self._with.is_empty() == false, InsertClause::With),
+      (self._overriding.is_empty() == false, InsertClause::Overriding),
+      (self._values.is_empty() == false, InsertClause::Values),
+      (self._select.is_some(), InsertClause::Select),
+      (self._on_conflict.is_empty() == false, InsertClause::OnConflict),
+      (
+        self._on_duplicate_key_update.is_empty() == false,
+        InsertClause::OnDuplicateKeyUpdate,
+      ),
+      (self._returning.is_empty() == false, InsertClause::Returning),
+    ];
+    check_required(
+      (
+        has_head || has_raw(InsertClause::InsertInto, &["INSERT", "REPLACE"]),
+        InsertClause::InsertInto,
+      ),
+      clauses,
+    )?;
+    check_required(
+      (
+        has_values || has_raw(InsertClause::Values, &["VALUES", "SELECT"]),
+        InsertClause::Values,
+      ),
+      [(has_head, InsertClause::InsertInto)],
+    )?;
+    for (_, query) in &self._with {
+      query.check_query()?;
+    }
+    if let Some(select) = &self._select {
+      select.check_query()?;
     }
 
-    query.trim_end().to_owned()
+    Ok(())
   }
 }
 
 impl Insert<'_> {
-  fn concat_insert_into(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._insert_into.is_empty() == false {
-      let insert_into = self._insert_into;
-      format!("INSERT INTO{space}{insert_into}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(InsertClause::OnDuplicateKeyUpdate), &self._on_duplicate_key_update)?;
+    duplicates.check(Some(InsertClause::Returning), &self._returning)?;
+    duplicates.check(Some(InsertClause::Values), &self._values)?;
+    duplicates.check(None, &self._raw)
+  }
 
+  fn concat_insert_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       InsertClause::InsertInto,
-      sql,
-    )
+      |query| {
+        if self._insert_into.is_empty() == false {
+          append(query, &["INSERT INTO", space]);
+          query.append(&self._insert_into);
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_overriding(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_insert_or(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._overriding.is_empty() == false {
-      let overriding = self._overriding;
-      format!("OVERRIDING{space}{overriding}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::InsertOr,
+      |query| {
+        if self._insert_or.is_empty() == false {
+          append(query, &["INSERT OR", space]);
+          query.append(&self._insert_or);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
 
+  fn concat_insert_ignore_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
-      InsertClause::Overriding,
-      sql,
-    )
+      InsertClause::InsertIgnoreInto,
+      |query| {
+        if self._insert_ignore_into.is_empty() == false {
+          append(query, &["INSERT IGNORE INTO", space]);
+          query.append(&self._insert_ignore_into);
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_on_conflict(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_overriding(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._on_conflict.is_empty() == false {
-      let overriding = self._on_conflict;
-      format!("ON CONFLICT{space}{overriding}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::Overriding,
+      |query| {
+        if self._overriding.is_empty() == false {
+          append(query, &["OVERRIDING", space]);
+          query.append(&self._overriding);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
 
+  fn concat_on_conflict(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       InsertClause::OnConflict,
-      sql,
-    )
+      |query| {
+        if self._on_conflict.is_empty() == false {
+          append(query, &["ON CONFLICT", space]);
+          query.append(&self._on_conflict);
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if let Some(select) = &self._select {
-      let select_string = select.concat(fmts);
-      format!("{select_string}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
+  fn concat_on_duplicate_key_update(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::OnDuplicateKeyUpdate,
+      |query| {
+        if self._on_duplicate_key_update.is_empty() == false {
+          append(query, &["ON DUPLICATE KEY UPDATE", space]);
+          crate::behavior::append_list(query, &self._on_duplicate_key_update, comma);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
 
+  fn concat_select(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { lb, space, .. } = fmts;
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       InsertClause::Select,
-      sql,
-    )
+      |query| {
+        if let Some(select) = &self._select {
+          bind::scope(query, |query| select.concat_into(query, fmts));
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
+}
+
+const CLAUSES: &[&[&str]] = &[
+  &["WITH"],
+  &["INSERT INTO"],
+  &["INSERT OR"],
+  &["INSERT IGNORE INTO"],
+  &["OVERRIDING"],
+  &["VALUES"],
+  &["SELECT"],
+  &["ON CONFLICT"],
+  &["ON DUPLICATE KEY UPDATE"],
+  &["RETURNING"],
+];
+
+impl<'a> Insert<'a> {
+  /// Parses an insert, the select clause is parsed by the [Select] builder until the next clause of the insert
+  pub(crate) fn parse_statement(sql: &'a str) -> Result<Self, ParseError> {
+    let (leading, clauses) = parse::split_sequence(sql, CLAUSES)?;
+    let mut insert = Self {
+      _raw: parse::raw(leading, "INSERT")?,
+      ..Default::default()
+    };
+
+    for clause in clauses {
+      let body = clause.body;
+      match clause.keyword {
+        "WITH" => insert._with = parse::with_items(sql, &clause)?,
+        "INSERT INTO" => insert._insert_into = body.into(),
+        "INSERT OR" => insert._insert_or = body.into(),
+        "INSERT IGNORE INTO" => insert._insert_ignore_into = body.into(),
+        "OVERRIDING" => insert._overriding = body.into(),
+        "VALUES" => insert._values = parse::list(body)?,
+        "SELECT" => insert._select = Some(parse::nested(sql, clause.source, Select::parse_statement)?),
+        "ON CONFLICT" => insert._on_conflict = body.into(),
+        "ON DUPLICATE KEY UPDATE" => insert._on_duplicate_key_update = parse::list(body)?,
+        _ => insert._returning = parse::list(body)?,
+      }
+    }
+
+    Ok(insert)
   }
 }
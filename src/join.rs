@@ -0,0 +1,432 @@
+use crate::{
+  behavior::{append, append_list, Concat},
+  bind,
+  condition::Condition,
+  fmt, parse,
+  sql::Sql,
+  structure::Select,
+};
+use std::hash::{Hash, Hasher};
+
+/// The type of a [Join], rendered as the keyword that begins the join
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new().from("users u").left_join("orders o ON o.user_id = u.id");
+///
+/// assert_eq!(select.join_items()[0].kind(), sql::JoinKind::Left);
+/// assert_eq!(sql::JoinKind::Left.keyword(), "LEFT JOIN");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum JoinKind {
+  Cross,
+  FullOuter,
+  Inner,
+  /// The join without type, like the `JOIN LATERAL` of the [join_lateral](Select::join_lateral) method
+  Join,
+  Left,
+  Natural,
+  Right,
+}
+
+impl JoinKind {
+  /// The keyword of the join in uppercase, like `LEFT JOIN`
+  pub fn keyword(&self) -> &'static str {
+    match self {
+      Self::Cross => "CROSS JOIN",
+      Self::FullOuter => "FULL OUTER JOIN",
+      Self::Inner => "INNER JOIN",
+      Self::Join => "JOIN",
+      Self::Left => "LEFT JOIN",
+      Self::Natural => "NATURAL JOIN",
+      Self::Right => "RIGHT JOIN",
+    }
+  }
+
+  /// The type of a join keyword of the parsed statements, the keyword is in uppercase with one space between the words
+  fn of_keyword(keyword: &str) -> Self {
+    match keyword {
+      "CROSS JOIN" => Self::Cross,
+      "FULL JOIN" | "FULL OUTER JOIN" => Self::FullOuter,
+      "INNER JOIN" => Self::Inner,
+      "LEFT JOIN" | "LEFT OUTER JOIN" => Self::Left,
+      "NATURAL JOIN" => Self::Natural,
+      "RIGHT JOIN" | "RIGHT OUTER JOIN" => Self::Right,
+      _ => Self::Join,
+    }
+  }
+}
+
+/// A join built from its parts, the table or subquery, the alias and the `ON` conditions or the `USING` columns.
+/// The join is added to the [Select] builder with the [join](Select::join) method, a subquery is kept
+/// as a builder and rendered with the options of the query that contains it
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let totals = sql::Select::new()
+///   .select("user_id, sum(total) AS total")
+///   .from("orders")
+///   .group_by("user_id");
+///
+/// let query = sql::Select::new()
+///   .select("u.login, t.total")
+///   .from("users u")
+///   .join(sql::Join::left(totals).alias("t").on("t.user_id = u.id"))
+///   .join(sql::Join::inner("addresses").using(["user_id"]))
+///   .as_string();
+///
+/// # let expected = "\
+/// #   SELECT u.login, t.total \
+/// #   FROM users u \
+/// #   LEFT JOIN (SELECT user_id, sum(total) AS total FROM orders GROUP BY user_id) AS t ON t.user_id = u.id \
+/// #   INNER JOIN addresses USING (user_id)\
+/// # ";
+/// # assert_eq!(query, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT u.login, t.total
+/// FROM users u
+/// LEFT JOIN (SELECT user_id, sum(total) AS total FROM orders GROUP BY user_id) AS t ON t.user_id = u.id
+/// INNER JOIN addresses USING (user_id)
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Join {
+  kind: JoinKind,
+  #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+  keyword: Option<String>, // the keyword as written in a parsed statement
+  lateral: bool,
+  source: Source,
+  alias: Sql<'static>,
+  constraint: Constraint,
+}
+
+/// A [Join] without constraint, the `CROSS JOIN` and the `NATURAL JOIN` don't accept the `ON` conditions
+/// or the `USING` columns. Converted into a [Join] by the [join](Select::join) method
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Select::new()
+///   .select("*")
+///   .from("orders")
+///   .join(sql::Join::natural("order_items").alias("i"))
+///   .as_string();
+///
+/// # let expected = "SELECT * FROM orders NATURAL JOIN order_items AS i";
+/// # assert_eq!(query, expected);
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT * FROM orders NATURAL JOIN order_items AS i
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnconstrainedJoin(Join);
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+enum Source {
+  Table(Sql<'static>),
+  Select(Box<Select<'static>>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+enum Constraint {
+  None,
+  On(Vec<Condition>),
+  Using(Vec<Sql<'static>>),
+}
+
+/// The tables and subqueries accepted by the constructors of [Join], a text is used as written
+/// and a [Select] is rendered between parentheses
+pub trait JoinSource {
+  fn into_join(self, kind: JoinKind) -> Join;
+}
+
+impl<'s, T: Into<Sql<'s>>> JoinSource for T {
+  fn into_join(self, kind: JoinKind) -> Join {
+    Join::new(kind, Source::Table(self.into().trim().into_owned()))
+  }
+}
+
+impl JoinSource for Select<'_> {
+  fn into_join(self, kind: JoinKind) -> Join {
+    Join::new(kind, Source::Select(Box::new(self.into_owned())))
+  }
+}
+
+impl Source {
+  /// The subquery rendered in one line, the builders are compared by the query they render
+  fn key(&self) -> std::borrow::Cow<'_, Sql<'static>> {
+    match self {
+      Self::Table(table) => std::borrow::Cow::Borrowed(table),
+      Self::Select(select) => std::borrow::Cow::Owned(select.concat(&fmt::one_line())),
+    }
+  }
+}
+
+impl PartialEq for Source {
+  fn eq(&self, other: &Self) -> bool {
+    matches!(
+      (self, other),
+      (Self::Table(_), Self::Table(_)) | (Self::Select(_), Self::Select(_))
+    ) && self.key() == other.key()
+  }
+}
+
+impl Eq for Source {}
+
+impl Hash for Source {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    std::mem::discriminant(self).hash(state);
+    self.key().hash(state);
+  }
+}
+
+impl Join {
+  fn new(kind: JoinKind, source: Source) -> Self {
+    Self {
+      kind,
+      keyword: None,
+      lateral: false,
+      source,
+      alias: Sql::default(),
+      constraint: Constraint::None,
+    }
+  }
+
+  /// A join of the text methods of the builders, the text has the table and the constraint as written
+  pub(crate) fn table<'s>(kind: JoinKind, table: impl Into<Sql<'s>>) -> Self {
+    table.into_join(kind)
+  }
+
+  /// A join of a parsed statement, the keyword and the `LATERAL` keyword are kept as written
+  pub(crate) fn parsed(clause: &parse::Clause) -> Self {
+    let parse::Clause {
+      keyword, text, body, ..
+    } = *clause;
+    let lateral = body
+      .split_whitespace()
+      .next()
+      .filter(|word| word.eq_ignore_ascii_case("LATERAL"));
+    let (text, body) = match lateral {
+      Some(word) => (format!("{text} {word}"), body[word.len()..].trim_start()),
+      None => (text.to_owned(), body),
+    };
+    Self {
+      keyword: Some(text),
+      lateral: lateral.is_some(),
+      ..Self::table(JoinKind::of_keyword(keyword), body)
+    }
+  }
+
+  /// Creates a `CROSS JOIN`, the join has no constraint
+  pub fn cross(source: impl JoinSource) -> UnconstrainedJoin {
+    UnconstrainedJoin(source.into_join(JoinKind::Cross))
+  }
+
+  /// Creates a `FULL OUTER JOIN`, MySQL doesn't support this join
+  pub fn full_outer(source: impl JoinSource) -> Self {
+    source.into_join(JoinKind::FullOuter)
+  }
+
+  /// Creates an `INNER JOIN`
+  pub fn inner(source: impl JoinSource) -> Self {
+    source.into_join(JoinKind::Inner)
+  }
+
+  /// Creates a `LEFT JOIN`
+  pub fn left(source: impl JoinSource) -> Self {
+    source.into_join(JoinKind::Left)
+  }
+
+  /// Creates a `NATURAL JOIN`, the tables are joined by the columns with the same name so the join has no constraint
+  pub fn natural(source: impl JoinSource) -> UnconstrainedJoin {
+    UnconstrainedJoin(source.into_join(JoinKind::Natural))
+  }
+
+  /// Creates a `RIGHT JOIN`
+  pub fn right(source: impl JoinSource) -> Self {
+    source.into_join(JoinKind::Right)
+  }
+
+  /// The alias of the table or subquery, required by the subqueries. This method overrides the previous value
+  pub fn alias<'s>(mut self, alias: impl Into<Sql<'s>>) -> Self {
+    self.alias = alias.into().trim().into_owned();
+    self
+  }
+
+  /// Adds the `LATERAL` keyword, the subquery can reference the columns of the tables that come before it.
+  /// Supported by Postgres and MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let last_order = sql::Select::new()
+  ///   .select("total")
+  ///   .from("orders o")
+  ///   .where_clause("o.user_id = u.id")
+  ///   .order_by("o.created_at desc")
+  ///   .limit(1);
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("u.login, last.total")
+  ///   .from("users u")
+  ///   .join(sql::Join::left(last_order).lateral().alias("last").on("true"))
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT u.login, last.total \
+  /// #   FROM users u \
+  /// #   LEFT JOIN LATERAL (SELECT total FROM orders o WHERE o.user_id = u.id ORDER BY o.created_at desc LIMIT 1) \
+  /// #   AS last ON true\
+  /// # ";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT u.login, last.total
+  /// FROM users u
+  /// LEFT JOIN LATERAL (SELECT total FROM orders o WHERE o.user_id = u.id ORDER BY o.created_at desc LIMIT 1) AS last ON true
+  /// ```
+  pub fn lateral(mut self) -> Self {
+    self.lateral = true;
+    self
+  }
+
+  /// Adds a condition of the `ON` constraint, the conditions are joined by `AND`.
+  /// This method overrides the columns of the [using](Join::using) method
+  pub fn on(mut self, condition: impl Into<Condition>) -> Self {
+    match &mut self.constraint {
+      Constraint::On(conditions) => conditions.push(condition.into()),
+      _ => self.constraint = Constraint::On(vec![condition.into()]),
+    }
+    self
+  }
+
+  /// The columns of the `USING` constraint, the columns must have the same name in both tables.
+  /// This method overrides the conditions of the [on](Join::on) method
+  pub fn using<'s, Column: Into<Sql<'s>>>(mut self, columns: impl IntoIterator<Item = Column>) -> Self {
+    let columns = columns
+      .into_iter()
+      .map(|column| column.into().trim().into_owned())
+      .collect();
+    self.constraint = Constraint::Using(columns);
+    self
+  }
+
+  /// The type of the join
+  pub fn kind(&self) -> JoinKind {
+    self.kind
+  }
+
+  /// Returns true when the join has the `LATERAL` keyword
+  pub fn is_lateral(&self) -> bool {
+    self.lateral
+  }
+
+  /// The subquery of the join, `None` when the join is made against a table
+  pub fn subquery(&self) -> Option<&Select<'static>> {
+    match &self.source {
+      Source::Select(select) => Some(select),
+      Source::Table(_) => None,
+    }
+  }
+
+  /// Writes the join at the end of the query, the subquery is rendered with the options of the query
+  /// indented inside the parentheses
+  pub(crate) fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { indent, lb, space, .. } = fmts;
+    match &self.keyword {
+      Some(keyword) => append(query, &[keyword]),
+      None if self.lateral => append(query, &[self.kind.keyword(), space, "LATERAL"]),
+      None => append(query, &[self.kind.keyword()]),
+    }
+    append(query, &[space]);
+    match &self.source {
+      Source::Table(table) => query.append(table),
+      Source::Select(select) => {
+        let inner_lb = format!("{lb}{indent}");
+        let inner_fmts = fmt::Formatter {
+          lb: inner_lb.as_str(),
+          ..*fmts
+        };
+        append(query, &["(", lb, indent]);
+        bind::scope(query, |query| select.concat_into(query, &inner_fmts));
+        append(query, &[lb, ")"]);
+      }
+    }
+    if self.alias.is_empty() == false {
+      append(query, &[space, "AS", space]);
+      query.append(&self.alias);
+    }
+    match &self.constraint {
+      Constraint::None => {}
+      Constraint::On(conditions) => {
+        append(query, &[space, "ON", space]);
+        Condition::concat_list(query, conditions, " AND ");
+      }
+      Constraint::Using(columns) => {
+        append(query, &[space, "USING", space, "("]);
+        append_list(query, columns, ", ");
+        query.push_str(")");
+      }
+    }
+  }
+}
+
+impl UnconstrainedJoin {
+  /// The alias of the table or subquery, required by the subqueries. This method overrides the previous value
+  pub fn alias<'s>(self, alias: impl Into<Sql<'s>>) -> Self {
+    Self(self.0.alias(alias))
+  }
+
+  /// Adds the `LATERAL` keyword, see [Join::lateral]
+  pub fn lateral(self) -> Self {
+    Self(self.0.lateral())
+  }
+}
+
+impl From<UnconstrainedJoin> for Join {
+  fn from(join: UnconstrainedJoin) -> Self {
+    join.0
+  }
+}
+
+/// Writes the join in one line with the syntax enabled by the feature flags
+impl std::fmt::Display for Join {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let mut item = Sql::default();
+    self.concat_into(&mut item, &fmt::one_line());
+    write!(f, "{item}")
+  }
+}
+
+impl PartialEq<str> for Join {
+  fn eq(&self, other: &str) -> bool {
+    self.to_string().as_str() == other
+  }
+}
+
+impl PartialEq<&str> for Join {
+  fn eq(&self, other: &&str) -> bool {
+    self == *other
+  }
+}
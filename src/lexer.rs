@@ -0,0 +1,211 @@
+/// The keywords written by the builders and the most common ones written in the arguments
+const KEYWORDS: &[&str] = &[
+  "ADD",
+  "ALL",
+  "ALTER",
+  "AND",
+  "AS",
+  "ASC",
+  "BETWEEN",
+  "BY",
+  "CASCADE",
+  "CASE",
+  "COLUMN",
+  "CONCURRENTLY",
+  "CONFLICT",
+  "CONSTRAINT",
+  "CREATE",
+  "CROSS",
+  "DEFAULT",
+  "DELETE",
+  "DESC",
+  "DISTINCT",
+  "DO",
+  "DROP",
+  "DUPLICATE",
+  "ELSE",
+  "END",
+  "EXCEPT",
+  "EXISTS",
+  "FALSE",
+  "FIRST",
+  "FOREIGN",
+  "FROM",
+  "FULL",
+  "GROUP",
+  "HAVING",
+  "IF",
+  "IGNORE",
+  "IN",
+  "INDEX",
+  "INNER",
+  "INSERT",
+  "INTERSECT",
+  "INTO",
+  "IS",
+  "JOIN",
+  "KEY",
+  "LAST",
+  "LEFT",
+  "LIKE",
+  "LIMIT",
+  "NOT",
+  "NOTHING",
+  "NULL",
+  "NULLS",
+  "OFFSET",
+  "ON",
+  "OR",
+  "ORDER",
+  "OUTER",
+  "OVERRIDING",
+  "PRIMARY",
+  "REFERENCES",
+  "RENAME",
+  "RETURNING",
+  "RIGHT",
+  "SELECT",
+  "SET",
+  "TABLE",
+  "THEN",
+  "TO",
+  "TRUE",
+  "UNION",
+  "UNIQUE",
+  "UPDATE",
+  "USING",
+  "VALUES",
+  "WHEN",
+  "WHERE",
+  "WITH",
+];
+
+/// A piece of SQL, the concatenation of the tokens is the original text
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Token<'a> {
+  /// A comment starting with `--` or enclosed by `/*` and `*/`
+  Comment(&'a str),
+  /// A name of a table, column or function, quoted or not, and the keywords qualified by a dot like `users.from`
+  Identifier(&'a str),
+  /// A SQL keyword like `SELECT`, in any case
+  Keyword(&'a str),
+  /// A string, including the dollar quoted strings of PostgreSQL, or a number
+  Literal(&'a str),
+  /// A placeholder of a bound value, like `$1`, `?1` or `?`
+  Placeholder(&'a str),
+  /// Whitespaces, operators and punctuation
+  Punctuation(&'a str),
+}
+
+impl<'a> Token<'a> {
+  pub(crate) fn text(&self) -> &'a str {
+    match self {
+      Token::Comment(text)
+      | Token::Identifier(text)
+      | Token::Keyword(text)
+      | Token::Literal(text)
+      | Token::Placeholder(text)
+      | Token::Punctuation(text) => text,
+    }
+  }
+}
+
+/// Splits the SQL into tokens, the quotes and comments not closed extend to the end of the text
+pub(crate) fn tokenize(sql: &str) -> Vec<Token<'_>> {
+  let bytes = sql.as_bytes();
+  let mut tokens = vec![];
+  let mut index = 0;
+
+  while index < bytes.len() {
+    let start = index;
+    let rest = &sql[index..];
+    let token = match bytes[index] {
+      quote @ (b'\'' | b'"' | b'`') => {
+        index = start + quoted_length(rest, quote);
+        match quote {
+          b'\'' => Token::Literal(&sql[start..index]),
+          _ => Token::Identifier(&sql[start..index]),
+        }
+      }
+      b'-' if rest.starts_with("--") => {
+        index = rest.find('\n').map_or(sql.len(), |end| start + end);
+        Token::Comment(&sql[start..index])
+      }
+      b'/' if rest.starts_with("/*") => {
+        index = rest[2..].find("*/").map_or(sql.len(), |end| start + end + 4);
+        Token::Comment(&sql[start..index])
+      }
+      b'$' if dollar_tag(rest).is_some() => {
+        let tag = dollar_tag(rest).unwrap_or_default();
+        index = rest[tag.len()..]
+          .find(tag)
+          .map_or(sql.len(), |end| start + tag.len() + end + tag.len());
+        Token::Literal(&sql[start..index])
+      }
+      b'$' | b'?' => {
+        let digits = rest[1..].bytes().take_while(u8::is_ascii_digit).count();
+        index += 1 + digits;
+        match (bytes[start], digits) {
+          (b'$', 0) => Token::Punctuation(&sql[start..index]),
+          _ => Token::Placeholder(&sql[start..index]),
+        }
+      }
+      byte if byte.is_ascii_digit() => {
+        index += rest
+          .find(|c: char| (c.is_ascii_alphanumeric() || c == '_') == false)
+          .unwrap_or(rest.len());
+        Token::Literal(&sql[start..index])
+      }
+      _ if rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') => {
+        index += rest
+          .find(|c: char| (c.is_alphanumeric() || c == '_' || c == '$') == false)
+          .unwrap_or(rest.len());
+        let word = &sql[start..index];
+        let is_qualified = sql[..start].ends_with('.') || sql[index..].starts_with('.');
+        match is_qualified == false && is_keyword(word) {
+          true => Token::Keyword(word),
+          false => Token::Identifier(word),
+        }
+      }
+      _ => {
+        index += rest.chars().next().map_or(1, char::len_utf8);
+        Token::Punctuation(&sql[start..index])
+      }
+    };
+    tokens.push(token);
+  }
+
+  tokens
+}
+
+fn is_keyword(word: &str) -> bool {
+  KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(word))
+}
+
+/// The length of the quoted text at the beginning of `text`, the quote is escaped by doubling it
+fn quoted_length(text: &str, quote: u8) -> usize {
+  let bytes = text.as_bytes();
+  let mut index = 1;
+  while index < bytes.len() {
+    index += 1;
+    if bytes[index - 1] == quote {
+      if bytes.get(index) == Some(&quote) {
+        index += 1;
+        continue;
+      }
+      break;
+    }
+  }
+
+  index
+}
+
+/// The opening tag of a dollar quoted string of PostgreSQL, like `$$` or `$body$`
+fn dollar_tag(text: &str) -> Option<&str> {
+  let end = text[1..].find('$')? + 2;
+  let name = &text[1..end - 1];
+  let is_tag = name.starts_with(|c: char| c.is_ascii_digit()) == false
+    && name.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'_');
+
+  is_tag.then(|| &text[..end])
+}
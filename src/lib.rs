@@ -1,14 +1,53 @@
 #![doc = include_str!("../README.md")]
 
+mod alter_table;
 mod behavior;
+mod bind;
+mod condition;
+mod create_index;
+mod create_table;
 mod delete;
+mod dialect;
+mod drop_index;
+mod drop_table;
+mod duplicate;
+mod error;
 mod fmt;
+mod ident;
 mod insert;
+mod join;
+mod lexer;
+mod literal;
+mod pagination;
+mod parse;
 mod select;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sink;
+mod sql;
 mod structure;
 mod update;
 mod values;
 
+pub use crate::bind::{Params, PlaceholderMap, Value};
+pub use crate::condition::Condition;
+pub use crate::dialect::Dialect;
+pub use crate::duplicate::DuplicatePolicy;
+pub use crate::error::{BuildError, ParseError};
+pub use crate::fmt::{CommaStyle, FormatOptions, KeywordCase};
+pub use crate::ident::Ident;
+pub use crate::join::{Join, JoinKind, JoinSource, UnconstrainedJoin};
+pub use crate::literal::Literal;
+pub use crate::pagination::{LimitStyle, RowCount};
+pub use crate::sink::{FmtSink, IoSink, Sink};
+pub use crate::sql::Sql;
 pub use crate::structure::{
-  Delete, DeleteClause, Insert, InsertClause, Select, SelectClause, Update, UpdateClause, Values, ValuesClause,
+  AlterTable, AlterTableClause, Clause, CreateIndex, CreateIndexClause, CreateTable, CreateTableClause, Delete,
+  DeleteClause, DropIndex, DropIndexClause, DropTable, DropTableClause, Insert, InsertClause, Select, SelectClause,
+  Update, UpdateClause, Values, ValuesClause,
 };
+
+#[cfg(feature = "log")]
+pub use crate::sink::LogSink;
+#[cfg(feature = "tracing")]
+pub use crate::sink::TracingSink;
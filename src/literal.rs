@@ -0,0 +1,242 @@
+use crate::dialect::Dialect;
+use std::borrow::Cow;
+
+/// A value written inline in the query, escaped when the query is rendered with the rules of the dialect.
+///
+/// The texts are quoted with the standard `''` doubling of the quotes, Postgres uses the `E''` strings when the text
+/// has backslashes and MySQL also escapes the backslashes and the control characters. The literals follow the dialect of
+/// the `to_sql` and `build_with` methods, the other methods escape like the syntax enabled by the feature flags.
+///
+/// The builder methods receive a `Literal` anywhere a text is accepted, and the literal can be composed with other texts
+/// with the [Sql::push](crate::Sql::push) method, prefer the `*_bind` methods to send the values apart from the query to the database
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause(sql::Sql::from("login = ").push(sql::Literal::from("o'reilly")))
+///   .where_clause(sql::Sql::from("path = ").push(sql::Literal::from(r"C:\home")));
+///
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Postgres).unwrap(),
+///   r"SELECT * FROM users WHERE login = 'o''reilly' AND path = E'C:\\home'"
+/// );
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Sqlite).unwrap(),
+///   r"SELECT * FROM users WHERE login = 'o''reilly' AND path = 'C:\home'"
+/// );
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Mysql).unwrap(),
+///   r"SELECT * FROM users WHERE login = 'o''reilly' AND path = 'C:\\home'"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Literal {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  Text(String),
+  Bytes(Vec<u8>),
+  /// A date like `2024-01-31`, rendered as `DATE '2024-01-31'`, SQLite has no date type and renders the text
+  Date(String),
+  /// A date and time like `2024-01-31 10:30:00`, rendered as `TIMESTAMP '2024-01-31 10:30:00'`,
+  /// SQLite has no timestamp type and renders the text
+  Timestamp(String),
+  /// Postgres renders an `ARRAY[...]`, MySQL and SQLite have no array type and render
+  /// the items between parentheses, like the list of an `IN` operator
+  Array(Vec<Literal>),
+}
+
+impl Literal {
+  /// Creates an array literal of the items
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let ids = sql::Literal::array([1, 2, 3]);
+  ///
+  /// assert_eq!(ids.to_sql(sql::Dialect::Postgres), "ARRAY[1, 2, 3]");
+  /// assert_eq!(ids.to_sql(sql::Dialect::Mysql), "(1, 2, 3)");
+  /// ```
+  pub fn array<T: Into<Literal>>(items: impl IntoIterator<Item = T>) -> Self {
+    Literal::Array(items.into_iter().map(Into::into).collect())
+  }
+
+  /// Creates a date literal, the text is escaped like any other text
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let date = sql::Literal::date("2024-01-31");
+  ///
+  /// assert_eq!(date.to_sql(sql::Dialect::Postgres), "DATE '2024-01-31'");
+  /// assert_eq!(date.to_sql(sql::Dialect::Sqlite), "'2024-01-31'");
+  /// ```
+  pub fn date(date: impl Into<String>) -> Self {
+    Literal::Date(date.into())
+  }
+
+  /// Creates a timestamp literal, the text is escaped like any other text
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let timestamp = sql::Literal::timestamp("2024-01-31 10:30:00");
+  ///
+  /// assert_eq!(timestamp.to_sql(sql::Dialect::Mysql), "TIMESTAMP '2024-01-31 10:30:00'");
+  /// ```
+  pub fn timestamp(timestamp: impl Into<String>) -> Self {
+    Literal::Timestamp(timestamp.into())
+  }
+
+  /// Returns the literal escaped with the rules of the dialect
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let literal = sql::Literal::from("it's");
+  ///
+  /// assert_eq!(literal.to_sql(sql::Dialect::Postgres), "'it''s'");
+  /// assert_eq!(sql::Literal::from(true).to_sql(sql::Dialect::Sqlite), "1");
+  /// assert_eq!(sql::Literal::from(vec![0xca_u8, 0xfe]).to_sql(sql::Dialect::Mysql), "X'cafe'");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> String {
+    let mut sql = String::new();
+    self.write(&mut sql, dialect);
+    sql
+  }
+
+  fn write(&self, sql: &mut String, dialect: Dialect) {
+    match self {
+      Literal::Null => sql.push_str("NULL"),
+      Literal::Bool(value) => sql.push_str(match (dialect, value) {
+        (Dialect::Sqlite, true) => "1",
+        (Dialect::Sqlite, false) => "0",
+        (_, true) => "TRUE",
+        (_, false) => "FALSE",
+      }),
+      Literal::Int(value) => sql.push_str(&value.to_string()),
+      Literal::Float(value) => write_float(sql, *value, dialect),
+      Literal::Text(value) => write_text(sql, value, dialect),
+      Literal::Bytes(value) => {
+        let hex = value.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        match dialect {
+          Dialect::Postgres => sql.push_str(&format!(r"E'\\x{hex}'::bytea")),
+          Dialect::Mysql | Dialect::Sqlite => sql.push_str(&format!("X'{hex}'")),
+        }
+      }
+      Literal::Date(value) => write_typed_text(sql, "DATE", value, dialect),
+      Literal::Timestamp(value) => write_typed_text(sql, "TIMESTAMP", value, dialect),
+      Literal::Array(items) => {
+        let (open, close) = match dialect {
+          Dialect::Postgres => ("ARRAY[", "]"),
+          Dialect::Mysql | Dialect::Sqlite => ("(", ")"),
+        };
+        sql.push_str(open);
+        for (index, item) in items.iter().enumerate() {
+          if index > 0 {
+            sql.push_str(", ");
+          }
+          item.write(sql, dialect);
+        }
+        sql.push_str(close);
+      }
+    }
+  }
+}
+
+/// Postgres accepts the special values as text casted to float, the other dialects have no literal to them
+fn write_float(sql: &mut String, value: f64, dialect: Dialect) {
+  if value.is_finite() {
+    return sql.push_str(&format!("{value:?}"));
+  }
+  match dialect {
+    Dialect::Postgres if value.is_nan() => sql.push_str("'NaN'::float8"),
+    Dialect::Postgres if value.is_sign_positive() => sql.push_str("'Infinity'::float8"),
+    Dialect::Postgres => sql.push_str("'-Infinity'::float8"),
+    Dialect::Mysql | Dialect::Sqlite => sql.push_str("NULL"),
+  }
+}
+
+/// SQLite has no date types, the dates are written as texts
+fn write_typed_text(sql: &mut String, type_name: &str, text: &str, dialect: Dialect) {
+  if dialect != Dialect::Sqlite {
+    sql.push_str(type_name);
+    sql.push(' ');
+  }
+  write_text(sql, text, dialect);
+}
+
+/// The quotes are always escaped by doubling it, so the text is safe even for the tools that
+/// don't understand the backslash escapes
+fn write_text(sql: &mut String, text: &str, dialect: Dialect) {
+  match dialect {
+    Dialect::Postgres if text.contains('\\') => {
+      sql.push_str("E'");
+      sql.push_str(&text.replace('\\', r"\\").replace('\'', "''"));
+    }
+    Dialect::Mysql => {
+      sql.push('\'');
+      for c in text.chars() {
+        match c {
+          '\'' => sql.push_str("''"),
+          '\\' => sql.push_str(r"\\"),
+          '\0' => sql.push_str(r"\0"),
+          '\n' => sql.push_str(r"\n"),
+          '\r' => sql.push_str(r"\r"),
+          '\u{1a}' => sql.push_str(r"\Z"),
+          _ => sql.push(c),
+        }
+      }
+    }
+    Dialect::Postgres | Dialect::Sqlite => {
+      sql.push('\'');
+      sql.push_str(&text.replace('\'', "''"));
+    }
+  }
+  sql.push('\'');
+}
+
+macro_rules! literal_from {
+  ($variant:ident, $($ty:ty),+) => {
+    $(
+      impl From<$ty> for Literal {
+        fn from(value: $ty) -> Self {
+          Literal::$variant(value.into())
+        }
+      }
+    )+
+  };
+}
+
+literal_from!(Bool, bool);
+literal_from!(Int, i8, i16, i32, i64, u8, u16, u32);
+literal_from!(Float, f32, f64);
+literal_from!(Text, &str, String, Cow<'_, str>);
+literal_from!(Bytes, &[u8], Vec<u8>);
+
+impl<T: Into<Literal>> From<Option<T>> for Literal {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => value.into(),
+      None => Literal::Null,
+    }
+  }
+}
+
+/// Writes the literal escaped like the syntax enabled by the feature flags, use the [Literal::to_sql]
+/// method to get the literal of a dialect
+impl std::fmt::Display for Literal {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.to_sql(Dialect::of_features()))
+  }
+}
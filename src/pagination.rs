@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+/// The values accepted by the limit and offset clauses, the integers and the texts like `"10"`, `"$1"` or `"?"`.
+/// The texts that aren't a number or a placeholder are rejected by the `try_as_string` and `validate` methods
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let per_page: u32 = 20;
+/// let query = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .limit(per_page)
+///   .as_string();
+///
+/// # assert_eq!(query, "SELECT * FROM users LIMIT 20");
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT * FROM users LIMIT 20
+/// ```
+pub trait RowCount<'a> {
+  fn into_row_count(self) -> Cow<'a, str>;
+}
+
+impl<'a> RowCount<'a> for &'a str {
+  fn into_row_count(self) -> Cow<'a, str> {
+    Cow::Borrowed(self)
+  }
+}
+
+impl<'a> RowCount<'a> for &'a String {
+  fn into_row_count(self) -> Cow<'a, str> {
+    Cow::Borrowed(self)
+  }
+}
+
+impl<'a> RowCount<'a> for String {
+  fn into_row_count(self) -> Cow<'a, str> {
+    Cow::Owned(self)
+  }
+}
+
+impl<'a> RowCount<'a> for Cow<'a, str> {
+  fn into_row_count(self) -> Cow<'a, str> {
+    self
+  }
+}
+
+macro_rules! row_count_integer {
+  ($($ty:ty),+) => {
+    $(
+      impl<'a> RowCount<'a> for $ty {
+        fn into_row_count(self) -> Cow<'a, str> {
+          Cow::Owned(self.to_string())
+        }
+      }
+    )+
+  };
+}
+
+row_count_integer!(i32, i64, u8, u16, u32, u64, usize);
+
+/// The syntax used by the [Select](crate::Select) builder to render the limit and the offset clauses
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Select::new()
+///   .limit_style(sql::LimitStyle::FetchFirst)
+///   .select("*")
+///   .from("users")
+///   .order_by("id")
+///   .paginate(3, 10)
+///   .as_string();
+///
+/// # assert_eq!(query, "SELECT * FROM users ORDER BY id OFFSET 20 ROWS FETCH FIRST 10 ROWS ONLY");
+/// ```
+///
+/// Output
+///
+/// ```sql
+/// SELECT * FROM users ORDER BY id OFFSET 20 ROWS FETCH FIRST 10 ROWS ONLY
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LimitStyle {
+  /// `LIMIT n OFFSET m`, MySQL renders `LIMIT m, n` when both clauses are defined, the default of all dialects
+  #[default]
+  Limit,
+  /// `OFFSET m ROWS FETCH FIRST n ROWS ONLY` of the SQL standard, supported by Postgres
+  FetchFirst,
+  /// `SELECT TOP n` of SQL Server, a query with offset is rendered with the syntax [LimitStyle::FetchFirst].
+  /// None of the dialects supports it, so it's only rendered by the methods that don't receive a dialect
+  Top,
+}
@@ -0,0 +1,388 @@
+use crate::{error::ParseError, sql::Sql};
+
+/// The joins accepted by the builders
+pub(crate) const JOINS: &[&str] = &[
+  "CROSS JOIN",
+  "FULL JOIN",
+  "FULL OUTER JOIN",
+  "INNER JOIN",
+  "JOIN",
+  "LEFT JOIN",
+  "LEFT OUTER JOIN",
+  "NATURAL JOIN",
+  "RIGHT JOIN",
+  "RIGHT OUTER JOIN",
+];
+
+/// Clauses of the databases that the builders can't represent, a statement with one of them is rejected
+/// instead of merging the clause into the clause that comes before it
+const UNSUPPORTED: &[&str] = &[
+  "FETCH FIRST",
+  "FETCH NEXT",
+  "FOR KEY SHARE",
+  "FOR NO KEY UPDATE",
+  "FOR SHARE",
+  "FOR UPDATE",
+  "LOCK IN SHARE MODE",
+  "WINDOW",
+];
+
+/// A clause found at the top level of a statement
+pub(crate) struct Clause<'a> {
+  /// The keyword as defined in the list of keywords, in uppercase with one space between the words
+  pub(crate) keyword: &'static str,
+  /// The keyword as written in the statement
+  pub(crate) text: &'a str,
+  /// The content between the keyword and the next clause, trimmed
+  pub(crate) body: &'a str,
+  /// The keyword and the content as written in the statement
+  pub(crate) source: &'a str,
+  pub(crate) position: usize,
+}
+
+impl Clause<'_> {
+  pub(crate) fn empty_error(&self) -> ParseError {
+    ParseError::EmptyClause {
+      clause: self.keyword.to_owned(),
+      position: self.position,
+    }
+  }
+
+  pub(crate) fn unexpected_error(&self) -> ParseError {
+    ParseError::UnexpectedClause {
+      clause: self.keyword.to_owned(),
+      position: self.position,
+    }
+  }
+
+  pub(crate) fn unsupported_error(&self) -> ParseError {
+    ParseError::UnsupportedClause {
+      clause: self.keyword.to_owned(),
+      position: self.position,
+    }
+  }
+}
+
+/// Checks the clauses are in the same order rendered by the builder, `order` has the groups of keywords
+/// in that order, only the keywords of a group with more than one keyword, like the joins, can be repeated
+fn check_sequence(clauses: &[Clause], order: &[&[&'static str]]) -> Result<(), ParseError> {
+  let mut previous = None;
+
+  for clause in clauses {
+    if clause.body.is_empty() {
+      return Err(clause.empty_error());
+    }
+    let rank = order.iter().position(|group| group.contains(&clause.keyword));
+    let is_repeatable = rank.is_some_and(|rank| order[rank].len() > 1);
+    match (previous, rank) {
+      (Some(previous), Some(rank)) if rank < previous || (rank == previous && is_repeatable == false) => {
+        return Err(clause.unexpected_error())
+      }
+      _ => previous = rank,
+    }
+  }
+
+  Ok(())
+}
+
+/// Parses a slice of the statement, the positions of the errors are moved to be relative to the statement
+pub(crate) fn nested<'a, T>(
+  statement: &'a str,
+  slice: &'a str,
+  parse: impl FnOnce(&'a str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+  parse(slice).map_err(|error| error.shift(offset(statement, slice)))
+}
+
+/// The text before the first clause is accepted when it has only comments and is kept as raw SQL
+pub(crate) fn raw(leading: &str, expected: &str) -> Result<Vec<String>, ParseError> {
+  if leading.is_empty() {
+    return Ok(vec![]);
+  }
+  let is_comment = top_level(leading)?.iter().all(|(_, char)| char.is_whitespace());
+  if is_comment {
+    Ok(vec![leading.to_owned()])
+  } else {
+    Err(ParseError::UnexpectedStatement {
+      expected: expected.to_owned(),
+    })
+  }
+}
+
+/// Splits the statement at the keywords found outside quotes, comments and parentheses,
+/// returns the text before the first keyword and the clauses in the same order of the statement
+pub(crate) fn split_clauses<'a>(
+  sql: &'a str,
+  keywords: &[&'static str],
+) -> Result<(&'a str, Vec<Clause<'a>>), ParseError> {
+  let words = top_level_words(sql)?;
+  let mut found = vec![];
+  let mut index = 0;
+
+  while index < words.len() {
+    let matched = keywords
+      .iter()
+      .filter_map(|keyword| {
+        let parts = keyword.split(' ').collect::<Vec<_>>();
+        let candidates = words.get(index..index + parts.len())?;
+        let is_same_words = parts
+          .iter()
+          .zip(candidates)
+          .all(|(part, (start, end))| sql[*start..*end].eq_ignore_ascii_case(part));
+        let is_contiguous = candidates
+          .windows(2)
+          .all(|pair| sql[pair[0].1..pair[1].0].trim().is_empty());
+        (is_same_words && is_contiguous).then_some((*keyword, parts.len()))
+      })
+      .max_by_key(|(_, length)| *length);
+
+    match matched {
+      Some((keyword, length)) => {
+        found.push((keyword, words[index].0, words[index + length - 1].1));
+        index += length;
+      }
+      None => index += 1,
+    }
+  }
+
+  let leading = match found.first() {
+    Some((_, start, _)) => sql[..*start].trim(),
+    None => sql.trim(),
+  };
+  let clauses = found
+    .iter()
+    .enumerate()
+    .map(|(index, (keyword, start, end))| {
+      let next = found.get(index + 1).map_or(sql.len(), |(_, next, _)| *next);
+      Clause {
+        keyword,
+        text: &sql[*start..*end],
+        body: sql[*end..next].trim(),
+        source: sql[*start..next].trim_end(),
+        position: *start,
+      }
+    })
+    .collect();
+
+  Ok((leading, clauses))
+}
+
+/// The items of a list as the builders store them
+pub(crate) fn list(sql: &str) -> Result<Vec<Sql<'static>>, ParseError> {
+  Ok(
+    split_list(sql)?
+      .into_iter()
+      .map(|item| Sql::from(item.to_owned()))
+      .collect(),
+  )
+}
+
+/// Splits a list at the commas found outside quotes, comments and parentheses
+pub(crate) fn split_list(sql: &str) -> Result<Vec<&str>, ParseError> {
+  let mut items = vec![];
+  let mut start = 0;
+
+  for (index, char) in top_level(sql)? {
+    if char == ',' {
+      items.push(sql[start..index].trim());
+      start = index + 1;
+    }
+  }
+  items.push(sql[start..].trim());
+
+  Ok(items.into_iter().filter(|item| item.is_empty() == false).collect())
+}
+
+/// Splits the statement at the keywords of the groups and checks they are in the order of the groups,
+/// see [split_clauses] and [check_sequence]
+pub(crate) fn split_sequence<'a>(
+  sql: &'a str,
+  order: &[&[&'static str]],
+) -> Result<(&'a str, Vec<Clause<'a>>), ParseError> {
+  let keywords = order
+    .iter()
+    .flat_map(|group| group.iter().copied())
+    .chain(UNSUPPORTED.iter().copied())
+    .collect::<Vec<_>>();
+  let rank = |keyword| order.iter().position(|group| group.contains(&keyword));
+  let (leading, found) = split_clauses(sql, &keywords)?;
+  if let Some(clause) = found.iter().find(|clause| UNSUPPORTED.contains(&clause.keyword)) {
+    return Err(clause.unsupported_error());
+  }
+  let mut clauses: Vec<Clause> = vec![];
+
+  // a keyword out of order followed by a parenthesis is a function call, like `VALUES(login)` of MySQL
+  for clause in found {
+    let is_call = clause.source[clause.text.len()..].starts_with('(');
+    match clauses.last_mut() {
+      Some(previous) if is_call && rank(clause.keyword) <= rank(previous.keyword) => {
+        let end = offset(sql, clause.source) + clause.source.len();
+        previous.body = sql[offset(sql, previous.body)..end].trim();
+        previous.source = &sql[previous.position..end];
+      }
+      _ => clauses.push(clause),
+    }
+  }
+  check_sequence(&clauses, order)?;
+
+  Ok((leading, clauses))
+}
+
+/// Trims the statement and removes the semicolon at the end
+pub(crate) fn statement(sql: &str) -> Result<&str, ParseError> {
+  let sql = sql.trim();
+  let semicolon = top_level(sql)?
+    .into_iter()
+    .find_map(|(index, char)| (char == ';').then_some(index));
+
+  match semicolon {
+    Some(index) if sql[index + 1..].trim().is_empty() => Ok(sql[..index].trim_end()),
+    Some(index) => Err(ParseError::MultipleStatements {
+      position: index + 1 + (sql[index + 1..].len() - sql[index + 1..].trim_start().len()),
+    }),
+    None => Ok(sql),
+  }
+}
+
+/// Returns the content of the parentheses when the whole text is wrapped by one pair of parentheses
+pub(crate) fn unwrap_parentheses(sql: &str) -> Result<Option<&str>, ParseError> {
+  let chars = top_level(sql)?;
+  let is_wrapped = matches!(chars.as_slice(), [(0, '('), (_, ')')]) && sql.ends_with(')');
+
+  Ok(is_wrapped.then(|| sql[1..sql.len() - 1].trim()))
+}
+
+pub(crate) fn offset(statement: &str, slice: &str) -> usize {
+  slice.as_ptr() as usize - statement.as_ptr() as usize
+}
+
+/// The chars found outside quotes, comments and parentheses with their byte offsets,
+/// the quotes and parentheses are kept as a boundary of the words and the comments are replaced by a space
+fn top_level(sql: &str) -> Result<Vec<(usize, char)>, ParseError> {
+  let mut output = vec![];
+  let mut chars = sql.char_indices().peekable();
+  let mut depth = 0usize;
+
+  while let Some((index, char)) = chars.next() {
+    let next = chars.peek().map(|(_, next)| *next);
+    match (char, next) {
+      ('\'' | '"' | '`', _) => {
+        chars
+          .find(|(_, closing)| *closing == char)
+          .ok_or(ParseError::Unbalanced { position: index })?;
+        if depth == 0 {
+          output.push((index, char));
+        }
+      }
+      ('-', Some('-')) => {
+        chars.find(|(_, end)| *end == '\n');
+        if depth == 0 {
+          output.push((index, ' '));
+        }
+      }
+      ('/', Some('*')) => {
+        chars.next();
+        let mut previous = ' ';
+        chars
+          .find(|(_, end)| std::mem::replace(&mut previous, *end) == '*' && *end == '/')
+          .ok_or(ParseError::Unbalanced { position: index })?;
+        if depth == 0 {
+          output.push((index, ' '));
+        }
+      }
+      ('(', _) => {
+        if depth == 0 {
+          output.push((index, char));
+        }
+        depth += 1;
+      }
+      (')', _) => {
+        depth = depth.checked_sub(1).ok_or(ParseError::Unbalanced { position: index })?;
+        if depth == 0 {
+          output.push((index, char));
+        }
+      }
+      _ if depth == 0 => output.push((index, char)),
+      _ => {}
+    }
+  }
+
+  if depth > 0 {
+    let position = output
+      .iter()
+      .rev()
+      .find(|(_, char)| *char == '(')
+      .map_or(0, |(index, _)| *index);
+    return Err(ParseError::Unbalanced { position });
+  }
+
+  Ok(output)
+}
+
+/// Byte ranges of the words found outside quotes, comments and parentheses,
+/// the words qualified by a dot like `users.from` are not included
+fn top_level_words(sql: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+  let mut words = vec![];
+  let mut current: Option<(usize, usize, bool)> = None;
+  let mut previous = ' ';
+
+  for (index, char) in top_level(sql)? {
+    let is_word_char = char.is_alphanumeric() || char == '_';
+    match (is_word_char, current.as_mut()) {
+      (true, Some(word)) => word.1 = index + char.len_utf8(),
+      (true, None) => current = Some((index, index + char.len_utf8(), matches!(previous, '.' | '$' | ':'))),
+      (false, _) => {
+        if let Some((start, end, false)) = current.take() {
+          words.push((start, end));
+        }
+      }
+    }
+    previous = char;
+  }
+  if let Some((start, end, false)) = current {
+    words.push((start, end));
+  }
+
+  Ok(words)
+}
+
+/// Parses the queries of the with clause with the builder of its statement, the builders are converted
+/// with `into_owned` because the with clause only accepts `'static` queries
+pub(crate) fn with_items<'a>(
+  statement: &'a str,
+  clause: &Clause<'a>,
+) -> Result<Vec<crate::behavior::WithItem<'a>>, ParseError> {
+  split_list(clause.body)?
+    .into_iter()
+    .map(|item| nested(statement, item, |item| with_item(item, clause)))
+    .collect()
+}
+
+fn with_item<'a>(item: &'a str, clause: &Clause) -> Result<crate::behavior::WithItem<'a>, ParseError> {
+  use crate::structure::{Delete, Insert, Select, Update};
+
+  let unsupported = ParseError::UnsupportedClause {
+    clause: clause.keyword.to_owned(),
+    position: 0,
+  };
+  let (name, clauses) = split_clauses(item, &["AS"])?;
+  let query = match clauses.as_slice() {
+    [as_clause] if name.is_empty() == false => unwrap_parentheses(as_clause.body)?.ok_or(unsupported)?,
+    _ => return Err(unsupported),
+  };
+
+  let (_, statements) = split_clauses(query, &["DELETE", "INSERT", "SELECT", "UPDATE"])?;
+  let query = match statements.first().map(|statement| statement.keyword) {
+    Some("DELETE") => shared(nested(item, query, Delete::parse_statement)?.into_owned()),
+    Some("INSERT") => shared(nested(item, query, Insert::parse_statement)?.into_owned()),
+    Some("UPDATE") => shared(nested(item, query, Update::parse_statement)?.into_owned()),
+    _ => shared(nested(item, query, Select::parse_statement)?.into_owned()),
+  };
+
+  Ok((name.into(), query))
+}
+
+/// The same conversion of the `with` method of the builders
+fn shared(query: impl crate::behavior::WithQuery + 'static) -> std::sync::Arc<dyn crate::behavior::WithQuery> {
+  std::sync::Arc::new(query)
+}
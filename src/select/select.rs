@@ -1,8 +1,19 @@
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
+  behavior::{defined, push_or, trim, Concat, WithQuery},
+  bind::{bind, Params, PlaceholderMap, Value},
+  condition::Condition,
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::{BuildError, ParseError},
+  fmt::{self, FormatOptions},
+  join::{Join, JoinKind},
+  pagination::{LimitStyle, RowCount},
+  parse,
+  sink::{self, IoSink, Sink},
+  sql::Sql,
   structure::{Select, SelectClause},
 };
+use std::borrow::Cow;
 
 impl<'a> Select<'a> {
   /// The same as [where_clause](Select::where_clause) method, useful to write more idiomatic SQL query
@@ -15,11 +26,56 @@ impl<'a> Select<'a> {
   ///   .where_clause("login = foo")
   ///   .and("active = true");
   /// ```
-  pub fn and(mut self, condition: &'a str) -> Self {
+  pub fn and(mut self, condition: impl Into<Condition>) -> Self {
     self = self.where_clause(condition);
     self
   }
 
+  /// The same as [where_clause_bind](Select::where_clause_bind) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .and_bind("created_at > ?", 1_640_995_200);
+  /// ```
+  pub fn and_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self = self.where_clause_bind(condition, params);
+    self
+  }
+
+  /// Calls the function with the Select and returns its result, useful to reuse a set of method calls
+  /// without breaking the method chaining
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn active_only(query: sql::Select) -> sql::Select {
+  ///   query.where_clause("active = true")
+  /// }
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .apply(active_only)
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id, login FROM users WHERE active = true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login FROM users WHERE active = true
+  /// ```
+  pub fn apply(self, f: impl FnOnce(Self) -> Self) -> Self {
+    f(self)
+  }
+
   /// Gets the current state of the Select returns it as string
   ///
   /// # Examples
@@ -27,150 +83,933 @@ impl<'a> Select<'a> {
   /// use sql_query_builder as sql;
   ///
   /// let query = sql::Select::new()
-  ///   .select("id")
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// SELECT id FROM users WHERE login = 'foo'
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.render(&fmts).0
+  }
+
+  /// Gets the current state of the Select and returns it as string together with the bound values,
+  /// the placeholders are numbered in the order they appear in the query with the syntax enabled by the feature flags
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .build();
+  ///
+  /// assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// SELECT id FROM users WHERE login = $1
+  /// ```
+  ///
+  /// # Panics
+  /// Panics when the placeholders of a `*_bind` method don't match the values bound to it,
+  /// use [Select::build_with] to get the [BuildError::BindMismatch] error instead
+  pub fn build(&self) -> (String, Vec<Value>) {
+    let fmts = fmt::one_line();
+    self.try_render(&fmts).unwrap_or_else(|error| panic!("{error}"))
+  }
+
+  /// Gets the current state of the [Select] rendered for the given dialect together with the bound values,
+  /// returns an error when a clause defined in the query isn't supported by the dialect, see [Dialect] for the details
+  /// or [BuildError::BindMismatch] when the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .build_with(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "SELECT id FROM users WHERE login = ?1");
+  /// assert_eq!(values.len(), 1);
+  /// ```
+  pub fn build_with(&self, dialect: Dialect) -> Result<(String, Vec<Value>), BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    self.try_render(&fmts)
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .order_by("login")
+  ///   .limit("10");
+  /// let count = select
+  ///   .clear(sql::SelectClause::OrderBy)
+  ///   .clear(sql::SelectClause::Limit)
+  ///   .replace_select("count(*)")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT count(*) FROM users";
+  /// # assert_eq!(expected, count);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT count(*) FROM users
+  /// ```
+  pub fn clear(mut self, clause: SelectClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      SelectClause::From => self._from.clear(),
+      SelectClause::GroupBy => self._group_by.clear(),
+      SelectClause::Having => self._having.clear(),
+      SelectClause::Join => self._join.clear(),
+      SelectClause::Limit => self._limit = "".into(),
+      SelectClause::Offset => self._offset = "".into(),
+      SelectClause::OrderBy => self._order_by.clear(),
+      SelectClause::Select => self._select.clear(),
+      SelectClause::Where => self._where.clear(),
+      SelectClause::Except => self._except.clear(),
+      SelectClause::Intersect => self._intersect.clear(),
+      SelectClause::Union => self._union.clear(),
+      SelectClause::With => self._with.clear(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
+  }
+
+  /// Prints the current state of the Select into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("login = foo")
+  ///   .and("active = true")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT *
+  /// FROM users
+  /// WHERE login = foo AND active = true
+  /// ```
+  ///
+  /// You can debug different parts of the select putting it in another position
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select_query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .debug()
+  ///   .where_clause("login = foo")
+  ///   .and("active = true")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT *
+  /// FROM users
+  /// ```
+  pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Select] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::multiline();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::KeepAll)
+  ///   .select("random()")
+  ///   .select("random()")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT random(), random()";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT random(), random()
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// The except clause
+  pub fn except(mut self, select: Self) -> Self {
+    self._except.push(select);
+    self
+  }
+
+  /// The queries of the except clause
+  pub fn except_queries(&self) -> &[Select<'a>] {
+    &self._except
+  }
+
+  /// The from clause
+  pub fn from<'s>(mut self, tables: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(SelectClause::From),
+      &mut self._from,
+      tables.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The tables of the from clause in the order they were added
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new().select("*").from("users").from("orders");
+  ///
+  /// assert_eq!(select.from_items(), ["users", "orders"]);
+  /// ```
+  pub fn from_items(&self) -> &[Sql<'static>] {
+    &self._from
+  }
+
+  /// The full outer join clause, MySQL doesn't support this join
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users u")
+  ///   .full_outer_join("addresses a ON a.user_id = u.id")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM users u FULL OUTER JOIN addresses a ON a.user_id = u.id";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users u FULL OUTER JOIN addresses a ON a.user_id = u.id
+  /// ```
+  pub fn full_outer_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::FullOuter, table);
+    self._duplicates.push(Some(SelectClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The group by clause
+  pub fn group_by<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(SelectClause::GroupBy),
+      &mut self._group_by,
+      column.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The expressions of the group by clause in the order they were added
+  pub fn group_by_items(&self) -> &[Sql<'static>] {
+    &self._group_by
+  }
+
+  /// The having clause
+  pub fn having(mut self, condition: impl Into<Condition>) -> Self {
+    self
+      ._duplicates
+      .push(Some(SelectClause::Having), &mut self._having, condition.into());
+    self
+  }
+
+  /// The having clause with bound values, each `?` of the condition is replaced by a placeholder
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("status, count(*)")
+  ///   .from("orders")
+  ///   .group_by("status")
+  ///   .having_bind("count(*) > ?", 10);
+  /// ```
+  ///
+  /// The condition with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn having_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self._duplicates.push(
+      Some(SelectClause::Having),
+      &mut self._having,
+      Condition::Raw(bind(condition, params)),
+    );
+    self
+  }
+
+  /// The cross join clause
+  pub fn cross_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Cross, table);
+    self._duplicates.push(Some(SelectClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The conditions of the having clause in the order they were added
+  pub fn having_conditions(&self) -> &[Condition] {
+    &self._having
+  }
+
+  /// The inner join clause
+  pub fn inner_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Inner, table);
+    self._duplicates.push(Some(SelectClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The queries of the intersect clause
+  pub fn intersect_queries(&self) -> &[Select<'a>] {
+    &self._intersect
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn users_page(limit: &str) -> sql::Select<'static> {
+  ///   sql::Select::new()
+  ///     .select("id")
+  ///     .from("users")
+  ///     .limit(limit)
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = users_page(&String::from("10")).as_string();
+  ///
+  /// # let expected = "SELECT id FROM users LIMIT 10";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id FROM users LIMIT 10
+  /// ```
+  pub fn into_owned(self) -> Select<'static> {
+    Select {
+      _duplicates: self._duplicates,
+      _except: self._except.into_iter().map(Select::into_owned).collect(),
+      _from: self._from,
+      _group_by: self._group_by,
+      _having: self._having,
+      _intersect: self._intersect.into_iter().map(Select::into_owned).collect(),
+      _join: self._join,
+      _limit: self._limit.into_owned(),
+      _limit_style: self._limit_style,
+      _offset: self._offset.into_owned(),
+      _order_by: self._order_by,
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+      _select: self._select,
+      _union: self._union.into_iter().map(Select::into_owned).collect(),
+      _where: self._where,
+      _with: crate::behavior::owned_with(self._with),
+    }
+  }
+
+  /// Adds a join built with [Join], the join can have an `ON` condition or a `USING` list
+  /// and can be made against a subquery with an alias
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let active = sql::Select::new().select("id").from("users").where_clause("active = true");
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("o.*")
+  ///   .from("orders o")
+  ///   .join(sql::Join::inner(active).alias("u").on("u.id = o.user_id"))
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT o.* FROM orders o \
+  /// #   INNER JOIN (SELECT id FROM users WHERE active = true) AS u ON u.id = o.user_id\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT o.* FROM orders o INNER JOIN (SELECT id FROM users WHERE active = true) AS u ON u.id = o.user_id
+  /// ```
+  pub fn join(mut self, join: impl Into<Join>) -> Self {
+    self
+      ._duplicates
+      .push(Some(SelectClause::Join), &mut self._join, join.into());
+    self
+  }
+
+  /// The joins in the order they were added, the subqueries are kept as builders, see [Join::subquery]
+  pub fn join_items(&self) -> &[Join] {
+    &self._join
+  }
+
+  /// The join lateral clause, the subquery can reference the columns of the tables that come before it.
+  /// Supported by Postgres and MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("u.login, o.total")
+  ///   .from("users u")
+  ///   .join_lateral("(SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT u.login, o.total FROM users u \
+  /// #   JOIN LATERAL (SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT u.login, o.total FROM users u JOIN LATERAL (SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true
+  /// ```
+  pub fn join_lateral<'s>(mut self, subquery: impl Into<Sql<'s>>) -> Self {
+    let subquery = Join::table(JoinKind::Join, subquery).lateral();
+    self
+      ._duplicates
+      .push(Some(SelectClause::Join), &mut self._join, subquery);
+    self
+  }
+
+  /// The left join clause
+  pub fn left_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Left, table);
+    self._duplicates.push(Some(SelectClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The left join lateral clause, the subquery can reference the columns of the tables that come before it.
+  /// Supported by Postgres and MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("u.login, o.total")
+  ///   .from("users u")
+  ///   .left_join_lateral("(SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true")
+  ///   .as_string();
+  ///
+  /// # let expected = "\
+  /// #   SELECT u.login, o.total FROM users u \
+  /// #   LEFT JOIN LATERAL (SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true\
+  /// # ";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT u.login, o.total FROM users u LEFT JOIN LATERAL (SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true
+  /// ```
+  pub fn left_join_lateral<'s>(mut self, subquery: impl Into<Sql<'s>>) -> Self {
+    let subquery = Join::table(JoinKind::Left, subquery).lateral();
+    self
+      ._duplicates
+      .push(Some(SelectClause::Join), &mut self._join, subquery);
+    self
+  }
+
+  /// The limit clause with a bound value, the value is replaced by a placeholder numbered at render time.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .limit_bind(10)
+  ///   .build_with(sql::Dialect::Postgres)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users LIMIT $1");
+  /// assert_eq!(values, vec![sql::Value::Int(10)]);
+  /// ```
+  pub fn limit_bind(mut self, value: impl Into<Value>) -> Self {
+    self._limit = bind("?", value.into());
+    self
+  }
+
+  /// Defines the syntax of the limit and offset clauses, the default is [LimitStyle::Limit].
+  /// See [LimitStyle] for the syntax supported by each dialect
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .limit_style(sql::LimitStyle::FetchFirst)
+  ///   .select("*")
+  ///   .from("users")
+  ///   .limit(10);
+  ///
+  /// # let expected = "SELECT * FROM users FETCH FIRST 10 ROWS ONLY";
+  /// # assert_eq!(select.to_sql(sql::Dialect::Postgres).unwrap(), expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users FETCH FIRST 10 ROWS ONLY
+  /// ```
+  pub fn limit_style(mut self, style: LimitStyle) -> Self {
+    self._limit_style = style;
+    self
+  }
+
+  /// The value of the limit clause, `None` when the clause is not defined. The value of the
+  /// [limit_bind](Select::limit_bind) method is kept apart from the text, see [Sql::values]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new().select("*").from("users").limit("10");
+  /// let select_bind = sql::Select::new().select("*").from("users").limit_bind(10);
+  ///
+  /// assert_eq!(select.limit_value().unwrap(), "10");
+  /// assert_eq!(select.offset_value(), None);
+  /// assert_eq!(select_bind.limit_value().unwrap().values().collect::<Vec<_>>(), [&sql::Value::Int(10)]);
+  /// ```
+  pub fn limit_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._limit)
+  }
+
+  /// The natural join clause, the tables are joined by the columns with the same name
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("orders")
+  ///   .natural_join("order_items")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM orders NATURAL JOIN order_items";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM orders NATURAL JOIN order_items
+  /// ```
+  pub fn natural_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Natural, table);
+    self._duplicates.push(Some(SelectClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The offset clause with a bound value, the value is replaced by a placeholder numbered at render time.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .limit_bind(10)
+  ///   .offset_bind(20)
+  ///   .build_with(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users LIMIT ?1 OFFSET ?2");
+  /// assert_eq!(values, vec![sql::Value::Int(10), sql::Value::Int(20)]);
+  /// ```
+  pub fn offset_bind(mut self, value: impl Into<Value>) -> Self {
+    self._offset = bind("?", value.into());
+    self
+  }
+
+  /// The value of the offset clause, `None` when the clause is not defined
+  pub fn offset_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._offset)
+  }
+
+  /// The expressions of the order by clause in the order they were added
+  pub fn order_by_items(&self) -> &[Sql<'static>] {
+    &self._order_by
+  }
+
+  /// Defines the limit and the offset of the page, the pages begin at `1` and the page `0` is the same of the first page.
+  /// This method overrides the previous values of the limit and offset clauses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .order_by("id")
+  ///   .paginate(3, 25)
+  ///   .as_string();
+  ///
+  /// # #[cfg(not(feature = "mysql"))]
+  /// # assert_eq!(query, "SELECT * FROM users ORDER BY id LIMIT 25 OFFSET 50");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users ORDER BY id LIMIT 25 OFFSET 50
+  /// ```
+  pub fn paginate(mut self, page: u64, per_page: u64) -> Self {
+    let offset = page.saturating_sub(1).saturating_mul(per_page);
+    self._limit = per_page.to_string().into();
+    self._offset = match offset {
+      0 => "".into(),
+      offset => offset.to_string().into(),
+    };
+    self
+  }
+
+  /// Parses a SQL statement into the clauses of the [Select] builder, so the statement can be changed with the builder methods.
+  /// The text of each clause is kept as written, a comment before the statement is kept as a raw SQL
+  /// and the queries of the `with` clause are parsed by the builder of its statement.
+  /// The members of `union`, `except` and `intersect` are parsed as well, see [ParseError] for the statements
+  /// that can't be split into the clauses of the builder.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::parse("SELECT id, login FROM users WHERE active = true")
+  ///   .unwrap()
+  ///   .and("login = 'foo'");
+  ///
+  /// # let expected = "SELECT id, login FROM users WHERE active = true AND login = 'foo'";
+  /// # assert_eq!(select.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// SELECT id, login FROM users WHERE active = true AND login = 'foo'
+  /// ```
+  pub fn parse(sql: &'a str) -> Result<Self, ParseError> {
+    let statement = parse::statement(sql)?;
+    parse::nested(sql, statement, Self::parse_statement)
+  }
+
+  /// Writes the current state of the [Select] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let select = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "SELECT id FROM users\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// SELECT id FROM users
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Removes the conditions of the having clause for which the predicate returns `true`,
+  /// the conditions joined by the `or` method are a single [Condition::Or]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login, count(*)")
+  ///   .from("orders")
+  ///   .group_by("login")
+  ///   .having("count(*) > 10")
+  ///   .remove_having(|condition| *condition == "count(*) > 10".into())
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login, count(*) FROM orders GROUP BY login";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT login, count(*) FROM orders GROUP BY login
+  /// ```
+  pub fn remove_having(mut self, mut predicate: impl FnMut(&Condition) -> bool) -> Self {
+    self._having.retain(|condition| predicate(condition) == false);
+    self._duplicates.clear(Some(SelectClause::Having));
+    self
+  }
+
+  /// Removes the conditions of the where clause for which the predicate returns `true`,
+  /// the conditions joined by the `or` method are a single [Condition::Or]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("active = true")
+  ///   .where_clause("created_at > '2024-01-01'")
+  ///   .remove_where(|condition| match condition {
+  ///     sql::Condition::Raw(text) => text.to_string().starts_with("created_at"),
+  ///     _ => false,
+  ///   })
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM users WHERE active = true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users WHERE active = true
+  /// ```
+  pub fn remove_where(mut self, mut predicate: impl FnMut(&Condition) -> bool) -> Self {
+    self._where.retain(|condition| predicate(condition) == false);
+    self._duplicates.clear(Some(SelectClause::Where));
+    self
+  }
+
+  /// Replaces the values of the from clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login")
   ///   .from("users")
-  ///   .where_clause("login = 'foo'")
+  ///   .replace_from("users_bk")
   ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users_bk";
+  /// # assert_eq!(expected, query);
   /// ```
   ///
   /// Output
+  ///
   /// ```sql
-  /// SELECT id FROM users WHERE login = 'foo'
+  /// SELECT login FROM users_bk
   /// ```
-  pub fn as_string(&self) -> String {
-    let fmts = fmt::one_line();
-    self.concat(&fmts)
+  pub fn replace_from<'s>(mut self, tables: impl Into<Sql<'s>>) -> Self {
+    self._from.clear();
+    self._duplicates.clear(Some(SelectClause::From));
+    self.from(tables)
   }
 
-  /// Prints the current state of the Select into console output in a more ease to read version.
-  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  /// Replaces the values of the order by clause, the raw SQL added before and after the clause is kept
   ///
   /// # Examples
   /// ```
   /// use sql_query_builder as sql;
   ///
-  /// let select = sql::Select::new()
-  ///   .select("*")
+  /// let query = sql::Select::new()
+  ///   .select("login")
   ///   .from("users")
-  ///   .where_clause("login = foo")
-  ///   .and("active = true")
-  ///   .debug();
+  ///   .order_by("login asc")
+  ///   .replace_order_by("created_at desc")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT login FROM users ORDER BY created_at desc";
+  /// # assert_eq!(expected, query);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// SELECT *
-  /// FROM users
-  /// WHERE login = foo AND active = true
+  /// SELECT login FROM users ORDER BY created_at desc
   /// ```
-  ///
-  /// You can debug different parts of the select putting it in another position
+  pub fn replace_order_by<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._order_by.clear();
+    self._duplicates.clear(Some(SelectClause::OrderBy));
+    self.order_by(column)
+  }
+
+  /// Replaces the values of the select clause, the raw SQL added before and after the clause is kept
   ///
   /// # Examples
   /// ```
   /// use sql_query_builder as sql;
   ///
-  /// let select_query = sql::Select::new()
-  ///   .select("*")
+  /// let query = sql::Select::new()
+  ///   .select("id, login")
   ///   .from("users")
-  ///   .debug()
-  ///   .where_clause("login = foo")
-  ///   .and("active = true")
+  ///   .replace_select("count(*)")
   ///   .as_string();
+  ///
+  /// # let expected = "SELECT count(*) FROM users";
+  /// # assert_eq!(expected, query);
   /// ```
   ///
   /// Output
   ///
   /// ```sql
-  /// SELECT *
-  /// FROM users
+  /// SELECT count(*) FROM users
   /// ```
-  pub fn debug(self) -> Self {
-    let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
-    self
-  }
-
-  /// The except clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
-  pub fn except(mut self, select: Self) -> Self {
-    self._except.push(select);
-    self
-  }
-
-  /// The from clause
-  pub fn from(mut self, tables: &'a str) -> Self {
-    push_unique(&mut self._from, tables.trim().to_owned());
-    self
-  }
-
-  /// The group by clause
-  pub fn group_by(mut self, column: &'a str) -> Self {
-    push_unique(&mut self._group_by, column.trim().to_owned());
-    self
-  }
-
-  /// The having clause
-  pub fn having(mut self, condition: &'a str) -> Self {
-    push_unique(&mut self._having, condition.trim().to_owned());
-    self
+  pub fn replace_select<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._select.clear();
+    self._duplicates.clear(Some(SelectClause::Select));
+    self.select(column)
   }
 
-  /// The cross join clause
-  pub fn cross_join(mut self, table: &'a str) -> Self {
-    let table = table.trim();
-    let table = format!("CROSS JOIN {table}");
-    push_unique(&mut self._join, table);
-    self
-  }
-
-  /// The inner join clause
-  pub fn inner_join(mut self, table: &'a str) -> Self {
-    let table = table.trim();
-    let table = format!("INNER JOIN {table}");
-    push_unique(&mut self._join, table);
-    self
-  }
-
-  /// The left join clause
-  pub fn left_join(mut self, table: &'a str) -> Self {
-    let table = table.trim();
-    let table = format!("LEFT JOIN {table}");
-    push_unique(&mut self._join, table);
-    self
+  /// Replaces the values of the where clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .replace_where("login = 'bar'")
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT * FROM users WHERE login = 'bar'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT * FROM users WHERE login = 'bar'
+  /// ```
+  pub fn replace_where(mut self, condition: impl Into<Condition>) -> Self {
+    self._where.clear();
+    self._duplicates.clear(Some(SelectClause::Where));
+    self.where_clause(condition)
   }
 
   /// The right join clause
-  pub fn right_join(mut self, table: &'a str) -> Self {
-    let table = table.trim();
-    let table = format!("RIGHT JOIN {table}");
-    push_unique(&mut self._join, table);
+  pub fn right_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Right, table);
+    self._duplicates.push(Some(SelectClause::Join), &mut self._join, table);
     self
   }
 
-  /// The intersect clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
+  /// The intersect clause
   pub fn intersect(mut self, select: Self) -> Self {
     self._intersect.push(select);
     self
   }
 
-  /// The limit clause. This method overrides the previous value
+  /// The limit clause, accepts integers and texts like `"10"` or `"$1"`. This method overrides the previous value
   ///
   /// # Examples
   /// ```
   /// use sql_query_builder as sql;
   ///
   /// let select = sql::Select::new()
-  ///   .limit("123");
+  ///   .limit(123);
   ///
   /// let select = sql::Select::new()
   ///   .limit("1000")
   ///   .limit("123");
   /// ```
-  pub fn limit(mut self, num: &'a str) -> Self {
-    self._limit = num.trim();
+  pub fn limit(mut self, num: impl RowCount<'a>) -> Self {
+    self._limit = Sql::from(num.into_row_count()).trim();
     self
   }
 
@@ -179,36 +1018,86 @@ impl<'a> Select<'a> {
     Self::default()
   }
 
-  /// The offset clause. This method overrides the previous value
+  /// The or operator of the where clause, the conditions defined so far are grouped and joined with the new
+  /// condition by `OR`, the next conditions are joined with the whole group by `AND`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .or("login = 'bar'")
+  ///   .and("created_at < '2024-01-01'")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT * FROM users WHERE (login = 'foo' OR login = 'bar') AND created_at < '2024-01-01'"
+  /// );
+  /// ```
+  pub fn or(mut self, condition: impl Into<Condition>) -> Self {
+    push_or(&mut self._where, condition.into());
+    self._duplicates.clear(Some(SelectClause::Where));
+    self
+  }
+
+  /// The offset clause, accepts integers and texts like `"10"` or `"$1"`. This method overrides the previous value
   ///
   /// # Examples
   /// ```
   /// use sql_query_builder as sql;
   ///
   /// let select = sql::Select::new()
-  ///   .offset("1500");
+  ///   .offset(1500);
   ///
   /// let select = sql::Select::new()
   ///   .offset("1000")
   ///   .offset("1500");
   /// ```
-  pub fn offset(mut self, num: &'a str) -> Self {
-    self._offset = num.trim();
+  pub fn offset(mut self, num: impl RowCount<'a>) -> Self {
+    self._offset = Sql::from(num.into_row_count()).trim();
     self
   }
 
   /// The order by clause
-  pub fn order_by(mut self, column: &'a str) -> Self {
-    push_unique(&mut self._order_by, column.trim().to_owned());
+  pub fn order_by<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(SelectClause::OrderBy),
+      &mut self._order_by,
+      column.into().trim().into_owned(),
+    );
     self
   }
 
+  /// Returns how the positional placeholders (`$1`, `$2`, ...) written in each composed builder were renumbered
+  /// in the final query, see [PlaceholderMap] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .where_clause("id = $1")
+  ///   .and("active = $2");
+  ///
+  /// let map = select.placeholder_map();
+  ///
+  /// assert_eq!(map.count(0), 2);
+  /// ```
+  pub fn placeholder_map(&self) -> PlaceholderMap {
+    let fmts = fmt::one_line();
+    self.placeholders(&fmts)
+  }
+
   /// Prints the current state of the Select into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
-    let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
-    self
+    self.print_to(&mut IoSink::stdout())
   }
 
   /// Adds at the beginning a raw SQL query.
@@ -230,8 +1119,10 @@ impl<'a> Select<'a> {
   /// select * from users u inner join address addr on u.login = addr.owner_login
   /// WHERE u.login = foo
   /// ```
-  pub fn raw(mut self, raw_sql: &'a str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
     self
   }
 
@@ -258,8 +1149,8 @@ impl<'a> Select<'a> {
   /// inner join address addr on u.login = addr.owner_login
   /// WHERE u.login = foo
   /// ```
-  pub fn raw_after(mut self, clause: SelectClause, raw_sql: &'a str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_after(mut self, clause: SelectClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
@@ -284,24 +1175,203 @@ impl<'a> Select<'a> {
   /// from users u inner join address addr on u.login = addr.owner_login
   /// WHERE u.login = foo
   /// ```
-  pub fn raw_before(mut self, clause: SelectClause, raw_sql: &'a str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_before(mut self, clause: SelectClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
   /// The select clause
-  pub fn select(mut self, column: &'a str) -> Self {
-    push_unique(&mut self._select, column.trim().to_owned());
+  pub fn select<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(SelectClause::Select),
+      &mut self._select,
+      column.into().trim().into_owned(),
+    );
     self
   }
 
-  /// The union clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
+  /// The expressions of the select clause in the order they were added
+  pub fn select_items(&self) -> &[Sql<'static>] {
+    &self._select
+  }
+
+  /// Gets the current state of the [Select] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .to_sql(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "SELECT id FROM users WHERE login = ?");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.build_with(dialect).map(|(query, _)| query)
+  }
+
+  /// Gets the current state of the [Select] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .where_clause("login = 'foo'")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "select id, login\nfrom users\nwhere login = 'foo'";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// select id, login
+  /// from users
+  /// where login = 'foo'
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [Select] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [Select::validate] for the checks, or [BuildError::BindMismatch] when
+  /// the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .limit("10")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "SELECT id FROM users LIMIT 10";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// SELECT id FROM users LIMIT 10
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(|(query, _)| query)
+  }
+
+  /// The union clause
   pub fn union(mut self, select: Self) -> Self {
     self._union.push(select);
     self
   }
 
+  /// The queries of the union clause
+  pub fn union_queries(&self) -> &[Select<'a>] {
+    &self._union
+  }
+
+  /// Checks the current state of the [Select], returns an error naming the clause
+  /// that makes the query invalid, like a limit or offset that isn't a number or a placeholder, or a join without the from clause.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .limit("abc")
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::InvalidValue {
+  ///     clause: sql::Clause::Select(sql::SelectClause::Limit),
+  ///     value: "abc".to_owned(),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(drop)
+  }
+
+  /// Calls the function with the Select only when the condition is true, otherwise the Select is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let only_active = true;
+  /// let query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .when(only_active, |query| query.where_clause("active = true"))
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id, login FROM users WHERE active = true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login FROM users WHERE active = true
+  /// ```
+  pub fn when(self, condition: bool, f: impl FnOnce(Self) -> Self) -> Self {
+    if condition {
+      f(self)
+    } else {
+      self
+    }
+  }
+
+  /// Calls the function with the Select and the value of the option only when the option is `Some`,
+  /// otherwise the Select is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let login = Some("foo");
+  /// let query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .from("users")
+  ///   .when_some(login, |query, login| query.where_clause(format!("login = '{login}'")))
+  ///   .as_string();
+  ///
+  /// # let expected = "SELECT id, login FROM users WHERE login = 'foo'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// SELECT id, login FROM users WHERE login = 'foo'
+  /// ```
+  pub fn when_some<T>(self, value: Option<T>, f: impl FnOnce(Self, T) -> Self) -> Self {
+    match value {
+      Some(value) => f(self, value),
+      None => self,
+    }
+  }
+
   /// The where clause
   ///
   /// # Examples
@@ -312,12 +1382,59 @@ impl<'a> Select<'a> {
   ///   .from("users")
   ///   .where_clause("login = $1");
   /// ```
-  pub fn where_clause(mut self, condition: &'a str) -> Self {
-    push_unique(&mut self._where, condition.trim().to_owned());
+  pub fn where_clause(mut self, condition: impl Into<Condition>) -> Self {
+    self
+      ._duplicates
+      .push(Some(SelectClause::Where), &mut self._where, condition.into());
+    self
+  }
+
+  /// The where clause with bound values, each `?` of the condition is replaced by a placeholder
+  /// numbered at render time, use [build](Select::build) to get the values in the placeholders order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .from("users")
+  ///   .where_clause_bind("login = ? and active = ?", ("foo", true));
+  /// ```
+  ///
+  /// The condition with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn where_clause_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self._duplicates.push(
+      Some(SelectClause::Where),
+      &mut self._where,
+      Condition::Raw(bind(condition, params)),
+    );
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The conditions of the where clause in the order they were added, the conditions joined
+  /// by the `or` method are a single [Condition::Or]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("active = true")
+  ///   .where_clause(sql::Condition::not("deleted"));
+  ///
+  /// assert_eq!(
+  ///   select.where_conditions(),
+  ///   [sql::Condition::raw("active = true"), sql::Condition::not("deleted")]
+  /// );
+  /// ```
+  pub fn where_conditions(&self) -> &[Condition] {
+    &self._where
+  }
+
+  /// The with clause
   ///
   /// # Examples
   /// ```
@@ -344,14 +1461,35 @@ impl<'a> Select<'a> {
   /// FROM orders
   /// WHERE owner_login in (select * from active_users)
   /// ```
-  #[cfg(feature = "postgresql")]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Cow<'a, str>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((trim(name), std::sync::Arc::new(query)));
     self
   }
+
+  /// The names of the queries of the with clause in the order they were added
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .with("active_users", sql::Select::new().select("id").from("users"))
+  ///   .select("id")
+  ///   .from("active_users");
+  ///
+  /// assert_eq!(select.with_ctes().collect::<Vec<_>>(), ["active_users"]);
+  /// ```
+  pub fn with_ctes(&self) -> impl Iterator<Item = &str> {
+    self._with.iter().map(|(name, _)| name.as_ref())
+  }
 }
 
-impl WithQuery for Select<'_> {}
+impl WithQuery for Select<'_> {
+  #[cfg(feature = "serde")]
+  fn tagged(&self) -> crate::serialize::TaggedQuery<'_> {
+    crate::serialize::TaggedQuery::Select(self)
+  }
+}
 
 impl std::fmt::Display for Select<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -362,6 +1500,6 @@ impl std::fmt::Display for Select<'_> {
 impl std::fmt::Debug for Select<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
   }
 }
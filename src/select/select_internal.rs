@@ -1,241 +1,496 @@
 use crate::{
-  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  behavior::{
+    append, append_list, check_clauses, check_number, check_required, concat_raw_before_after, raw_defines,
+    raw_queries, trim_end_from, Concat, ConcatMethods,
+  },
+  bind,
+  condition::Condition,
+  dialect::Dialect,
+  error::{BuildError, ParseError},
   fmt,
-  structure::{Select, SelectClause},
+  join::{Join, JoinKind},
+  pagination::LimitStyle,
+  parse,
+  sql::Sql,
+  structure::{Combinator, Select, SelectClause},
 };
 
 impl<'a> ConcatMethods<'a, SelectClause> for Select<'_> {}
 
 impl Concat for Select<'_> {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_owned();
-
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        SelectClause::With,
-        &self._with,
-      );
-    }
-    query = self.concat_select(query, &fmts);
-    query = self.concat_from(
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    // the compound selects wraps the previous statement in parentheses, they are opened before it
+    let (open, close) = self.combinator_parentheses(fmts);
+    let combinators = [&self._except, &self._intersect, &self._union];
+    for _ in combinators.iter().filter(|list| list.is_empty() == false) {
+      query.push_str(open);
+    }
+    let statement_start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_with(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::With,
+      &self._with,
+    );
+    self.concat_select(query, fmts);
+    self.concat_from(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       SelectClause::From,
       &self._from,
     );
-    query = self.concat_join(query, &fmts);
-    query = self.concat_where(
+    self.concat_join(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::Join,
+      &self._join,
+    );
+    self.concat_where(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       SelectClause::Where,
       &self._where,
     );
-    query = self.concat_group_by(query, &fmts);
-    query = self.concat_having(query, &fmts);
-    query = self.concat_order_by(query, &fmts);
-    query = self.concat_limit(query, &fmts);
-    query = self.concat_offset(query, &fmts);
-    #[cfg(feature = "postgresql")]
-    {
-      use crate::structure::Combinator;
-      query = self.concat_combinator(query, &fmts, Combinator::Except);
-      query = self.concat_combinator(query, &fmts, Combinator::Intersect);
-      query = self.concat_combinator(query, &fmts, Combinator::Union);
+    self.concat_group_by(query, fmts);
+    self.concat_having(query, fmts);
+    self.concat_order_by(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::OrderBy,
+      &self._order_by,
+    );
+
+    match self.rendered_limit_style() {
+      LimitStyle::Limit => {
+        // MySQL renders both clauses as `LIMIT offset, count`, the offset clause keeps only its raw SQL
+        let limit = self.rendered_limit(fmts);
+        let is_mysql = fmts.dialect.unwrap_or_else(Dialect::of_features) == Dialect::Mysql;
+        let (limit, offset) = if is_mysql && limit.is_empty() == false && self._offset.is_empty() == false {
+          (self._offset.clone().push(", ").push(limit), Sql::default())
+        } else {
+          (limit, self._offset.clone())
+        };
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          query,
+          fmts,
+          SelectClause::Limit,
+          &limit,
+        );
+        self.concat_offset(query, fmts, &offset, "");
+      }
+      LimitStyle::FetchFirst => {
+        self.concat_offset(query, fmts, &self._offset, " ROWS");
+        self.concat_fetch_first(query, fmts);
+      }
+      LimitStyle::Top => {
+        // the limit was rendered by the select clause, only the raw SQL of the clause is left
+        self.concat_limit(
+          &self._raw_before,
+          &self._raw_after,
+          query,
+          fmts,
+          SelectClause::Limit,
+          &Sql::default(),
+        );
+      }
     }
+    let parentheses = (open, close);
+    self.concat_combinator(query, fmts, statement_start, parentheses, Combinator::Except);
+    self.concat_combinator(query, fmts, statement_start, parentheses, Combinator::Intersect);
+    self.concat_combinator(query, fmts, statement_start, parentheses, Combinator::Union);
 
-    query.trim_end().to_owned()
+    trim_end_from(query, start);
+  }
+
+  fn check_dialect(&self, dialect: Dialect) -> Result<(), BuildError> {
+    let limit_style = self.rendered_limit_style();
+    check_clauses(
+      dialect,
+      [
+        (
+          limit_style == LimitStyle::FetchFirst && self._limit.is_empty() == false,
+          SelectClause::Limit,
+          &[Dialect::Postgres][..],
+        ),
+        (
+          limit_style == LimitStyle::FetchFirst && self._offset.is_empty() == false,
+          SelectClause::Offset,
+          &[Dialect::Postgres][..],
+        ),
+        (
+          limit_style == LimitStyle::Top && self._limit.is_empty() == false,
+          SelectClause::Limit,
+          &[][..],
+        ),
+        (
+          self._join.iter().any(|join| join.kind() == JoinKind::FullOuter),
+          SelectClause::Join,
+          &[Dialect::Postgres, Dialect::Sqlite][..],
+        ),
+        (
+          self._join.iter().any(Join::is_lateral),
+          SelectClause::Join,
+          &[Dialect::Postgres, Dialect::Mysql][..],
+        ),
+      ],
+    )?;
+    for (_, query) in &self._with {
+      query.check_dialect(dialect)?;
+    }
+    for select in self._except.iter().chain(&self._intersect).chain(&self._union) {
+      select.check_dialect(dialect)?;
+    }
+    for select in self._join.iter().filter_map(Join::subquery) {
+      select.check_dialect(dialect)?;
+    }
+
+    Ok(())
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let has_raw = |clause, keywords: &[&'static str]| {
+      raw_defines(&self._raw, &self._raw_before, &self._raw_after, clause, keywords)
+    };
+    let clauses: Vec<(bool, SelectClause)> = vec![
+      (self._with.is_empty() == false, SelectClause::With),
+      (self._from.is_empty() == false, SelectClause::From),
+      (self._join.is_empty() == false, SelectClause::Join),
+      (self._where.is_empty() == false, SelectClause::Where),
+      (self._group_by.is_empty() == false, SelectClause::GroupBy),
+      (self._having.is_empty() == false, SelectClause::Having),
+      (self._order_by.is_empty() == false, SelectClause::OrderBy),
+      (self._limit.is_empty() == false, SelectClause::Limit),
+      (self._offset.is_empty() == false, SelectClause::Offset),
+      (self._except.is_empty() == false, SelectClause::Except),
+      (self._intersect.is_empty() == false, SelectClause::Intersect),
+      (self._union.is_empty() == false, SelectClause::Union),
+    ];
+    let has_select = self._select.is_empty() == false || has_raw(SelectClause::Select, &["SELECT"]);
+    let has_from = self._from.is_empty() == false || has_raw(SelectClause::From, &["FROM"]);
+    check_required((has_select, SelectClause::Select), clauses)?;
+    check_required(
+      (has_from, SelectClause::From),
+      [(self._join.is_empty() == false, SelectClause::Join)],
+    )?;
+    check_number(SelectClause::Limit, &self._limit)?;
+    check_number(SelectClause::Offset, &self._offset)?;
+    for (_, query) in &self._with {
+      query.check_query()?;
+    }
+    for select in self._except.iter().chain(&self._intersect).chain(&self._union) {
+      select.check_query()?;
+    }
+    for select in self._join.iter().filter_map(Join::subquery) {
+      select.check_query()?;
+    }
+
+    Ok(())
   }
 }
 
 impl Select<'_> {
-  #[cfg(feature = "postgresql")]
+  /// The syntax of the limit clause, the `TOP` syntax has no offset, so a query with offset is rendered with `FETCH FIRST`
+  fn rendered_limit_style(&self) -> LimitStyle {
+    match self._limit_style {
+      LimitStyle::Top if self._offset.is_empty() == false => LimitStyle::FetchFirst,
+      style => style,
+    }
+  }
+
+  /// MySQL and SQLite don't accept the offset clause without a limit, so the largest limit of the dialect is rendered
+  fn rendered_limit(&self, fmts: &fmt::Formatter) -> Sql<'_> {
+    let has_raw_limit = raw_queries(&self._raw_before, &SelectClause::Limit)
+      .chain(raw_queries(&self._raw_after, &SelectClause::Limit))
+      .next()
+      .is_some();
+    if self._limit.is_empty() == false || self._offset.is_empty() || has_raw_limit {
+      return self._limit.clone();
+    }
+    match fmts.dialect.unwrap_or_else(Dialect::of_features) {
+      Dialect::Mysql => Sql::from("18446744073709551615"),
+      Dialect::Sqlite => Sql::from("-1"),
+      Dialect::Postgres => self._limit.clone(),
+    }
+  }
+
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(SelectClause::From), &self._from)?;
+    duplicates.check(Some(SelectClause::GroupBy), &self._group_by)?;
+    duplicates.check(Some(SelectClause::Having), &self._having)?;
+    duplicates.check(Some(SelectClause::Join), &self._join)?;
+    duplicates.check(Some(SelectClause::OrderBy), &self._order_by)?;
+    duplicates.check(Some(SelectClause::Select), &self._select)?;
+    duplicates.check(Some(SelectClause::Where), &self._where)?;
+    duplicates.check(None, &self._raw)
+  }
+
+  /// SQLite rejects parenthesized members of a compound select
+  fn combinator_parentheses(&self, fmts: &fmt::Formatter) -> (&'static str, &'static str) {
+    let parenthesize = match fmts.dialect {
+      Some(dialect) => dialect != Dialect::Sqlite,
+      None => Dialect::of_features() != Dialect::Sqlite,
+    };
+    if parenthesize {
+      ("(", ")")
+    } else {
+      ("", "")
+    }
+  }
+
+  /// Closes the parentheses of the previous statement, opened before it, and appends the members of the combinator
   fn concat_combinator(
     &self,
-    query: String,
+    query: &mut Sql<'static>,
     fmts: &fmt::Formatter,
-    combinator: crate::structure::Combinator,
-  ) -> String {
-    use crate::behavior::raw_queries;
-    use crate::structure::Combinator;
-
+    statement_start: usize,
+    (open, close): (&str, &str),
+    combinator: Combinator,
+  ) {
     let fmt::Formatter { lb, space, .. } = fmts;
     let (clause, clause_name, clause_list) = match combinator {
       Combinator::Except => (SelectClause::Except, "EXCEPT", &self._except),
       Combinator::Intersect => (SelectClause::Intersect, "INTERSECT", &self._intersect),
       Combinator::Union => (SelectClause::Union, "UNION", &self._union),
     };
-
-    let raw_before = raw_queries(&self._raw_before, &clause).join(space);
-    let raw_after = raw_queries(&self._raw_after, &clause).join(space);
-
-    let space_before = if raw_before.is_empty() {
-      "".to_owned()
-    } else {
-      space.to_string()
-    };
-    let space_after = if raw_after.is_empty() {
-      "".to_owned()
-    } else {
-      space.to_string()
-    };
-
     if clause_list.is_empty() {
-      let sql = "".to_owned();
-      return format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}");
+      return concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, clause, |_| {});
     }
 
-    let right_stmt = clause_list.iter().fold("".to_owned(), |acc, select| {
-      let query = select.concat(&fmts);
-      format!("{acc}{clause_name}{space}({lb}{query}){space}{lb}")
-    });
-
-    let query = query.trim_end();
-    let space_before = space;
-    let left_stmt = format!("({query}{raw_before}){space_before}");
-
-    format!("{left_stmt}{right_stmt}{raw_after}{space_after}")
+    trim_end_from(query, statement_start);
+    let has_raw_before = raw_queries(&self._raw_before, &clause).next().is_some();
+    if query.len() > statement_start && has_raw_before {
+      query.push_str(space);
+    }
+    append_list(query, raw_queries(&self._raw_before, &clause), space);
+    append(query, &[close, space]);
+    for select in clause_list {
+      append(query, &[clause_name, space, open, lb]);
+      bind::scope(query, |query| select.concat_into(query, fmts));
+      append(query, &[close, space, lb]);
+    }
+    if append_list(query, raw_queries(&self._raw_after, &clause), space) > 0 {
+      query.push_str(space);
+    }
   }
 
-  fn concat_group_by(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_group_by(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._group_by.is_empty() == false {
-      let columns = self._group_by.join(comma);
-      format!("GROUP BY{space}{columns}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       SelectClause::GroupBy,
-      sql,
-    )
+      |query| {
+        if self._group_by.is_empty() == false {
+          append(query, &["GROUP BY", space]);
+          append_list(query, &self._group_by, comma);
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_having(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_having(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._having.is_empty() == false {
-      let conditions = self._having.join(" AND ");
-      format!("HAVING{space}{conditions}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       SelectClause::Having,
-      sql,
-    )
+      |query| {
+        if self._having.is_empty() == false {
+          append(query, &["HAVING", space]);
+          Condition::concat_list(query, &self._having, " AND ");
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_join(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_fetch_first(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._join.is_empty() == false {
-      let joins = self._join.join(format!("{space}{lb}").as_str());
-      format!("{joins}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
-      SelectClause::Join,
-      sql,
-    )
+      SelectClause::Limit,
+      |query| {
+        if self._limit.is_empty() == false {
+          append(query, &["FETCH FIRST", space]);
+          query.append(&self._limit);
+          append(query, &[space, "ROWS ONLY", space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_limit(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_offset(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter, offset: &Sql, unit: &str) {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._limit.is_empty() == false {
-      let count = self._limit;
-      format!("LIMIT{space}{count}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
-      SelectClause::Limit,
-      sql,
-    )
+      SelectClause::Offset,
+      |query| {
+        if offset.is_empty() == false {
+          append(query, &["OFFSET", space]);
+          query.append(offset);
+          append(query, &[unit, space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_offset(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._offset.is_empty() == false {
-      let start = self._offset;
-      format!("OFFSET{space}{start}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
+  fn concat_select(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
-      SelectClause::Offset,
-      sql,
-    )
+      SelectClause::Select,
+      |query| {
+        if self._select.is_empty() == false {
+          append(query, &["SELECT", space]);
+          if self.rendered_limit_style() == LimitStyle::Top && self._limit.is_empty() == false {
+            append(query, &["TOP", space]);
+            query.append(&self._limit);
+            query.push_str(space);
+          }
+          append_list(query, &self._select, comma);
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
+}
 
-  fn concat_order_by(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._order_by.is_empty() == false {
-      let columns = self._order_by.join(comma);
-      format!("ORDER BY{space}{columns}{space}{lb}")
-    } else {
-      "".to_owned()
+const CLAUSES: &[&[&str]] = &[
+  &["WITH"],
+  &["SELECT"],
+  &["FROM"],
+  parse::JOINS,
+  &["WHERE"],
+  &["GROUP BY"],
+  &["HAVING"],
+  &["ORDER BY"],
+  &["LIMIT"],
+  &["OFFSET"],
+];
+
+const COMBINATORS: &[&str] = &[
+  "EXCEPT",
+  "EXCEPT ALL",
+  "INTERSECT",
+  "INTERSECT ALL",
+  "UNION",
+  "UNION ALL",
+];
+
+impl<'a> Select<'a> {
+  /// Parses a select and its compound members, the members are split before the clauses
+  pub(crate) fn parse_statement(sql: &'a str) -> Result<Self, ParseError> {
+    let (first, members) = parse::split_clauses(sql, COMBINATORS)?;
+    let mut select = match parse::unwrap_parentheses(first)? {
+      Some(inner) => parse::nested(sql, inner, Self::parse_statement)?,
+      None => parse::nested(sql, first, Self::parse_clauses)?,
     };
 
-    concat_raw_before_after(
-      &self._raw_before,
-      &self._raw_after,
-      query,
-      fmts,
-      SelectClause::OrderBy,
-      sql,
-    )
+    select.parse_members(sql, members)?;
+
+    Ok(select)
   }
 
-  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._select.is_empty() == false {
-      let columns = self._select.join(comma);
-      format!("SELECT{space}{columns}{space}{lb}")
-    } else {
-      "".to_owned()
+  fn parse_clauses(sql: &'a str) -> Result<Self, ParseError> {
+    let (leading, clauses) = parse::split_sequence(sql, CLAUSES)?;
+    let mut select = Self {
+      _raw: parse::raw(leading, "SELECT")?,
+      ..Default::default()
     };
 
-    concat_raw_before_after(
-      &self._raw_before,
-      &self._raw_after,
-      query,
-      fmts,
-      SelectClause::Select,
-      sql,
-    )
+    for clause in clauses {
+      let body = clause.body;
+      match clause.keyword {
+        "WITH" => select._with = parse::with_items(sql, &clause)?,
+        "SELECT" => select._select = parse::list(body)?,
+        "FROM" => select._from = parse::list(body)?,
+        "WHERE" => select._where.push(Condition::raw(body)),
+        "GROUP BY" => select._group_by = parse::list(body)?,
+        "HAVING" => select._having.push(Condition::raw(body)),
+        "ORDER BY" => select._order_by = parse::list(body)?,
+        "LIMIT" => select._limit = body.into(),
+        "OFFSET" => select._offset = body.into(),
+        _ => select._join.push(Join::parsed(&clause)),
+      }
+    }
+
+    Ok(select)
+  }
+
+  /// The members are rendered grouped by combinator, so the combinators must follow the same order,
+  /// including the members of a parenthesized first select.
+  /// When the members are parenthesized by the renderer, a trailing order by, limit or offset
+  /// would be moved from the compound to the last member and is rejected
+  fn parse_members(&mut self, sql: &'a str, members: Vec<parse::Clause<'a>>) -> Result<(), ParseError> {
+    let mut previous = match (self._intersect.is_empty(), self._union.is_empty()) {
+      (_, false) => 2,
+      (false, true) => 1,
+      (true, true) => 0,
+    };
+
+    for member in members {
+      let rank = match member.keyword {
+        "EXCEPT" => 0,
+        "INTERSECT" => 1,
+        "UNION" => 2,
+        _ => return Err(member.unsupported_error()),
+      };
+      if member.body.is_empty() {
+        return Err(member.empty_error());
+      }
+      if rank < previous {
+        return Err(member.unexpected_error());
+      }
+      previous = rank;
+
+      let is_parenthesized = parse::unwrap_parentheses(member.body)?.is_some();
+      if Dialect::of_features() != Dialect::Sqlite && is_parenthesized == false {
+        let (_, trailing) = parse::split_clauses(member.body, &["LIMIT", "OFFSET", "ORDER BY"])?;
+        if let Some(clause) = trailing.first() {
+          return Err(clause.unsupported_error().shift(parse::offset(sql, member.body)));
+        }
+      }
+
+      let select = parse::nested(sql, member.body, Self::parse_statement)?;
+      match rank {
+        0 => self._except.push(select),
+        1 => self._intersect.push(select),
+        _ => self._union.push(select),
+      }
+    }
+
+    Ok(())
   }
 }
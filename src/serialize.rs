@@ -0,0 +1,235 @@
+use crate::{
+  bind::Value,
+  ident::Ident,
+  literal::Literal,
+  sql::{Fragment, Segment, Sql},
+  structure::{Delete, Insert, Select, Update, Values},
+};
+use serde::{
+  de::{self, MapAccess, SeqAccess, Visitor},
+  ser::{self, SerializeSeq},
+  Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::borrow::Cow;
+
+/// The query of a with clause tagged by the builder that constructs it, serialized as `{"Select": {...}}`
+#[derive(Serialize)]
+pub enum TaggedQuery<'q> {
+  Delete(&'q Delete<'q>),
+  Insert(&'q Insert<'q>),
+  Select(&'q Select<'q>),
+  Update(&'q Update<'q>),
+  Values(&'q Values),
+}
+
+/// Serializes the with clause as a list of `{"name": "...", "query": {"Select": {...}}}`,
+/// the deserialized queries are owned by the builder
+pub(crate) mod with_items {
+  use super::TaggedQuery;
+  use crate::{
+    behavior::{WithItem, WithQuery},
+    structure::{Delete, Insert, Select, Update, Values},
+  };
+  use serde::{Deserialize, Deserializer, Serialize, Serializer};
+  use std::{borrow::Cow, sync::Arc};
+
+  #[derive(Serialize)]
+  struct Item<'q> {
+    name: &'q str,
+    query: TaggedQuery<'q>,
+  }
+
+  #[derive(Deserialize)]
+  struct OwnedItem {
+    name: String,
+    query: OwnedQuery,
+  }
+
+  #[derive(Deserialize)]
+  enum OwnedQuery {
+    Delete(Box<Delete<'static>>),
+    Insert(Box<Insert<'static>>),
+    Select(Box<Select<'static>>),
+    Update(Box<Update<'static>>),
+    Values(Box<Values>),
+  }
+
+  impl OwnedQuery {
+    fn shared(self) -> Arc<dyn WithQuery> {
+      match self {
+        Self::Delete(query) => Arc::new(*query),
+        Self::Insert(query) => Arc::new(*query),
+        Self::Select(query) => Arc::new(*query),
+        Self::Update(query) => Arc::new(*query),
+        Self::Values(query) => Arc::new(*query),
+      }
+    }
+  }
+
+  pub fn serialize<S: Serializer>(items: &[WithItem<'_>], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(items.iter().map(|(name, query)| Item {
+      name,
+      query: query.tagged(),
+    }))
+  }
+
+  pub fn deserialize<'de, 'a, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<WithItem<'a>>, D::Error> {
+    let items = Vec::<OwnedItem>::deserialize(deserializer)?;
+    let items = items
+      .into_iter()
+      .map(|item| (Cow::Owned(item.name), item.query.shared()))
+      .collect();
+
+    Ok(items)
+  }
+}
+
+/// A fragment of a [Sql] tagged by its type, serialized as `{"bind": {"Int": 5}}`, `{"ident": ["public", "order"]}`,
+/// `{"literal": {"Text": "foo"}}` or `{"unbound": {"sql": "a = ?", "placeholders": 1, "values": 0}}`
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum TaggedFragment<'f> {
+  Bind(Cow<'f, Value>),
+  Ident(Cow<'f, [String]>),
+  Literal(Cow<'f, Literal>),
+  Unbound {
+    sql: Cow<'f, str>,
+    placeholders: usize,
+    values: usize,
+  },
+}
+
+impl TaggedFragment<'_> {
+  fn of(fragment: &Fragment) -> Option<TaggedFragment<'_>> {
+    let tagged = match fragment {
+      Fragment::Value(value) => TaggedFragment::Bind(Cow::Borrowed(value)),
+      Fragment::Ident(ident) => TaggedFragment::Ident(Cow::Borrowed(ident.names())),
+      Fragment::Literal(literal) => TaggedFragment::Literal(Cow::Borrowed(literal)),
+      Fragment::Unbound {
+        sql,
+        placeholders,
+        values,
+      } => TaggedFragment::Unbound {
+        sql: Cow::Borrowed(sql),
+        placeholders: *placeholders,
+        values: *values,
+      },
+      Fragment::ScopeStart | Fragment::ScopeEnd => return None,
+    };
+    Some(tagged)
+  }
+
+  /// Converts into a fragment of a [Sql], the fragments that couldn't be built by the builders are rejected
+  fn into_fragment<E: de::Error>(self) -> Result<Fragment, E> {
+    let fragment = match self {
+      Self::Bind(value) => Fragment::Value(value.into_owned()),
+      Self::Ident(names) if names.is_empty() => {
+        return Err(E::invalid_length(0, &"an identifier with one or more names"))
+      }
+      Self::Ident(names) => Fragment::Ident(Ident::path(names.into_owned())),
+      Self::Literal(literal) => Fragment::Literal(literal.into_owned()),
+      Self::Unbound {
+        placeholders, values, ..
+      } if placeholders == values => {
+        return Err(E::custom(format!(
+          "the unbound fragment has {placeholders} placeholder(s) and the same number of values"
+        )))
+      }
+      Self::Unbound {
+        sql,
+        placeholders,
+        values,
+      } => Fragment::Unbound {
+        sql: sql.into_owned(),
+        placeholders,
+        values,
+      },
+    };
+    Ok(fragment)
+  }
+}
+
+/// A text without fragments is serialized as a string, otherwise as a list of the texts and the
+/// tagged fragments in the order they are rendered, like `["login = ", {"bind": {"Text": "foo"}}]`
+impl Serialize for Sql<'_> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    if self.has_fragments() == false {
+      return serializer.serialize_str(self.text());
+    }
+    let segments = self.segments();
+    let mut seq = serializer.serialize_seq(None)?;
+    for segment in segments {
+      match segment {
+        Segment::Text("") => {}
+        Segment::Text(text) => seq.serialize_element(text)?,
+        Segment::Fragment(fragment) => match TaggedFragment::of(fragment) {
+          Some(tagged) => seq.serialize_element(&tagged)?,
+          None => return Err(ser::Error::custom("a rendered query can't be serialized")),
+        },
+      }
+    }
+    seq.end()
+  }
+}
+
+impl<'de> Deserialize<'de> for Sql<'_> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(SqlVisitor)
+  }
+}
+
+struct SqlVisitor;
+
+impl<'de> Visitor<'de> for SqlVisitor {
+  type Value = Sql<'static>;
+
+  fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    formatter.write_str("a string or a list of strings and tagged fragments")
+  }
+
+  fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+    Ok(Sql::from(text.to_owned()))
+  }
+
+  fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+    let mut sql = Sql::default();
+    while let Some(part) = seq.next_element::<Part>()? {
+      match part {
+        Part::Text(text) => sql.push_str(&text),
+        Part::Fragment(fragment) => sql.push_fragment(fragment),
+      }
+    }
+    Ok(sql)
+  }
+}
+
+/// An item of the list of a [Sql], a string or a tagged fragment
+enum Part {
+  Text(String),
+  Fragment(Fragment),
+}
+
+impl<'de> Deserialize<'de> for Part {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    deserializer.deserialize_any(PartVisitor)
+  }
+}
+
+struct PartVisitor;
+
+impl<'de> Visitor<'de> for PartVisitor {
+  type Value = Part;
+
+  fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    formatter.write_str(r#"a string or a fragment tagged by "bind", "ident", "literal" or "unbound""#)
+  }
+
+  fn visit_str<E: de::Error>(self, text: &str) -> Result<Self::Value, E> {
+    Ok(Part::Text(text.to_owned()))
+  }
+
+  fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+    let tagged = TaggedFragment::deserialize(de::value::MapAccessDeserializer::new(map))?;
+    tagged.into_fragment().map(Part::Fragment)
+  }
+}
@@ -0,0 +1,287 @@
+use crate::fmt;
+use std::io::IsTerminal;
+
+/// A destination of the queries written by the `debug_to` and `print_to` methods of the builders
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// struct Lines(Vec<String>);
+///
+/// impl sql::Sink for Lines {
+///   fn is_colored(&self) -> bool {
+///     false
+///   }
+///
+///   fn write_query(&mut self, query: &str) {
+///     self.0.push(query.to_owned());
+///   }
+/// }
+///
+/// let mut lines = Lines(vec![]);
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .print_to(&mut lines);
+///
+/// assert_eq!(lines.0, vec!["SELECT id FROM users"]);
+/// ```
+pub trait Sink {
+  /// Returns true when the query can be written highlighted with ANSI colors
+  fn is_colored(&self) -> bool;
+
+  /// Writes the query, a sink that can fail keeps the error instead of interrupting the chain of the builder
+  fn write_query(&mut self, query: &str);
+}
+
+/// Writes the queries into a [std::io::Write], one query per line, keeping the first error found.
+/// The colors are disabled by default, [IoSink::stdout] and [IoSink::stderr] enable them when the output is a terminal,
+/// the colors are never used when the `NO_COLOR` environment variable is defined
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let mut output = vec![];
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .print_to(&mut sql::IoSink::new(&mut output));
+///
+/// assert_eq!(String::from_utf8(output).unwrap(), "SELECT id FROM users\n");
+/// ```
+pub struct IoSink<W: std::io::Write> {
+  colored: bool,
+  error: Option<std::io::Error>,
+  writer: W,
+}
+
+impl<W: std::io::Write> IoSink<W> {
+  /// Enables or disables the colors, the colors are never used when the `NO_COLOR` environment variable is defined
+  pub fn colored(mut self, enabled: bool) -> Self {
+    self.colored = enabled;
+    self
+  }
+
+  /// Returns the writer of the sink
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+
+  /// Creates a sink without colors
+  pub fn new(writer: W) -> Self {
+    Self {
+      colored: false,
+      error: None,
+      writer,
+    }
+  }
+
+  /// Returns the first error found writing the queries, the error is removed from the sink
+  pub fn take_error(&mut self) -> Option<std::io::Error> {
+    self.error.take()
+  }
+}
+
+impl IoSink<std::io::Stderr> {
+  /// Creates a sink of the standard error, colored when the standard error is a terminal
+  pub fn stderr() -> Self {
+    let stderr = std::io::stderr();
+    Self::new(stderr).colored(std::io::stderr().is_terminal())
+  }
+}
+
+impl IoSink<std::io::Stdout> {
+  /// Creates a sink of the standard output, colored when the standard output is a terminal
+  pub fn stdout() -> Self {
+    let stdout = std::io::stdout();
+    Self::new(stdout).colored(std::io::stdout().is_terminal())
+  }
+}
+
+impl<W: std::io::Write> Sink for IoSink<W> {
+  fn is_colored(&self) -> bool {
+    self.colored && is_no_color() == false
+  }
+
+  fn write_query(&mut self, query: &str) {
+    let result = writeln!(self.writer, "{query}");
+    if let (Err(error), None) = (result, &self.error) {
+      self.error = Some(error);
+    }
+  }
+}
+
+/// Writes the queries into a [std::fmt::Write] like a `String`, one query per line, keeping the first error found.
+/// The colors are disabled by default, the colors are never used when the `NO_COLOR` environment variable is defined
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let mut output = String::new();
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .print_to(&mut sql::FmtSink::new(&mut output));
+///
+/// assert_eq!(output, "SELECT id FROM users\n");
+/// ```
+pub struct FmtSink<W: std::fmt::Write> {
+  colored: bool,
+  error: Option<std::fmt::Error>,
+  writer: W,
+}
+
+impl<W: std::fmt::Write> FmtSink<W> {
+  /// Enables or disables the colors, the colors are never used when the `NO_COLOR` environment variable is defined
+  pub fn colored(mut self, enabled: bool) -> Self {
+    self.colored = enabled;
+    self
+  }
+
+  /// Returns the writer of the sink
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+
+  /// Creates a sink without colors
+  pub fn new(writer: W) -> Self {
+    Self {
+      colored: false,
+      error: None,
+      writer,
+    }
+  }
+
+  /// Returns the first error found writing the queries, the error is removed from the sink
+  pub fn take_error(&mut self) -> Option<std::fmt::Error> {
+    self.error.take()
+  }
+}
+
+impl<W: std::fmt::Write> Sink for FmtSink<W> {
+  fn is_colored(&self) -> bool {
+    self.colored && is_no_color() == false
+  }
+
+  fn write_query(&mut self, query: &str) {
+    let result = writeln!(self.writer, "{query}");
+    if let (Err(error), None) = (result, &self.error) {
+      self.error = Some(error);
+    }
+  }
+}
+
+/// Writes the queries as records of the [log](https://docs.rs/log) facade with the target `sql_query_builder`,
+/// this sink can be used enabling the feature flag `log`
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "log")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .print_to(&mut sql::LogSink::new(log::Level::Debug));
+/// # }
+/// ```
+#[cfg(feature = "log")]
+pub struct LogSink {
+  level: log::Level,
+}
+
+#[cfg(feature = "log")]
+impl LogSink {
+  /// Creates a sink that writes the records with the level
+  pub fn new(level: log::Level) -> Self {
+    Self { level }
+  }
+}
+
+#[cfg(feature = "log")]
+impl Sink for LogSink {
+  fn is_colored(&self) -> bool {
+    false
+  }
+
+  fn write_query(&mut self, query: &str) {
+    log::log!(target: "sql_query_builder", self.level, "{}", query.trim());
+  }
+}
+
+/// Writes the queries as events of the [tracing](https://docs.rs/tracing) facade with the target `sql_query_builder`,
+/// this sink can be used enabling the feature flag `tracing`
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "tracing")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("id")
+///   .from("users")
+///   .print_to(&mut sql::TracingSink::new(tracing::Level::DEBUG));
+/// # }
+/// ```
+#[cfg(feature = "tracing")]
+pub struct TracingSink {
+  level: tracing::Level,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingSink {
+  /// Creates a sink that writes the events with the level
+  pub fn new(level: tracing::Level) -> Self {
+    Self { level }
+  }
+}
+
+#[cfg(feature = "tracing")]
+impl Sink for TracingSink {
+  fn is_colored(&self) -> bool {
+    false
+  }
+
+  fn write_query(&mut self, query: &str) {
+    use tracing::Level;
+
+    // the level of the tracing macros must be a constant
+    let query = query.trim();
+    match self.level {
+      Level::ERROR => tracing::error!(target: "sql_query_builder", "{query}"),
+      Level::WARN => tracing::warn!(target: "sql_query_builder", "{query}"),
+      Level::INFO => tracing::info!(target: "sql_query_builder", "{query}"),
+      Level::DEBUG => tracing::debug!(target: "sql_query_builder", "{query}"),
+      Level::TRACE => tracing::trace!(target: "sql_query_builder", "{query}"),
+    }
+  }
+}
+
+/// Writes the query rendered by the formatter into the sink, highlighted when the sink accepts colors
+pub(crate) fn write(sink: &mut impl Sink, query: String, fmts: &fmt::Formatter) {
+  let query = fmt::frame(query, fmts);
+  match sink.is_colored() {
+    true => sink.write_query(&fmt::highlight(&query)),
+    false => sink.write_query(&query),
+  }
+}
+
+/// The query of the [Debug](std::fmt::Debug) format of the builders, highlighted when the standard output
+/// accepts colors like the sink of [IoSink::stdout]
+pub(crate) fn debug_format(query: String, fmts: &fmt::Formatter) -> String {
+  let query = fmt::frame(query, fmts);
+  match IoSink::stdout().is_colored() {
+    true => fmt::highlight(&query),
+    false => query,
+  }
+}
+
+/// The `NO_COLOR` environment variable disables the colors when defined with any value but empty, see https://no-color.org
+fn is_no_color() -> bool {
+  std::env::var_os("NO_COLOR").is_some_and(|value| value.is_empty() == false)
+}
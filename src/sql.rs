@@ -0,0 +1,390 @@
+use crate::{bind::Value, dialect::Dialect, ident::Ident, literal::Literal};
+use std::{
+  borrow::Cow,
+  hash::{Hash, Hasher},
+};
+
+/// A piece of SQL of a clause, the text written by the caller and the typed fragments inserted in the text,
+/// like the bound values, the identifiers and the literals. The fragments are kept apart from the text
+/// and are only rendered when the query is built, so the text is never scanned for them.
+///
+/// The builder methods receive a `Sql` anywhere a text is accepted and the getters of the builders return it,
+/// the [Display](std::fmt::Display) of a `Sql` writes the bound values as `?` and the identifiers and literals
+/// with the syntax enabled by the feature flags
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("*")
+///   .from(sql::Ident::new("users"))
+///   .where_clause(sql::Sql::from("path = ").push(sql::Literal::from(r"C:\home")));
+///
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Postgres).unwrap(),
+///   r#"SELECT * FROM "users" WHERE path = E'C:\\home'"#
+/// );
+/// assert_eq!(
+///   select.to_sql(sql::Dialect::Mysql).unwrap(),
+///   r"SELECT * FROM `users` WHERE path = 'C:\\home'"
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Sql<'a> {
+  text: Cow<'a, str>,
+  // the byte position of the text where each fragment is written, in the order they are written
+  fragments: Vec<(usize, Fragment)>,
+}
+
+/// The parts of a [Sql] that aren't text
+#[derive(Clone, Debug)]
+pub(crate) enum Fragment {
+  Value(Value),
+  Ident(Ident),
+  Literal(Literal),
+  /// The start of a composed builder, its positional placeholders are shifted by the ones that come before it
+  ScopeStart,
+  ScopeEnd,
+  /// The `?` placeholders of a `*_bind` method that don't match the number of values
+  Unbound {
+    sql: String,
+    placeholders: usize,
+    values: usize,
+  },
+}
+
+/// A part of a [Sql] in the order it's rendered
+pub(crate) enum Segment<'s> {
+  Text(&'s str),
+  Fragment(&'s Fragment),
+}
+
+impl<'a> Sql<'a> {
+  /// Appends a text, an identifier, a literal or other `Sql` at the end
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let condition = sql::Sql::from("login = ").push(sql::Literal::from("o'reilly"));
+  ///
+  /// assert_eq!(condition.to_string(), "login = 'o''reilly'");
+  /// ```
+  pub fn push<'b>(mut self, part: impl Into<Sql<'b>>) -> Self {
+    self.append(&part.into());
+    self
+  }
+
+  /// The values bound to the `?` placeholders of the text, in the order they are rendered
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new().update("users").set_bind("login = ?", "foo");
+  /// let values = update.set_items()[0].values().collect::<Vec<_>>();
+  ///
+  /// assert_eq!(update.set_items()[0].to_string(), "login = ?");
+  /// assert_eq!(values, [&sql::Value::Text("foo".to_owned())]);
+  /// ```
+  pub fn values(&self) -> impl Iterator<Item = &Value> {
+    self.fragments.iter().filter_map(|(_, fragment)| match fragment {
+      Fragment::Value(value) => Some(value),
+      _ => None,
+    })
+  }
+
+  /// Returns true when the text and the fragments are empty
+  pub fn is_empty(&self) -> bool {
+    self.text.is_empty() && self.fragments.is_empty()
+  }
+
+  /// Converts into a `Sql` that doesn't borrow
+  pub fn into_owned(self) -> Sql<'static> {
+    Sql {
+      text: Cow::Owned(self.text.into_owned()),
+      fragments: self.fragments,
+    }
+  }
+
+  pub(crate) fn new(text: impl Into<Cow<'a, str>>) -> Self {
+    Self {
+      text: text.into(),
+      fragments: vec![],
+    }
+  }
+
+  pub(crate) fn fragment(fragment: Fragment) -> Self {
+    Self {
+      text: Cow::Borrowed(""),
+      fragments: vec![(0, fragment)],
+    }
+  }
+
+  /// The text without the fragments
+  pub(crate) fn text(&self) -> &str {
+    &self.text
+  }
+
+  pub(crate) fn into_text(self) -> String {
+    self.text.into_owned()
+  }
+
+  pub(crate) fn has_fragments(&self) -> bool {
+    self.fragments.is_empty() == false
+  }
+
+  /// Returns true when the `Sql` is a single bound value, like the limit of the `limit_bind` methods
+  pub(crate) fn is_bound_value(&self) -> bool {
+    self.text.is_empty() && matches!(self.fragments.as_slice(), [(_, Fragment::Value(_))])
+  }
+
+  /// The length of the text in bytes
+  pub(crate) fn len(&self) -> usize {
+    self.text.len()
+  }
+
+  pub(crate) fn push_str(&mut self, text: &str) {
+    self.text.to_mut().push_str(text);
+  }
+
+  pub(crate) fn push_fragment(&mut self, fragment: Fragment) {
+    self.fragments.push((self.text.len(), fragment));
+  }
+
+  /// Appends the text and the fragments of other `Sql`
+  pub(crate) fn append(&mut self, other: &Sql) {
+    let offset = self.text.len();
+    self.text.to_mut().push_str(&other.text);
+    self.fragments.extend(
+      other
+        .fragments
+        .iter()
+        .map(|(position, fragment)| (offset + position, fragment.clone())),
+    );
+  }
+
+  /// Removes the whitespace around the text, the whitespace between the fragments is kept
+  pub(crate) fn trim(self) -> Self {
+    let first = self
+      .fragments
+      .first()
+      .map_or(self.text.len(), |(position, _)| *position);
+    let last = self.fragments.last().map_or(0, |(position, _)| *position);
+    let start = first - self.text[..first].trim_start().len();
+    let end = (last + self.text[last..].trim_end().len()).max(start);
+    if start == 0 && end == self.text.len() {
+      return self;
+    }
+
+    let text = match self.text {
+      Cow::Borrowed(text) => Cow::Borrowed(&text[start..end]),
+      Cow::Owned(text) => Cow::Owned(text[start..end].to_owned()),
+    };
+    let fragments = self
+      .fragments
+      .into_iter()
+      .map(|(position, fragment)| (position - start, fragment))
+      .collect();
+
+    Self { text, fragments }
+  }
+
+  /// Removes the whitespace at the end of the text written after the byte position `start`,
+  /// the text before the last fragment is kept
+  pub(crate) fn trim_end_from(&mut self, start: usize) {
+    let last = self.fragments.last().map_or(0, |(position, _)| *position);
+    let start = start.max(last);
+    let len = self.text[start..].trim_end().len();
+    self.text.to_mut().truncate(start + len);
+  }
+
+  /// The part of the text in the byte range with the fragments written inside the range, including its bounds
+  pub(crate) fn slice(&self, range: std::ops::Range<usize>) -> Sql<'_> {
+    let fragments = self
+      .fragments
+      .iter()
+      .filter(|(position, _)| range.contains(position) || *position == range.end)
+      .map(|(position, fragment)| (position - range.start, fragment.clone()))
+      .collect();
+
+    Sql {
+      text: Cow::Borrowed(&self.text[range]),
+      fragments,
+    }
+  }
+
+  /// The text and the fragments in the order they are rendered
+  pub(crate) fn segments(&self) -> Vec<Segment<'_>> {
+    let mut segments = Vec::with_capacity(self.fragments.len() * 2 + 1);
+    let mut last = 0;
+    for (position, fragment) in &self.fragments {
+      segments.push(Segment::Text(&self.text[last..*position]));
+      segments.push(Segment::Fragment(fragment));
+      last = *position;
+    }
+    segments.push(Segment::Text(&self.text[last..]));
+    segments
+  }
+}
+
+impl std::fmt::Display for Sql<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let dialect = Dialect::of_features();
+    for segment in self.segments() {
+      match segment {
+        Segment::Text(text) => f.write_str(text)?,
+        Segment::Fragment(Fragment::Value(_)) => f.write_str("?")?,
+        Segment::Fragment(Fragment::Ident(ident)) => f.write_str(&ident.to_sql(dialect))?,
+        Segment::Fragment(Fragment::Literal(literal)) => f.write_str(&literal.to_sql(dialect))?,
+        Segment::Fragment(_) => {}
+      }
+    }
+    Ok(())
+  }
+}
+
+impl PartialEq<str> for Sql<'_> {
+  fn eq(&self, other: &str) -> bool {
+    match self.has_fragments() {
+      true => self.to_string().as_str() == other,
+      false => self.text == other,
+    }
+  }
+}
+
+impl PartialEq<&str> for Sql<'_> {
+  fn eq(&self, other: &&str) -> bool {
+    self == *other
+  }
+}
+
+impl PartialEq<String> for Sql<'_> {
+  fn eq(&self, other: &String) -> bool {
+    self == other.as_str()
+  }
+}
+
+impl<'a> From<&'a str> for Sql<'a> {
+  fn from(text: &'a str) -> Self {
+    Self::new(text)
+  }
+}
+
+impl<'a> From<&'a String> for Sql<'a> {
+  fn from(text: &'a String) -> Self {
+    Self::new(text.as_str())
+  }
+}
+
+impl From<String> for Sql<'_> {
+  fn from(text: String) -> Self {
+    Self::new(text)
+  }
+}
+
+impl<'a> From<Cow<'a, str>> for Sql<'a> {
+  fn from(text: Cow<'a, str>) -> Self {
+    Self::new(text)
+  }
+}
+
+impl From<Ident> for Sql<'_> {
+  fn from(ident: Ident) -> Self {
+    Self::fragment(Fragment::Ident(ident))
+  }
+}
+
+impl From<&Ident> for Sql<'_> {
+  fn from(ident: &Ident) -> Self {
+    Self::fragment(Fragment::Ident(ident.clone()))
+  }
+}
+
+impl From<Literal> for Sql<'_> {
+  fn from(literal: Literal) -> Self {
+    Self::fragment(Fragment::Literal(literal))
+  }
+}
+
+// the floats are compared by its bits, so equality is reflexive like the `Eq` used by the duplicates index requires
+impl PartialEq for Fragment {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Fragment::Value(value), Fragment::Value(other)) => value_key(value) == value_key(other),
+      (Fragment::Ident(ident), Fragment::Ident(other)) => ident == other,
+      (Fragment::Literal(literal), Fragment::Literal(other)) => {
+        literal.to_sql(Dialect::Postgres) == other.to_sql(Dialect::Postgres)
+      }
+      (Fragment::ScopeStart, Fragment::ScopeStart) | (Fragment::ScopeEnd, Fragment::ScopeEnd) => true,
+      (
+        Fragment::Unbound {
+          sql,
+          placeholders,
+          values,
+        },
+        Fragment::Unbound {
+          sql: other_sql,
+          placeholders: other_placeholders,
+          values: other_values,
+        },
+      ) => (sql, placeholders, values) == (other_sql, other_placeholders, other_values),
+      _ => false,
+    }
+  }
+}
+
+impl Eq for Fragment {}
+
+impl Hash for Fragment {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    std::mem::discriminant(self).hash(state);
+    match self {
+      Fragment::Value(value) => value_key(value).hash(state),
+      Fragment::Ident(ident) => ident.hash(state),
+      Fragment::Literal(literal) => literal.to_sql(Dialect::Postgres).hash(state),
+      Fragment::ScopeStart | Fragment::ScopeEnd => {}
+      Fragment::Unbound {
+        sql,
+        placeholders,
+        values,
+      } => (sql, placeholders, values).hash(state),
+    }
+  }
+}
+
+/// The value with the floats replaced by its bits, the zeros are equal regardless of the sign and all the NaN are equal
+fn value_key(value: &Value) -> ValueKey<'_> {
+  match value {
+    Value::Float(value) if *value == 0.0 => ValueKey::Float(0),
+    Value::Float(value) if value.is_nan() => ValueKey::Float(f64::NAN.to_bits()),
+    Value::Float(value) => ValueKey::Float(value.to_bits()),
+    value => ValueKey::Other(value),
+  }
+}
+
+#[derive(PartialEq)]
+enum ValueKey<'v> {
+  Float(u64),
+  Other(&'v Value),
+}
+
+impl Hash for ValueKey<'_> {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    std::mem::discriminant(self).hash(state);
+    match self {
+      ValueKey::Float(bits) => bits.hash(state),
+      ValueKey::Other(value) => {
+        std::mem::discriminant(*value).hash(state);
+        match value {
+          Value::Null | Value::Float(_) => {}
+          Value::Bool(value) => value.hash(state),
+          Value::Int(value) => value.hash(state),
+          Value::Text(value) => value.hash(state),
+          Value::Bytes(value) => value.hash(state),
+        }
+      }
+    }
+  }
+}
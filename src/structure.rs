@@ -1,23 +1,188 @@
-#[cfg(feature = "postgresql")]
+use crate::{condition::Condition, duplicate::Duplicates, join::Join, pagination::LimitStyle, sql::Sql};
+
 pub enum Combinator {
   Except,
   Intersect,
   Union,
 }
 
+/// Builder to contruct a [AlterTable] command
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct AlterTable<'a> {
+  #[cfg_attr(feature = "serde", serde(rename = "add_column"))]
+  pub(crate) _add_column: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "add_constraint"))]
+  pub(crate) _add_constraint: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "alter_column"))]
+  pub(crate) _alter_column: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "alter_table"))]
+  pub(crate) _alter_table: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "drop_column"))]
+  pub(crate) _drop_column: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "drop_constraint"))]
+  pub(crate) _drop_constraint: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<AlterTableClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
+  pub(crate) _raw_after: Vec<(AlterTableClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
+  pub(crate) _raw_before: Vec<(AlterTableClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+  pub(crate) _raw: Vec<String>,
+  #[cfg_attr(feature = "serde", serde(rename = "rename"))]
+  pub(crate) _rename: Sql<'a>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [AlterTable] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "add column created_at timestamp";
+/// let alter_table_query = sql::AlterTable::new()
+///   .alter_table("users")
+///   .add_column("age int")
+///   .raw_after(sql::AlterTableClause::AddColumn, raw)
+///   .as_string();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlterTableClause {
+  AddColumn,
+  AddConstraint,
+  AlterColumn,
+  AlterTable,
+  DropColumn,
+  DropConstraint,
+  Rename,
+}
+
+/// Builder to contruct a [CreateIndex] command
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct CreateIndex<'a> {
+  #[cfg_attr(feature = "serde", serde(rename = "column"))]
+  pub(crate) _column: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "concurrently"))]
+  pub(crate) _concurrently: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "create_index"))]
+  pub(crate) _create_index: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<CreateIndexClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "if_not_exists"))]
+  pub(crate) _if_not_exists: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "on"))]
+  pub(crate) _on: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
+  pub(crate) _raw_after: Vec<(CreateIndexClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
+  pub(crate) _raw_before: Vec<(CreateIndexClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+  pub(crate) _raw: Vec<String>,
+  #[cfg_attr(feature = "serde", serde(rename = "unique"))]
+  pub(crate) _unique: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "where"))]
+  pub(crate) _where: Vec<Condition>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [CreateIndex] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "using gin";
+/// let create_index_query = sql::CreateIndex::new()
+///   .create_index("users_tags_idx")
+///   .on("users")
+///   .raw_after(sql::CreateIndexClause::On, raw)
+///   .column("tags")
+///   .as_string();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CreateIndexClause {
+  Column,
+  CreateIndex,
+  On,
+  Where,
+}
+
+/// Builder to contruct a [CreateTable] command
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct CreateTable<'a> {
+  #[cfg_attr(feature = "serde", serde(rename = "column"))]
+  pub(crate) _column: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "constraint"))]
+  pub(crate) _constraint: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "create_table"))]
+  pub(crate) _create_table: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<CreateTableClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "foreign_key"))]
+  pub(crate) _foreign_key: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "if_not_exists"))]
+  pub(crate) _if_not_exists: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "primary_key"))]
+  pub(crate) _primary_key: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
+  pub(crate) _raw_after: Vec<(CreateTableClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
+  pub(crate) _raw_before: Vec<(CreateTableClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+  pub(crate) _raw: Vec<String>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [CreateTable] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "created_at timestamp not null default now()";
+/// let create_table_query = sql::CreateTable::new()
+///   .create_table("users")
+///   .column("id serial")
+///   .raw_after(sql::CreateTableClause::Column, raw)
+///   .as_string();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CreateTableClause {
+  Column,
+  Constraint,
+  CreateTable,
+  ForeignKey,
+  PrimaryKey,
+}
+
 /// Builder to contruct a [Delete] command
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct Delete<'a> {
-  pub(crate) _delete_from: &'a str,
+  #[cfg_attr(feature = "serde", serde(rename = "delete_from"))]
+  pub(crate) _delete_from: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<DeleteClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "limit"))]
+  pub(crate) _limit: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "order_by"))]
+  pub(crate) _order_by: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
   pub(crate) _raw_after: Vec<(DeleteClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
   pub(crate) _raw_before: Vec<(DeleteClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
   pub(crate) _raw: Vec<String>,
-  pub(crate) _where: Vec<String>,
-
-  #[cfg(feature = "postgresql")]
-  pub(crate) _returning: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg_attr(feature = "serde", serde(rename = "returning"))]
+  pub(crate) _returning: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "where"))]
+  pub(crate) _where: Vec<Condition>,
+  #[cfg_attr(feature = "serde", serde(rename = "with", with = "crate::serialize::with_items"))]
+  pub(crate) _with: Vec<crate::behavior::WithItem<'a>>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Delete] builder
@@ -32,33 +197,125 @@ pub struct Delete<'a> {
 ///   .raw_after(sql::DeleteClause::DeleteFrom, raw)
 ///   .as_string();
 /// ```
-#[derive(PartialEq, Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeleteClause {
   DeleteFrom,
-  Where,
-
-  #[cfg(feature = "postgresql")]
+  Limit,
+  OrderBy,
   Returning,
-  #[cfg(feature = "postgresql")]
+  Where,
   With,
 }
 
+/// Builder to contruct a [DropIndex] command
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct DropIndex {
+  #[cfg_attr(feature = "serde", serde(rename = "cascade"))]
+  pub(crate) _cascade: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "concurrently"))]
+  pub(crate) _concurrently: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "drop_index"))]
+  pub(crate) _drop_index: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<DropIndexClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "if_exists"))]
+  pub(crate) _if_exists: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
+  pub(crate) _raw_after: Vec<(DropIndexClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
+  pub(crate) _raw_before: Vec<(DropIndexClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+  pub(crate) _raw: Vec<String>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [DropIndex] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let drop_index_query = sql::DropIndex::new()
+///   .drop_index("users_login_idx")
+///   .raw_after(sql::DropIndexClause::DropIndex, "restrict")
+///   .as_string();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropIndexClause {
+  DropIndex,
+}
+
+/// Builder to contruct a [DropTable] command
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
+pub struct DropTable {
+  #[cfg_attr(feature = "serde", serde(rename = "cascade"))]
+  pub(crate) _cascade: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "drop_table"))]
+  pub(crate) _drop_table: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<DropTableClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "if_exists"))]
+  pub(crate) _if_exists: bool,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
+  pub(crate) _raw_after: Vec<(DropTableClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
+  pub(crate) _raw_before: Vec<(DropTableClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
+  pub(crate) _raw: Vec<String>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [DropTable] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let drop_table_query = sql::DropTable::new()
+///   .drop_table("users")
+///   .raw_after(sql::DropTableClause::DropTable, "restrict")
+///   .as_string();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropTableClause {
+  DropTable,
+}
+
 /// Builder to contruct a [Insert] command
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct Insert<'a> {
-  pub(crate) _insert_into: &'a str,
-  pub(crate) _on_conflict: &'a str,
-  pub(crate) _overriding: &'a str,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<InsertClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "insert_ignore_into"))]
+  pub(crate) _insert_ignore_into: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "insert_into"))]
+  pub(crate) _insert_into: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "insert_or"))]
+  pub(crate) _insert_or: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "on_conflict"))]
+  pub(crate) _on_conflict: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "on_duplicate_key_update"))]
+  pub(crate) _on_duplicate_key_update: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "overriding"))]
+  pub(crate) _overriding: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
   pub(crate) _raw_after: Vec<(InsertClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
   pub(crate) _raw_before: Vec<(InsertClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
   pub(crate) _raw: Vec<String>,
+  #[cfg_attr(feature = "serde", serde(rename = "returning"))]
+  pub(crate) _returning: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "select"))]
   pub(crate) _select: Option<Select<'a>>,
-  pub(crate) _values: Vec<String>,
-
-  #[cfg(feature = "postgresql")]
-  pub(crate) _returning: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg_attr(feature = "serde", serde(rename = "values"))]
+  pub(crate) _values: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "with", with = "crate::serialize::with_items"))]
+  pub(crate) _with: Vec<crate::behavior::WithItem<'a>>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Insert] builder
@@ -73,44 +330,61 @@ pub struct Insert<'a> {
 ///   .raw_after(sql::InsertClause::InsertInto, raw)
 ///   .as_string();
 /// ```
-#[derive(PartialEq, Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InsertClause {
+  InsertIgnoreInto,
   InsertInto,
+  InsertOr,
   OnConflict,
+  OnDuplicateKeyUpdate,
   Overriding,
+  Returning,
   Select,
   Values,
-
-  #[cfg(feature = "postgresql")]
-  Returning,
-  #[cfg(feature = "postgresql")]
   With,
 }
 
 /// Builder to contruct a [Select] command
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct Select<'a> {
-  pub(crate) _from: Vec<String>,
-  pub(crate) _group_by: Vec<String>,
-  pub(crate) _having: Vec<String>,
-  pub(crate) _join: Vec<String>,
-  pub(crate) _limit: &'a str,
-  pub(crate) _offset: &'a str,
-  pub(crate) _order_by: Vec<String>,
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<SelectClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "except"))]
+  pub(crate) _except: Vec<Self>,
+  #[cfg_attr(feature = "serde", serde(rename = "from"))]
+  pub(crate) _from: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "group_by"))]
+  pub(crate) _group_by: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "having"))]
+  pub(crate) _having: Vec<Condition>,
+  #[cfg_attr(feature = "serde", serde(rename = "intersect"))]
+  pub(crate) _intersect: Vec<Self>,
+  #[cfg_attr(feature = "serde", serde(rename = "join"))]
+  pub(crate) _join: Vec<Join>,
+  #[cfg_attr(feature = "serde", serde(rename = "limit"))]
+  pub(crate) _limit: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "limit_style"))]
+  pub(crate) _limit_style: LimitStyle,
+  #[cfg_attr(feature = "serde", serde(rename = "offset"))]
+  pub(crate) _offset: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "order_by"))]
+  pub(crate) _order_by: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
   pub(crate) _raw_after: Vec<(SelectClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
   pub(crate) _raw_before: Vec<(SelectClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
   pub(crate) _raw: Vec<String>,
-  pub(crate) _select: Vec<String>,
-  pub(crate) _where: Vec<String>,
-
-  #[cfg(feature = "postgresql")]
-  pub(crate) _except: Vec<Self>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _intersect: Vec<Self>,
-  #[cfg(feature = "postgresql")]
+  #[cfg_attr(feature = "serde", serde(rename = "select"))]
+  pub(crate) _select: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "union"))]
   pub(crate) _union: Vec<Self>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg_attr(feature = "serde", serde(rename = "where"))]
+  pub(crate) _where: Vec<Condition>,
+  #[cfg_attr(feature = "serde", serde(rename = "with", with = "crate::serialize::with_items"))]
+  pub(crate) _with: Vec<crate::behavior::WithItem<'a>>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Select] builder
@@ -127,44 +401,54 @@ pub struct Select<'a> {
 ///   .where_clause("u.login = foo")
 ///   .as_string();
 /// ```
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectClause {
+  Except,
   From,
   GroupBy,
   Having,
+  Intersect,
   Join,
   Limit,
   Offset,
   OrderBy,
   Select,
-  Where,
-
-  #[cfg(feature = "postgresql")]
-  Except,
-  #[cfg(feature = "postgresql")]
-  Intersect,
-  #[cfg(feature = "postgresql")]
   Union,
-  #[cfg(feature = "postgresql")]
+  Where,
   With,
 }
 
 /// Builder to contruct a [Update] command
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct Update<'a> {
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<UpdateClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "from"))]
+  pub(crate) _from: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "join"))]
+  pub(crate) _join: Vec<Join>,
+  #[cfg_attr(feature = "serde", serde(rename = "limit"))]
+  pub(crate) _limit: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "order_by"))]
+  pub(crate) _order_by: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
   pub(crate) _raw_after: Vec<(UpdateClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
   pub(crate) _raw_before: Vec<(UpdateClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
   pub(crate) _raw: Vec<String>,
-  pub(crate) _set: Vec<String>,
-  pub(crate) _update: &'a str,
-  pub(crate) _where: Vec<String>,
-
-  #[cfg(feature = "postgresql")]
-  pub(crate) _from: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _returning: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg_attr(feature = "serde", serde(rename = "returning"))]
+  pub(crate) _returning: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "set"))]
+  pub(crate) _set: Vec<Sql<'static>>,
+  #[cfg_attr(feature = "serde", serde(rename = "update"))]
+  pub(crate) _update: Sql<'a>,
+  #[cfg_attr(feature = "serde", serde(rename = "where"))]
+  pub(crate) _where: Vec<Condition>,
+  #[cfg_attr(feature = "serde", serde(rename = "with", with = "crate::serialize::with_items"))]
+  pub(crate) _with: Vec<crate::behavior::WithItem<'a>>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Update] builder
@@ -179,27 +463,34 @@ pub struct Update<'a> {
 ///   .raw_after(sql::UpdateClause::Update, raw)
 ///   .as_string();
 /// ```
-#[derive(PartialEq, Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UpdateClause {
+  From,
+  Join,
+  Limit,
+  OrderBy,
+  Returning,
   Set,
   Update,
   Where,
-
-  #[cfg(feature = "postgresql")]
-  From,
-  #[cfg(feature = "postgresql")]
-  Returning,
-  #[cfg(feature = "postgresql")]
   With,
 }
 
 /// Builder to contruct a [Values] command
 #[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct Values {
+  #[cfg_attr(feature = "serde", serde(rename = "duplicate_policy"))]
+  pub(crate) _duplicates: Duplicates<ValuesClause>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_after"))]
   pub(crate) _raw_after: Vec<(ValuesClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw_before"))]
   pub(crate) _raw_before: Vec<(ValuesClause, String)>,
+  #[cfg_attr(feature = "serde", serde(rename = "raw"))]
   pub(crate) _raw: Vec<String>,
-  pub(crate) _values: Vec<String>,
+  #[cfg_attr(feature = "serde", serde(rename = "values"))]
+  pub(crate) _values: Vec<Sql<'static>>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Values] builder
@@ -214,7 +505,83 @@ pub struct Values {
 ///   .raw_after(sql::ValuesClause::Values, raw_query)
 ///   .debug();
 /// ```
-#[derive(PartialEq, Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ValuesClause {
   Values,
 }
+
+/// A clause of any builder, named by the errors of [BuildError](crate::BuildError) so the callers can match
+/// on the clause that caused the error
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let error = sql::Select::new().select("*").from("users").limit("ten").validate().unwrap_err();
+///
+/// match error {
+///   sql::BuildError::InvalidValue {
+///     clause: sql::Clause::Select(sql::SelectClause::Limit),
+///     ..
+///   } => {}
+///   _ => panic!("unexpected error {error}"),
+/// }
+/// # assert_eq!(sql::Clause::Select(sql::SelectClause::Limit).to_string(), "SelectClause::Limit");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Clause {
+  AlterTable(AlterTableClause),
+  CreateIndex(CreateIndexClause),
+  CreateTable(CreateTableClause),
+  Delete(DeleteClause),
+  DropIndex(DropIndexClause),
+  DropTable(DropTableClause),
+  Insert(InsertClause),
+  Select(SelectClause),
+  Update(UpdateClause),
+  Values(ValuesClause),
+}
+
+macro_rules! clause_from {
+  ($($variant:ident($ty:ty)),+) => {
+    $(
+      impl From<$ty> for Clause {
+        fn from(clause: $ty) -> Self {
+          Clause::$variant(clause)
+        }
+      }
+    )+
+  };
+}
+
+clause_from!(
+  AlterTable(AlterTableClause),
+  CreateIndex(CreateIndexClause),
+  CreateTable(CreateTableClause),
+  Delete(DeleteClause),
+  DropIndex(DropIndexClause),
+  DropTable(DropTableClause),
+  Insert(InsertClause),
+  Select(SelectClause),
+  Update(UpdateClause),
+  Values(ValuesClause)
+);
+
+/// Writes the clause prefixed by its enum, like `SelectClause::Limit`
+impl std::fmt::Display for Clause {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::AlterTable(clause) => write!(f, "AlterTableClause::{clause:?}"),
+      Self::CreateIndex(clause) => write!(f, "CreateIndexClause::{clause:?}"),
+      Self::CreateTable(clause) => write!(f, "CreateTableClause::{clause:?}"),
+      Self::Delete(clause) => write!(f, "DeleteClause::{clause:?}"),
+      Self::DropIndex(clause) => write!(f, "DropIndexClause::{clause:?}"),
+      Self::DropTable(clause) => write!(f, "DropTableClause::{clause:?}"),
+      Self::Insert(clause) => write!(f, "InsertClause::{clause:?}"),
+      Self::Select(clause) => write!(f, "SelectClause::{clause:?}"),
+      Self::Update(clause) => write!(f, "UpdateClause::{clause:?}"),
+      Self::Values(clause) => write!(f, "ValuesClause::{clause:?}"),
+    }
+  }
+}
@@ -1,8 +1,18 @@
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
+  behavior::{defined, push_or, trim, Concat, WithQuery},
+  bind::{bind, Params, PlaceholderMap, Value},
+  condition::Condition,
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::{BuildError, ParseError},
+  fmt::{self, FormatOptions},
+  join::{Join, JoinKind},
+  parse,
+  sink::{self, IoSink, Sink},
+  sql::Sql,
   structure::{Update, UpdateClause},
 };
+use std::borrow::Cow;
 
 impl<'a> Update<'a> {
   /// The same as [where_clause](Update::where_clause) method, useful to write more idiomatic SQL query
@@ -17,11 +27,58 @@ impl<'a> Update<'a> {
   ///   .where_clause("login = $2")
   ///   .and("active = true");
   /// ```
-  pub fn and(mut self, condition: &'a str) -> Self {
+  pub fn and(mut self, condition: impl Into<Condition>) -> Self {
     self = self.where_clause(condition);
     self
   }
 
+  /// The same as [where_clause_bind](Update::where_clause_bind) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("name = ?", "Foo")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .and_bind("active = ?", true);
+  /// ```
+  pub fn and_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self = self.where_clause_bind(condition, params);
+    self
+  }
+
+  /// Calls the function with the Update and returns its result, useful to reuse a set of method calls
+  /// without breaking the method chaining
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn active_only(query: sql::Update) -> sql::Update {
+  ///   query.where_clause("id = 1")
+  /// }
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .apply(active_only)
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false WHERE id = 1";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false WHERE id = 1
+  /// ```
+  pub fn apply(self, f: impl FnOnce(Self) -> Self) -> Self {
+    f(self)
+  }
+
   /// Gets the current state of the Update and returns it as string
   ///
   /// # Examples
@@ -40,11 +97,110 @@ impl<'a> Update<'a> {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    self.render(&fmts).0
+  }
+
+  /// Gets the current state of the Update and returns it as string together with the bound values,
+  /// the placeholders are numbered in the order they appear in the query with the syntax enabled by the feature flags
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("name = ?", "Foo")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .build();
+  ///
+  /// assert_eq!(values.len(), 2);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// UPDATE users SET name = $1 WHERE login = $2
+  /// ```
+  ///
+  /// # Panics
+  /// Panics when the placeholders of a `*_bind` method don't match the values bound to it,
+  /// use [Update::build_with] to get the [BuildError::BindMismatch] error instead
+  pub fn build(&self) -> (String, Vec<Value>) {
+    let fmts = fmt::one_line();
+    self.try_render(&fmts).unwrap_or_else(|error| panic!("{error}"))
+  }
+
+  /// Gets the current state of the [Update] rendered for the given dialect together with the bound values,
+  /// returns an error when a clause defined in the query isn't supported by the dialect, see [Dialect] for the details
+  /// or [BuildError::BindMismatch] when the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("name = ?", "Foo")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .build_with(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = ?1 WHERE login = ?2");
+  /// assert_eq!(values.len(), 2);
+  /// ```
+  pub fn build_with(&self, dialect: Dialect) -> Result<(String, Vec<Value>), BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    self.try_render(&fmts)
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause("login = 'foo'")
+  ///   .clear(sql::UpdateClause::Where)
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false
+  /// ```
+  pub fn clear(mut self, clause: UpdateClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      UpdateClause::Set => self._set.clear(),
+      UpdateClause::Update => self._update = "".into(),
+      UpdateClause::Where => self._where.clear(),
+      UpdateClause::From => self._from.clear(),
+      UpdateClause::Returning => self._returning.clear(),
+      UpdateClause::With => self._with.clear(),
+      UpdateClause::Limit => self._limit = "".into(),
+      UpdateClause::Join => self._join.clear(),
+      UpdateClause::OrderBy => self._order_by.clear(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
   }
 
   /// Prints the current state of the Update into console output in a more ease to read version.
-  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
   ///
   /// # Examples
   /// ```
@@ -65,15 +221,355 @@ impl<'a> Update<'a> {
   /// SET login = 'foo'
   /// ```
   pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Update] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::Update::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Error)
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .set("login = 'foo'")
+  ///   .try_as_string();
+  ///
+  /// # assert!(result.is_err());
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
+    self
+  }
+
+  /// The from clause, supported by Postgres and SQLite
+  pub fn from<'s>(mut self, tables: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(UpdateClause::From),
+      &mut self._from,
+      tables.into().trim().into_owned(),
+    );
+    self
+  }
+
+  /// The cross join clause, supported by MySQL
+  pub fn cross_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Cross, table);
+    self._duplicates.push(Some(UpdateClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The tables of the from clause
+  pub fn from_items(&self) -> &[Sql<'static>] {
+    &self._from
+  }
+
+  /// The inner join clause, supported by MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("orders o")
+  ///   .inner_join("users u ON u.login = o.owner_login")
+  ///   .set("o.active = u.active");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE orders o INNER JOIN users u ON u.login = o.owner_login SET o.active = u.active
+  /// ```
+  pub fn inner_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Inner, table);
+    self._duplicates.push(Some(UpdateClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// Converts the builder into a builder that doesn't borrow any text, this way it can be returned
+  /// from functions or stored in structs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn update_users(table: &str) -> sql::Update<'static> {
+  ///   sql::Update::new()
+  ///     .update(table)
+  ///     .set("active = false")
+  ///     .into_owned()
+  /// }
+  ///
+  /// let query = update_users(&String::from("users")).as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false
+  /// ```
+  pub fn into_owned(self) -> Update<'static> {
+    Update {
+      _duplicates: self._duplicates,
+      _from: self._from,
+      _join: self._join,
+      _limit: self._limit.into_owned(),
+      _order_by: self._order_by,
+      _raw_after: self._raw_after,
+      _raw_before: self._raw_before,
+      _raw: self._raw,
+      _returning: self._returning,
+      _set: self._set,
+      _update: self._update.into_owned(),
+      _where: self._where,
+      _with: crate::behavior::owned_with(self._with),
+    }
+  }
+
+  /// The join clauses in the order they were added
+  pub fn join_items(&self) -> &[Join] {
+    &self._join
+  }
+
+  /// The left join clause, supported by MySQL
+  pub fn left_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Left, table);
+    self._duplicates.push(Some(UpdateClause::Join), &mut self._join, table);
+    self
+  }
+
+  /// The limit clause with a bound value, supported by SQLite and MySQL.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .limit_bind(10)
+  ///   .build_with(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "UPDATE users SET active = false LIMIT ?");
+  /// assert_eq!(values, vec![sql::Value::Int(10)]);
+  /// ```
+  pub fn limit_bind(mut self, value: impl Into<Value>) -> Self {
+    self._limit = bind("?", value.into());
+    self
+  }
+
+  /// The value of the limit clause
+  pub fn limit_value(&self) -> Option<&Sql<'a>> {
+    defined(&self._limit)
+  }
+
+  /// The expressions of the order by clause
+  pub fn order_by_items(&self) -> &[Sql<'static>] {
+    &self._order_by
+  }
+
+  /// Parses a SQL statement into the clauses of the [Update] builder, so the statement can be changed with the builder methods.
+  /// The text of each clause is kept as written, a comment before the statement is kept as a raw SQL
+  /// and the queries of the `with` clause are parsed by the builder of its statement.
+  /// See [ParseError] for the statements that can't be split into the clauses of the builder.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::parse("UPDATE users SET name = 'Foo' WHERE login = 'foo'")
+  ///   .unwrap()
+  ///   .set("updated_at = now()");
+  ///
+  /// # let expected = "UPDATE users SET name = 'Foo', updated_at = now() WHERE login = 'foo'";
+  /// # assert_eq!(update.as_string(), expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// UPDATE users SET name = 'Foo', updated_at = now() WHERE login = 'foo'
+  /// ```
+  pub fn parse(sql: &'a str) -> Result<Self, ParseError> {
+    let statement = parse::statement(sql)?;
+    parse::nested(sql, statement, Self::parse_statement)
+  }
+
+  /// Writes the current state of the [Update] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "UPDATE users SET login = 'foo'\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// UPDATE users SET login = 'foo'
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
+    let fmts = fmt::one_line();
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Removes the conditions of the where clause for which the predicate returns `true`,
+  /// the conditions joined by the `or` method are a single [Condition::Or]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause("login = 'foo'")
+  ///   .where_clause("active = true")
+  ///   .remove_where(|condition| *condition == "active = true".into())
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false WHERE login = 'foo'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false WHERE login = 'foo'
+  /// ```
+  pub fn remove_where(mut self, mut predicate: impl FnMut(&Condition) -> bool) -> Self {
+    self._where.retain(|condition| predicate(condition) == false);
+    self._duplicates.clear(Some(UpdateClause::Where));
+    self
+  }
+
+  /// Replaces the values of the set clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .replace_set("active = true")
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = true";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = true
+  /// ```
+  pub fn replace_set<'s>(mut self, value: impl Into<Sql<'s>>) -> Self {
+    self._set.clear();
+    self._duplicates.clear(Some(UpdateClause::Set));
+    self.set(value)
+  }
+
+  /// Replaces the values of the where clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause("login = 'foo'")
+  ///   .replace_where("login = 'bar'")
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false WHERE login = 'bar'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false WHERE login = 'bar'
+  /// ```
+  pub fn replace_where(mut self, condition: impl Into<Condition>) -> Self {
+    self._where.clear();
+    self._duplicates.clear(Some(UpdateClause::Where));
+    self.where_clause(condition)
+  }
+
+  /// The output names of the returning clause
+  pub fn returning_items(&self) -> &[Sql<'static>] {
+    &self._returning
+  }
+
+  /// The right join clause, supported by MySQL
+  pub fn right_join<'s>(mut self, table: impl Into<Sql<'s>>) -> Self {
+    let table = Join::table(JoinKind::Right, table);
+    self._duplicates.push(Some(UpdateClause::Join), &mut self._join, table);
     self
   }
 
-  /// The from clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
-  pub fn from(mut self, tables: &'a str) -> Self {
-    push_unique(&mut self._from, tables.trim().to_owned());
+  /// The limit clause, supported by SQLite and MySQL.
+  /// This method overrides the previous value
+  ///
+  /// SQLite only accepts this clause when compiled with the `SQLITE_ENABLE_UPDATE_DELETE_LIMIT` option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .limit("10");
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false LIMIT 10
+  /// ```
+  pub fn limit(mut self, num: impl crate::pagination::RowCount<'a>) -> Self {
+    self._limit = Sql::from(num.into_row_count()).trim();
     self
   }
 
@@ -82,12 +578,77 @@ impl<'a> Update<'a> {
     Self::default()
   }
 
+  /// Returns how the positional placeholders (`$1`, `$2`, ...) written in each composed builder were renumbered
+  /// in the final query, see [PlaceholderMap] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("name = $1")
+  ///   .where_clause("login = $2");
+  ///
+  /// let map = update.placeholder_map();
+  ///
+  /// assert_eq!(map.count(0), 2);
+  /// ```
+  pub fn placeholder_map(&self) -> PlaceholderMap {
+    let fmts = fmt::one_line();
+    self.placeholders(&fmts)
+  }
+
+  /// The or operator of the where clause, the conditions defined so far are grouped and joined with the new
+  /// condition by `OR`, the next conditions are joined with the whole group by `AND`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause("login = 'foo'")
+  ///   .or("login = 'bar'")
+  ///   .and("created_at < '2024-01-01'")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "UPDATE users SET active = false WHERE (login = 'foo' OR login = 'bar') AND created_at < '2024-01-01'"
+  /// );
+  /// ```
+  pub fn or(mut self, condition: impl Into<Condition>) -> Self {
+    push_or(&mut self._where, condition.into());
+    self._duplicates.clear(Some(UpdateClause::Where));
+    self
+  }
+
+  /// The order by clause, supported by MySQL
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .order_by("created_at desc")
+  ///   .limit("10");
+  /// ```
+  pub fn order_by<'s>(mut self, column: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(UpdateClause::OrderBy),
+      &mut self._order_by,
+      column.into().trim().into_owned(),
+    );
+    self
+  }
+
   /// Prints the current state of the Update into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
-    let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
-    self
+    self.print_to(&mut IoSink::stdout())
   }
 
   /// Adds at the beginning a raw SQL query.
@@ -109,8 +670,10 @@ impl<'a> Update<'a> {
   /// update users
   /// SET login = 'foo'
   /// ```
-  pub fn raw(mut self, raw_sql: &'a str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
     self
   }
 
@@ -133,8 +696,8 @@ impl<'a> Update<'a> {
   /// UPDATE users
   /// set name = 'Foo'
   /// ```
-  pub fn raw_after(mut self, clause: UpdateClause, raw_sql: &'a str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_after(mut self, clause: UpdateClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
@@ -157,24 +720,160 @@ impl<'a> Update<'a> {
   /// update users
   /// SET name = 'Bar'
   /// ```
-  pub fn raw_before(mut self, clause: UpdateClause, raw_sql: &'a str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_before(mut self, clause: UpdateClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
-  /// The returning clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(feature = "postgresql")]
-  pub fn returning(mut self, output_name: &'a str) -> Self {
-    push_unique(&mut self._returning, output_name.trim().to_owned());
+  /// The returning clause, supported by Postgres and SQLite
+  pub fn returning<'s>(mut self, output_name: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(UpdateClause::Returning),
+      &mut self._returning,
+      output_name.into().trim().into_owned(),
+    );
     self
   }
 
   /// The set clause
-  pub fn set(mut self, value: &'a str) -> Self {
-    push_unique(&mut self._set, value.trim().to_owned());
+  pub fn set<'s>(mut self, value: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push(
+      Some(UpdateClause::Set),
+      &mut self._set,
+      value.into().trim().into_owned(),
+    );
     self
   }
 
+  /// The set clause with bound values, each `?` of the value is replaced by a placeholder
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("name = ?", "Foo")
+  ///   .set_bind("updated_at = ?", 1_640_995_200);
+  /// ```
+  ///
+  /// The value with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn set_bind<'s>(mut self, value: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self
+      ._duplicates
+      .push(Some(UpdateClause::Set), &mut self._set, bind(value, params));
+    self
+  }
+
+  /// The assignments of the set clause in the order they were added
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .set("active = true");
+  ///
+  /// assert_eq!(update.set_items(), ["login = 'foo'", "active = true"]);
+  /// ```
+  pub fn set_items(&self) -> &[Sql<'static>] {
+    &self._set
+  }
+
+  /// The table of the update clause, `None` when the clause is not defined
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new().update("users").set("active = true");
+  ///
+  /// assert_eq!(update.target_table().unwrap(), "users");
+  /// ```
+  pub fn target_table(&self) -> Option<&Sql<'a>> {
+    defined(&self._update)
+  }
+
+  /// Gets the current state of the [Update] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("name = ?", "Foo")
+  ///   .where_clause_bind("login = ?", "foo")
+  ///   .to_sql(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = ? WHERE login = ?");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.build_with(dialect).map(|(query, _)| query)
+  }
+
+  /// Gets the current state of the [Update] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .where_clause("id = 1")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "update users\nset login = 'foo'\nwhere id = 1";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// update users
+  /// set login = 'foo'
+  /// where id = 1
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [Update] and returns it as string, returns an error naming the clause
+  /// that makes the query invalid, see [Update::validate] for the checks, or [BuildError::BindMismatch] when
+  /// the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "UPDATE users SET login = 'foo'";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// UPDATE users SET login = 'foo'
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(|(query, _)| query)
+  }
+
   /// The update clause. This method overrides the previous value
   ///
   /// # Examples
@@ -188,11 +887,97 @@ impl<'a> Update<'a> {
   ///   .update("address")
   ///   .update("orders");
   /// ```
-  pub fn update(mut self, table_name: &'a str) -> Self {
-    self._update = table_name.trim();
+  pub fn update(mut self, table_name: impl Into<Sql<'a>>) -> Self {
+    self._update = table_name.into().trim();
     self
   }
 
+  /// Checks the current state of the [Update], returns an error naming the clause
+  /// that makes the query invalid, like a set clause without the update clause or an update clause without the set clause.
+  /// A missing clause is not reported when a raw SQL is added before or after it or has its keyword, like `FROM`,
+  /// outside quotes, comments and parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let result = sql::Update::new()
+  ///   .set("login = 'foo'")
+  ///   .validate();
+  ///
+  /// assert_eq!(
+  ///   result,
+  ///   Err(sql::BuildError::MissingClause {
+  ///     clause: sql::Clause::Update(sql::UpdateClause::Update),
+  ///     required_by: sql::Clause::Update(sql::UpdateClause::Set),
+  ///   })
+  /// );
+  /// ```
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(drop)
+  }
+
+  /// Calls the function with the Update only when the condition is true, otherwise the Update is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let only_active = true;
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .when(only_active, |query| query.where_clause("id = 1"))
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false WHERE id = 1";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false WHERE id = 1
+  /// ```
+  pub fn when(self, condition: bool, f: impl FnOnce(Self) -> Self) -> Self {
+    if condition {
+      f(self)
+    } else {
+      self
+    }
+  }
+
+  /// Calls the function with the Update and the value of the option only when the option is `Some`,
+  /// otherwise the Update is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let login = Some("foo");
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .when_some(login, |query, login| query.where_clause(format!("login = '{login}'")))
+  ///   .as_string();
+  ///
+  /// # let expected = "UPDATE users SET active = false WHERE login = 'foo'";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// UPDATE users SET active = false WHERE login = 'foo'
+  /// ```
+  pub fn when_some<T>(self, value: Option<T>, f: impl FnOnce(Self, T) -> Self) -> Self {
+    match value {
+      Some(value) => f(self, value),
+      None => self,
+    }
+  }
+
   /// The where clause
   ///
   /// # Examples
@@ -204,12 +989,44 @@ impl<'a> Update<'a> {
   ///   .set("name = $1")
   ///   .where_clause("login = $2");
   /// ```
-  pub fn where_clause(mut self, condition: &'a str) -> Self {
-    push_unique(&mut self._where, condition.trim().to_owned());
+  pub fn where_clause(mut self, condition: impl Into<Condition>) -> Self {
+    self
+      ._duplicates
+      .push(Some(UpdateClause::Where), &mut self._where, condition.into());
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The where clause with bound values, each `?` of the condition is replaced by a placeholder
+  /// numbered at render time, use [build](Update::build) to get the values in the placeholders order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_clause_bind("login = ?", "foo");
+  /// ```
+  ///
+  /// The condition with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn where_clause_bind<'s>(mut self, condition: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self._duplicates.push(
+      Some(UpdateClause::Where),
+      &mut self._where,
+      Condition::Raw(bind(condition, params)),
+    );
+    self
+  }
+
+  /// The conditions of the where clause in the order they were added, the conditions joined
+  /// by the `or` method are a single [Condition::Or]
+  pub fn where_conditions(&self) -> &[Condition] {
+    &self._where
+  }
+
+  /// The with clause
   ///
   /// # Examples
   /// ```
@@ -239,14 +1056,23 @@ impl<'a> Update<'a> {
   /// SET count = count + 1
   /// WHERE id = (select group_id from user)
   /// ```
-  #[cfg(feature = "postgresql")]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  pub fn with(mut self, name: impl Into<Cow<'a, str>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((trim(name), std::sync::Arc::new(query)));
     self
   }
+
+  /// The names of the queries of the with clause in the order they were added
+  pub fn with_ctes(&self) -> impl Iterator<Item = &str> {
+    self._with.iter().map(|(name, _)| name.as_ref())
+  }
 }
 
-impl WithQuery for Update<'_> {}
+impl WithQuery for Update<'_> {
+  #[cfg(feature = "serde")]
+  fn tagged(&self) -> crate::serialize::TaggedQuery<'_> {
+    crate::serialize::TaggedQuery::Update(self)
+  }
+}
 
 impl std::fmt::Display for Update<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -257,6 +1083,6 @@ impl std::fmt::Display for Update<'_> {
 impl std::fmt::Debug for Update<'_> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
   }
 }
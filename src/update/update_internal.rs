@@ -1,94 +1,236 @@
 use crate::{
-  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  behavior::{
+    append, append_list, check_clauses, check_required, concat_raw_before_after, raw_defines, trim_end_from, Concat,
+    ConcatMethods,
+  },
+  condition::Condition,
+  dialect::Dialect,
+  error::{BuildError, ParseError},
   fmt,
+  join::Join,
+  parse,
+  sql::Sql,
   structure::{Update, UpdateClause},
 };
 
 impl<'a> ConcatMethods<'a, UpdateClause> for Update<'_> {}
 
 impl Concat for Update<'_> {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_owned();
-
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_with(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::With,
-        &self._with,
-      );
-    }
-    query = self.concat_update(query, &fmts);
-    query = self.concat_set(query, &fmts);
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_from(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
-        UpdateClause::From,
-        &self._from,
-      );
-    }
-    query = self.concat_where(
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
+
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_with(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::With,
+      &self._with,
+    );
+    self.concat_update(query, fmts);
+    self.concat_join(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
+      UpdateClause::Join,
+      &self._join,
+    );
+    self.concat_set(query, fmts);
+    self.concat_from(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::From,
+      &self._from,
+    );
+    self.concat_where(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
       UpdateClause::Where,
       &self._where,
     );
 
-    #[cfg(feature = "postgresql")]
-    {
-      query = self.concat_returning(
-        &self._raw_before,
-        &self._raw_after,
-        query,
-        &fmts,
+    self.concat_returning(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::Returning,
+      &self._returning,
+    );
+
+    self.concat_order_by(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::OrderBy,
+      &self._order_by,
+    );
+    self.concat_limit(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::Limit,
+      &self._limit,
+    );
+
+    trim_end_from(query, start);
+  }
+
+  fn check_dialect(&self, dialect: Dialect) -> Result<(), BuildError> {
+    let clauses: Vec<(bool, UpdateClause, &'static [Dialect])> = vec![
+      (
+        self._from.is_empty() == false,
+        UpdateClause::From,
+        &[Dialect::Postgres, Dialect::Sqlite],
+      ),
+      (
+        self._returning.is_empty() == false,
         UpdateClause::Returning,
-        &self._returning,
-      );
+        &[Dialect::Postgres, Dialect::Sqlite],
+      ),
+      (
+        self._limit.is_empty() == false,
+        UpdateClause::Limit,
+        &[Dialect::Sqlite, Dialect::Mysql],
+      ),
+      (self._join.is_empty() == false, UpdateClause::Join, &[Dialect::Mysql]),
+      (
+        self._order_by.is_empty() == false,
+        UpdateClause::OrderBy,
+        &[Dialect::Mysql],
+      ),
+    ];
+    check_clauses(dialect, clauses)?;
+    for (_, query) in &self._with {
+      query.check_dialect(dialect)?;
     }
 
-    query.trim_end().to_owned()
+    Ok(())
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()?;
+    let has_raw = |clause, keywords: &[&'static str]| {
+      raw_defines(&self._raw, &self._raw_before, &self._raw_after, clause, keywords)
+    };
+    let clauses: Vec<(bool, UpdateClause)> = vec![
+      (self._with.is_empty() == false, UpdateClause::With),
+      (self._join.is_empty() == false, UpdateClause::Join),
+      (self._set.is_empty() == false, UpdateClause::Set),
+      (self._from.is_empty() == false, UpdateClause::From),
+      (self._where.is_empty() == false, UpdateClause::Where),
+      (self._returning.is_empty() == false, UpdateClause::Returning),
+      (self._order_by.is_empty() == false, UpdateClause::OrderBy),
+      (self._limit.is_empty() == false, UpdateClause::Limit),
+    ];
+    let has_update = self._update.is_empty() == false || has_raw(UpdateClause::Update, &["UPDATE"]);
+    let has_set = self._set.is_empty() == false || has_raw(UpdateClause::Set, &["SET"]);
+    check_required((has_update, UpdateClause::Update), clauses)?;
+    check_required(
+      (has_set, UpdateClause::Set),
+      [(self._update.is_empty() == false, UpdateClause::Update)],
+    )?;
+    crate::behavior::check_number(UpdateClause::Limit, &self._limit)?;
+    for (_, query) in &self._with {
+      query.check_query()?;
+    }
+
+    Ok(())
   }
 }
 
 impl Update<'_> {
-  fn concat_set(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._set.is_empty() == false {
-      let values = self._set.join(comma);
-      format!("SET{space}{values}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(UpdateClause::From), &self._from)?;
+    duplicates.check(Some(UpdateClause::Join), &self._join)?;
+    duplicates.check(Some(UpdateClause::OrderBy), &self._order_by)?;
+    duplicates.check(Some(UpdateClause::Returning), &self._returning)?;
+    duplicates.check(Some(UpdateClause::Set), &self._set)?;
+    duplicates.check(Some(UpdateClause::Where), &self._where)?;
+    duplicates.check(None, &self._raw)
+  }
 
-    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, UpdateClause::Set, sql)
+  fn concat_set(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::Set,
+      |query| {
+        if self._set.is_empty() == false {
+          append(query, &["SET", space]);
+          append_list(query, &self._set, comma);
+          append(query, &[space, lb]);
+        }
+      },
+    );
   }
 
-  fn concat_update(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_update(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
     let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._update.is_empty() == false {
-      let table_name = self._update;
-      format!("UPDATE{space}{table_name}{space}{lb}")
-    } else {
-      "".to_owned()
-    };
-
     concat_raw_before_after(
       &self._raw_before,
       &self._raw_after,
       query,
       fmts,
       UpdateClause::Update,
-      sql,
-    )
+      |query| {
+        if self._update.is_empty() == false {
+          append(query, &["UPDATE", space]);
+          query.append(&self._update);
+          append(query, &[space, lb]);
+        }
+      },
+    );
+  }
+}
+
+const CLAUSES: &[&[&str]] = &[
+  &["WITH"],
+  &["UPDATE"],
+  parse::JOINS,
+  &["SET"],
+  &["FROM"],
+  &["WHERE"],
+  &["RETURNING"],
+  &["ORDER BY"],
+  &["LIMIT"],
+];
+
+impl<'a> Update<'a> {
+  pub(crate) fn parse_statement(sql: &'a str) -> Result<Self, ParseError> {
+    let (leading, clauses) = parse::split_sequence(sql, CLAUSES)?;
+    let mut update = Self {
+      _raw: parse::raw(leading, "UPDATE")?,
+      ..Default::default()
+    };
+
+    for clause in clauses {
+      let body = clause.body;
+      match clause.keyword {
+        "WITH" => update._with = parse::with_items(sql, &clause)?,
+        "UPDATE" => update._update = body.into(),
+        "SET" => update._set = parse::list(body)?,
+        "FROM" => update._from = parse::list(body)?,
+        "WHERE" => update._where.push(Condition::raw(body)),
+        "RETURNING" => update._returning = parse::list(body)?,
+        "ORDER BY" => update._order_by = parse::list(body)?,
+        "LIMIT" => update._limit = body.into(),
+        _ => update._join.push(Join::parsed(&clause)),
+      }
+    }
+
+    Ok(update)
   }
 }
@@ -1,10 +1,45 @@
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
+  behavior::{Concat, WithQuery},
+  bind::{bind, Params, PlaceholderMap, Value},
+  dialect::Dialect,
+  duplicate::DuplicatePolicy,
+  error::BuildError,
+  fmt::{self, FormatOptions},
+  sink::{self, IoSink, Sink},
+  sql::Sql,
   structure::{Values, ValuesClause},
 };
 
 impl Values {
+  /// Calls the function with the Values and returns its result, useful to reuse a set of method calls
+  /// without breaking the method chaining
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// fn second_row(query: sql::Values) -> sql::Values {
+  ///   query.values("(2, 'two')")
+  /// }
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .apply(second_row)
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (1, 'one'), (2, 'two')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'one'), (2, 'two')
+  /// ```
+  pub fn apply(self, f: impl FnOnce(Self) -> Self) -> Self {
+    f(self)
+  }
+
   /// Gets the current state of the Values and returns it as string
   ///
   /// # Examples
@@ -22,11 +57,97 @@ impl Values {
   /// ```
   pub fn as_string(&self) -> String {
     let fmts = fmt::one_line();
-    self.concat(&fmts)
+    self.render(&fmts).0
+  }
+
+  /// Gets the current state of the Values and returns it as string together with the bound values,
+  /// the placeholders are numbered in the order they appear in the query with the syntax enabled by the feature flags
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"))
+  ///   .build();
+  ///
+  /// assert_eq!(values.len(), 2);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// VALUES ($1, $2)
+  /// ```
+  ///
+  /// # Panics
+  /// Panics when the placeholders of a `*_bind` method don't match the values bound to it,
+  /// use [Values::build_with] to get the [BuildError::BindMismatch] error instead
+  pub fn build(&self) -> (String, Vec<Value>) {
+    let fmts = fmt::one_line();
+    self.try_render(&fmts).unwrap_or_else(|error| panic!("{error}"))
+  }
+
+  /// Gets the current state of the [Values] rendered for the given dialect together with the bound values,
+  /// returns an error when a clause defined in the query isn't supported by the dialect, see [Dialect] for the details
+  /// or [BuildError::BindMismatch] when the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, values) = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"))
+  ///   .build_with(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "VALUES (?1, ?2)");
+  /// assert_eq!(values.len(), 2);
+  /// ```
+  pub fn build_with(&self, dialect: Dialect) -> Result<(String, Vec<Value>), BuildError> {
+    self.check_dialect(dialect)?;
+    let fmts = fmt::Formatter {
+      dialect: Some(dialect),
+      ..fmt::one_line()
+    };
+    self.try_render(&fmts)
+  }
+
+  /// Removes the values of the clause and the raw SQL added before and after it with the `raw_before`
+  /// and `raw_after` methods, the values added with the `raw` method are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .clear(sql::ValuesClause::Values)
+  ///   .values("(2, 'two')")
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (2, 'two')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (2, 'two')
+  /// ```
+  pub fn clear(mut self, clause: ValuesClause) -> Self {
+    self._duplicates.clear(Some(clause.clone()));
+    match clause {
+      ValuesClause::Values => self._values.clear(),
+    }
+    self._raw_after.retain(|(raw_clause, _)| *raw_clause != clause);
+    self._raw_before.retain(|(raw_clause, _)| *raw_clause != clause);
+    self
   }
 
   /// Prints the current state of the Values into console output in a more ease to read version.
-  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type.
+  /// The output is colored only when the standard output is a terminal and the `NO_COLOR` variable is not defined,
+  /// the [Debug](std::fmt::Debug) format writes the same layout with the same colors
   ///
   /// # Examples
   /// ```
@@ -44,8 +165,53 @@ impl Values {
   /// VALUES (1, 'one'), (2, 'two'), (3, 'three')
   /// ```
   pub fn debug(self) -> Self {
+    self.debug_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Values] into the sink in the same layout of the debug method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let values = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .debug_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// assert!(output.contains("-- ------"));
+  /// ```
+  pub fn debug_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::multiline();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
+    self
+  }
+
+  /// Defines how the values added more than once to the same clause are handled, the policy applies
+  /// to the values added after this method. See [DuplicatePolicy] for the default of each clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .duplicate_policy(sql::DuplicatePolicy::Dedupe)
+  ///   .values("(1, 'one')")
+  ///   .values("(1, 'one')")
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (1, 'one')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'one')
+  /// ```
+  pub fn duplicate_policy(mut self, policy: DuplicatePolicy) -> Self {
+    self._duplicates.set_policy(policy);
     self
   }
 
@@ -54,11 +220,54 @@ impl Values {
     Self::default()
   }
 
+  /// Returns how the positional placeholders (`$1`, `$2`, ...) written in each composed builder were renumbered
+  /// in the final query, see [PlaceholderMap] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let values = sql::Values::new()
+  ///   .values("($1, $2)");
+  ///
+  /// let map = values.placeholder_map();
+  ///
+  /// assert_eq!(map.count(0), 2);
+  /// ```
+  pub fn placeholder_map(&self) -> PlaceholderMap {
+    let fmts = fmt::one_line();
+    self.placeholders(&fmts)
+  }
+
   /// Prints the current state of the Values into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
+    self.print_to(&mut IoSink::stdout())
+  }
+
+  /// Writes the current state of the [Values] into the sink in one line similar to the print method,
+  /// the output is colored only when the sink accepts colors, see [Sink]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let mut output = String::new();
+  /// let values = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .print_to(&mut sql::FmtSink::new(&mut output));
+  ///
+  /// # let expected = "VALUES (1, 'one')\n";
+  /// # assert_eq!(output, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// VALUES (1, 'one')
+  /// ```
+  pub fn print_to(self, sink: &mut impl Sink) -> Self {
     let fmts = fmt::one_line();
-    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    sink::write(sink, self.render(&fmts).0, &fmts);
     self
   }
 
@@ -81,8 +290,10 @@ impl Values {
   /// insert into my_table(num, txt)
   /// VALUES (1, 'one'), (2, 'two')
   /// ```
-  pub fn raw(mut self, raw_sql: &str) -> Self {
-    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+  pub fn raw(mut self, raw_sql: impl Into<String>) -> Self {
+    self
+      ._duplicates
+      .push(None, &mut self._raw, raw_sql.into().trim().to_owned());
     self
   }
 
@@ -104,8 +315,8 @@ impl Values {
   /// ```sql
   /// VALUES (1, 'one'), (2, 'two') , (3, 'three')
   /// ```
-  pub fn raw_after(mut self, clause: ValuesClause, raw_sql: &str) -> Self {
-    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_after(mut self, clause: ValuesClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_after.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
@@ -128,11 +339,117 @@ impl Values {
   /// /* the values command */
   /// VALUES (1, 'one'), (2, 'two')
   /// ```
-  pub fn raw_before(mut self, clause: ValuesClause, raw_sql: &str) -> Self {
-    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+  pub fn raw_before(mut self, clause: ValuesClause, raw_sql: impl Into<String>) -> Self {
+    self._raw_before.push((clause, raw_sql.into().trim().to_owned()));
     self
   }
 
+  /// Replaces the values of the values clause, the raw SQL added before and after the clause is kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .replace_values("(2, 'two')")
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (2, 'two')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (2, 'two')
+  /// ```
+  pub fn replace_values<'s>(mut self, expression: impl Into<Sql<'s>>) -> Self {
+    self._values.clear();
+    self._duplicates.clear(Some(ValuesClause::Values));
+    self.values(expression)
+  }
+
+  /// Gets the current state of the [Values] rendered for the given dialect, returns an error when a clause
+  /// defined in the query isn't supported by the dialect, see [Dialect] for the details
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"))
+  ///   .to_sql(sql::Dialect::Mysql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "VALUES (?, ?)");
+  /// ```
+  pub fn to_sql(&self, dialect: Dialect) -> Result<String, BuildError> {
+    self.build_with(dialect).map(|(query, _)| query)
+  }
+
+  /// Gets the current state of the [Values] and returns it as string with the layout of the options
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let options = sql::FormatOptions::new()
+  ///   .keyword_case(sql::KeywordCase::Lower)
+  ///   .line_breaks(true);
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .values("(2, 'two')")
+  ///   .to_string_with(&options);
+  ///
+  /// # let expected = "values\n(1, 'one'),\n(2, 'two')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// values
+  /// (1, 'one'),
+  /// (2, 'two')
+  /// ```
+  pub fn to_string_with(&self, options: &FormatOptions) -> String {
+    options.format(|fmts| self.render(fmts).0)
+  }
+
+  /// Gets the current state of the [Values] and returns it as string, see [Values::validate] for the checks,
+  /// returns [BuildError::BindMismatch] when the placeholders of a `*_bind` method don't match the values bound to it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .try_as_string()
+  ///   .unwrap();
+  ///
+  /// # let expected = "VALUES (1, 'one')";
+  /// # assert_eq!(query, expected);
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// VALUES (1, 'one')
+  /// ```
+  pub fn try_as_string(&self) -> Result<String, BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(|(query, _)| query)
+  }
+
+  /// Checks the current state of the [Values], the clauses of the [Values] don't depend on each other,
+  /// so the errors are the repeated rows of the [DuplicatePolicy::Error] policy and the `values_bind` method
+  /// with a number of values different from its placeholders
+  pub fn validate(&self) -> Result<(), BuildError> {
+    self.check_query()?;
+    self.try_render(&fmt::one_line()).map(drop)
+  }
+
   /// The values clause
   ///
   /// # Examples
@@ -143,13 +460,105 @@ impl Values {
   ///   .values("(1, 'one'), (2, 'two')")
   ///   .values("(3, 'three')");
   /// ```
-  pub fn values(mut self, expression: &str) -> Self {
-    push_unique(&mut self._values, expression.trim().to_owned());
+  pub fn values<'s>(mut self, expression: impl Into<Sql<'s>>) -> Self {
+    self._duplicates.push_row(
+      ValuesClause::Values,
+      &mut self._values,
+      expression.into().trim().into_owned(),
+    );
     self
   }
+
+  /// The values clause with bound values, each `?` of the expression is replaced by a placeholder
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let values = sql::Values::new()
+  ///   .values_bind("(?, ?)", (1, "one"))
+  ///   .values_bind("(?, ?)", (2, "two"));
+  /// ```
+  ///
+  /// The expression with a number of `?` different from the number of values is reported by the `build_with`
+  /// and `try_as_string` methods with the [BuildError::BindMismatch] error
+  pub fn values_bind<'s>(mut self, expression: impl Into<Sql<'s>>, params: impl Params) -> Self {
+    self
+      ._duplicates
+      .push_row(ValuesClause::Values, &mut self._values, bind(expression, params));
+    self
+  }
+
+  /// The rows of the values clause in the order they were added
+  pub fn values_items(&self) -> &[Sql<'static>] {
+    &self._values
+  }
+
+  /// Calls the function with the Values only when the condition is true, otherwise the Values is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let condition = true;
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .when(condition, |query| query.values("(2, 'two')"))
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (1, 'one'), (2, 'two')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'one'), (2, 'two')
+  /// ```
+  pub fn when(self, condition: bool, f: impl FnOnce(Self) -> Self) -> Self {
+    if condition {
+      f(self)
+    } else {
+      self
+    }
+  }
+
+  /// Calls the function with the Values and the value of the option only when the option is `Some`,
+  /// otherwise the Values is returned unchanged
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let name = Some("foo");
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .when_some(name, |query, name| query.values(format!("(2, '{name}')")))
+  ///   .as_string();
+  ///
+  /// # let expected = "VALUES (1, 'one'), (2, 'foo')";
+  /// # assert_eq!(expected, query);
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// VALUES (1, 'one'), (2, 'foo')
+  /// ```
+  pub fn when_some<T>(self, value: Option<T>, f: impl FnOnce(Self, T) -> Self) -> Self {
+    match value {
+      Some(value) => f(self, value),
+      None => self,
+    }
+  }
 }
 
-impl WithQuery for Values {}
+impl WithQuery for Values {
+  #[cfg(feature = "serde")]
+  fn tagged(&self) -> crate::serialize::TaggedQuery<'_> {
+    crate::serialize::TaggedQuery::Values(self)
+  }
+}
 
 impl std::fmt::Display for Values {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -160,6 +569,6 @@ impl std::fmt::Display for Values {
 impl std::fmt::Debug for Values {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     let fmts = fmt::multiline();
-    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+    write!(f, "{}", sink::debug_format(self.render(&fmts).0, &fmts))
   }
 }
@@ -1,25 +1,39 @@
 use crate::{
-  behavior::{Concat, ConcatMethods},
+  behavior::{trim_end_from, Concat, ConcatMethods},
+  error::BuildError,
   fmt,
+  sql::Sql,
   structure::{Values, ValuesClause},
 };
 
 impl<'a> ConcatMethods<'a, ValuesClause> for Values {}
 
 impl Concat for Values {
-  fn concat(&self, fmts: &fmt::Formatter) -> String {
-    let mut query = "".to_owned();
+  fn concat_into(&self, query: &mut Sql<'static>, fmts: &fmt::Formatter) {
+    let start = query.len();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    query = self.concat_values(
+    self.concat_raw(query, fmts, &self._raw);
+    self.concat_values(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       ValuesClause::Values,
       &self._values,
     );
 
-    query.trim_end().to_owned()
+    trim_end_from(query, start);
+  }
+
+  fn check_query(&self) -> Result<(), BuildError> {
+    self.check_duplicates()
+  }
+}
+
+impl Values {
+  fn check_duplicates(&self) -> Result<(), BuildError> {
+    let duplicates = &self._duplicates;
+    duplicates.check(Some(ValuesClause::Values), &self._values)?;
+    duplicates.check(None, &self._raw)
   }
 }
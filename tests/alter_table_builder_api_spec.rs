@@ -0,0 +1,300 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn all_clauses_concatenated_in_order() {
+  let query = sql::AlterTable::new()
+    .raw("/* all clauses in order */")
+    .rename("column login to username")
+    .drop_constraint("users_email_key")
+    .add_constraint("users_login_key unique (login)")
+    .alter_column("name set not null")
+    .drop_column("birthday")
+    .add_column("age int")
+    .alter_table("users")
+    .as_string();
+
+  let expected_query = "\
+    /* all clauses in order */ \
+    ALTER TABLE users \
+    ADD COLUMN age int, \
+    DROP COLUMN birthday, \
+    ALTER COLUMN name set not null, \
+    ADD CONSTRAINT users_login_key unique (login), \
+    DROP CONSTRAINT users_email_key, \
+    RENAME column login to username\
+  ";
+
+  assert_eq!(query, expected_query);
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::AlterTable::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age int")
+      .debug()
+      .as_string();
+    let expected_query = "ALTER TABLE users ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .drop_column("age")
+      .print()
+      .as_string();
+    let expected_query = "ALTER TABLE users DROP COLUMN age";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::AlterTable::new()
+      .raw("alter table users")
+      .add_column("age int")
+      .as_string();
+    let expected_query = "alter table users ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::AlterTable::new()
+      .raw("/* raw one */")
+      .raw("/* raw one */")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new().raw("  /* raw one */  ").as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod alter_table_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_alter_table_should_add_the_alter_table_clause() {
+    let query = sql::AlterTable::new().alter_table("users").as_string();
+    let expected_query = "ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_table_should_override_the_current_value() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .alter_table("orders")
+      .as_string();
+    let expected_query = "ALTER TABLE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_table_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new().alter_table("  users  ").as_string();
+    let expected_query = "ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_alter_table_clause() {
+    let query = sql::AlterTable::new()
+      .raw_before(sql::AlterTableClause::AlterTable, "/* users table */")
+      .alter_table("users")
+      .as_string();
+    let expected_query = "/* users table */ ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_alter_table_clause() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .raw_after(sql::AlterTableClause::AlterTable, "owner to admin")
+      .as_string();
+    let expected_query = "ALTER TABLE users owner to admin";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod column_actions {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_add_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .add_column("active boolean")
+      .as_string();
+    let expected_query = "ADD COLUMN age int, ADD COLUMN active boolean";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_add_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .add_column("age int")
+      .as_string();
+    let expected_query = "ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .drop_column("age")
+      .drop_column("  active  ")
+      .as_string();
+    let expected_query = "DROP COLUMN age, DROP COLUMN active";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .alter_column("login set not null")
+      .alter_column("age type bigint")
+      .as_string();
+    let expected_query = "ALTER COLUMN login set not null, ALTER COLUMN age type bigint";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_a_raw_action_before_the_added_columns() {
+    let query = sql::AlterTable::new()
+      .raw_before(sql::AlterTableClause::AddColumn, "add column if not exists age int")
+      .add_column("active boolean")
+      .as_string();
+    let expected_query = "add column if not exists age int, ADD COLUMN active boolean";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_a_raw_action_after_the_altered_columns() {
+    let query = sql::AlterTable::new()
+      .alter_column("login set not null")
+      .raw_after(sql::AlterTableClause::AlterColumn, "alter column age drop default")
+      .as_string();
+    let expected_query = "ALTER COLUMN login set not null, alter column age drop default";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod constraint_actions {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_add_constraint_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .add_constraint("users_login_key unique (login)")
+      .add_constraint("users_age_check check (age > 0)")
+      .as_string();
+    let expected_query = "\
+      ADD CONSTRAINT users_login_key unique (login), \
+      ADD CONSTRAINT users_age_check check (age > 0)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_constraint_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .drop_constraint("users_login_key")
+      .drop_constraint("users_age_check")
+      .as_string();
+    let expected_query = "DROP CONSTRAINT users_login_key, DROP CONSTRAINT users_age_check";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_drop_constraint_should_be_after_add_constraint_clause() {
+    let query = sql::AlterTable::new()
+      .drop_constraint("users_email_key")
+      .add_constraint("users_login_key unique (login)")
+      .as_string();
+    let expected_query = "ADD CONSTRAINT users_login_key unique (login), DROP CONSTRAINT users_email_key";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod rename_action {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_rename_should_add_the_rename_action() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename("to customers")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME to customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_rename_should_override_the_current_value() {
+    let query = sql::AlterTable::new()
+      .rename("to customers")
+      .rename("  column login to username  ")
+      .as_string();
+    let expected_query = "RENAME column login to username";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_a_raw_action_after_the_rename() {
+    let query = sql::AlterTable::new()
+      .rename("to customers")
+      .raw_after(sql::AlterTableClause::Rename, "set schema sales")
+      .as_string();
+    let expected_query = "RENAME to customers, set schema sales";
+
+    assert_eq!(query, expected_query);
+  }
+}
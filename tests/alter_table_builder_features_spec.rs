@@ -0,0 +1,61 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn alter_table_builder_should_be_displayable() {
+  let alter_table = sql::AlterTable::new().alter_table("users").add_column("age int");
+
+  println!("{}", alter_table);
+
+  let query = alter_table.as_string();
+  let expected_query = "ALTER TABLE users ADD COLUMN age int";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn alter_table_builder_should_be_debuggable() {
+  let alter_table = sql::AlterTable::new()
+    .alter_table("users")
+    .add_column("age int")
+    .drop_column("birthday");
+
+  println!("{:?}", alter_table);
+
+  let query = alter_table.as_string();
+  let expected_query = "ALTER TABLE users ADD COLUMN age int, DROP COLUMN birthday";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn alter_table_builder_should_be_cloneable() {
+  let alter_users = sql::AlterTable::new()
+    .raw("/* test raw */")
+    .alter_table("users")
+    .add_column("age int");
+  let alter_users_with_drop = alter_users.clone().drop_column("birthday");
+
+  let query = alter_users.as_string();
+  let query_with_drop = alter_users_with_drop.as_string();
+
+  let expected_query = "/* test raw */ ALTER TABLE users ADD COLUMN age int";
+  let expected_query_with_drop = "/* test raw */ ALTER TABLE users ADD COLUMN age int, DROP COLUMN birthday";
+
+  assert_eq!(query, expected_query);
+  assert_eq!(query_with_drop, expected_query_with_drop);
+}
+
+#[test]
+fn alter_table_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut alter_table = sql::AlterTable::new().alter_table("users").add_column("age int");
+
+  if true {
+    alter_table = alter_table.add_constraint("users_age_check check (age > 0)");
+  }
+
+  let query = alter_table.as_string();
+  let expected_query = "ALTER TABLE users ADD COLUMN age int, ADD CONSTRAINT users_age_check check (age > 0)";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,251 @@
+mod bind_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_where_clause_bind_should_replace_the_question_mark_by_a_numbered_placeholder() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .build();
+    let expected_query = "SELECT * FROM users WHERE login = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_where_clause_bind_should_accept_multiple_values() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("created_at between ? and ?", [10, 20])
+      .and_bind("login = ? and active = ?", ("foo", true))
+      .build();
+    let expected_query = "WHERE created_at between $1 and $2 AND login = $3 and active = $4";
+    let expected_values = vec![
+      sql::Value::Int(10),
+      sql::Value::Int(20),
+      sql::Value::Text("foo".to_owned()),
+      sql::Value::Bool(true),
+    ];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, expected_values);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_where_clause_bind_should_ignore_question_marks_inside_quotes() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("title = 'why?' and \"who?\" = ?", 1)
+      .build();
+    let expected_query = "WHERE title = 'why?' and \"who?\" = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Int(1)]);
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_when_the_values_does_not_match_the_placeholders() {
+    let select = sql::Select::new()
+      .select("*")
+      .where_clause_bind("login = ? and name = ?", "foo");
+    let expected_error = sql::BuildError::BindMismatch {
+      sql: "login = ? and name = ?".to_owned(),
+      placeholders: 2,
+      values: 1,
+    };
+
+    assert_eq!(select.try_as_string(), Err(expected_error.clone()));
+    assert_eq!(select.build_with(sql::Dialect::Postgres), Err(expected_error.clone()));
+    assert_eq!(select.to_sql(sql::Dialect::Mysql), Err(expected_error));
+  }
+
+  #[test]
+  #[should_panic(expected = "the sql `login = ? and name = ?` has 2 placeholder(s) but 1 value(s) were bound")]
+  fn method_build_should_panic_when_the_values_does_not_match_the_placeholders() {
+    sql::Select::new()
+      .select("*")
+      .where_clause_bind("login = ? and name = ?", "foo")
+      .build();
+  }
+
+  #[test]
+  fn double_question_mark_should_be_written_as_a_single_question_mark() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("data ?? 'key' and id = ?", 1)
+      .build_with(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "WHERE data ? 'key' and id = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Int(1)]);
+  }
+
+  #[test]
+  fn control_characters_written_in_the_query_should_be_kept_as_written() {
+    let query = sql::Select::new()
+      .where_clause("note = '\u{1}\u{2}'")
+      .and_bind("id = ?", 1)
+      .to_sql(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "WHERE note = '\u{1}\u{2}' AND id = $1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_build_should_number_the_placeholders_in_the_render_order() {
+    let (query, values) = sql::Update::new()
+      .where_clause_bind("login = ?", "foo")
+      .set_bind("name = ?", "Foo")
+      .update("users")
+      .build();
+    let expected_query = "UPDATE users SET name = $1 WHERE login = $2";
+    let expected_values = vec![sql::Value::Text("Foo".to_owned()), sql::Value::Text("foo".to_owned())];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, expected_values);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_as_string_should_render_the_numbered_placeholders() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .where_clause_bind("id = ?", 1)
+      .and_bind("active = ?", false)
+      .as_string();
+    let expected_query = "DELETE FROM users WHERE id = $1 AND active = $2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_having_bind_should_add_the_values_to_the_having_clause() {
+    let (query, values) = sql::Select::new()
+      .select("status")
+      .group_by("status")
+      .having_bind("count(*) > ?", 10)
+      .where_clause_bind("active = ?", true)
+      .build();
+    let expected_query = "SELECT status WHERE active = $1 GROUP BY status HAVING count(*) > $2";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Bool(true), sql::Value::Int(10)]);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_values_bind_should_keep_the_rows_in_order() {
+    let (query, values) = sql::Values::new()
+      .values_bind("(?, ?)", (1, "one"))
+      .values_bind("(?, ?)", (2, "two"))
+      .build();
+    let expected_query = "VALUES ($1, $2), ($3, $4)";
+    let expected_values = vec![
+      sql::Value::Int(1),
+      sql::Value::Text("one".to_owned()),
+      sql::Value::Int(2),
+      sql::Value::Text("two".to_owned()),
+    ];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, expected_values);
+  }
+
+  #[test]
+  fn method_build_should_preserve_the_bound_value_types() {
+    let (_, values) = sql::Insert::new()
+      .values_bind(
+        "(?, ?, ?, ?)",
+        (None::<i32>, 1.5, vec![0xde_u8, 0xad], "multi\u{1}byte ✓"),
+      )
+      .build();
+    let expected_values = vec![
+      sql::Value::Null,
+      sql::Value::Float(1.5),
+      sql::Value::Bytes(vec![0xde, 0xad]),
+      sql::Value::Text("multi\u{1}byte ✓".to_owned()),
+    ];
+
+    assert_eq!(values, expected_values);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_build_should_collect_the_values_of_the_insert_select_subquery() {
+    let (query, values) = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .select(
+        sql::Select::new()
+          .select("login, name")
+          .from("users_bk")
+          .where_clause_bind("login = ?", "foo"),
+      )
+      .on_conflict("do nothing")
+      .build();
+    let expected_query = "\
+      INSERT INTO users (login, name) \
+      SELECT login, name FROM users_bk WHERE login = $1 \
+      ON CONFLICT do nothing\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod bind_methods_with_composition {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_build_should_collect_the_values_of_the_with_clause_before_the_main_query() {
+    let logins = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause_bind("active = ?", true);
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("price > ?", 100)
+      .with("logins", logins)
+      .select("*")
+      .from("orders")
+      .build();
+    let expected_query = "\
+      WITH logins AS (SELECT login FROM users WHERE active = $1) \
+      SELECT * FROM orders WHERE price > $2\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Bool(true), sql::Value::Int(100)]);
+  }
+
+  #[test]
+  fn method_build_should_collect_the_values_of_the_union_after_the_main_query() {
+    let (query, values) = sql::Select::new()
+      .union(
+        sql::Select::new()
+          .select("login")
+          .from("admins")
+          .where_clause_bind("id = ?", 2),
+      )
+      .select("login")
+      .from("users")
+      .where_clause_bind("id = ?", 1)
+      .build();
+    let expected_query = "\
+      (SELECT login FROM users WHERE id = $1) \
+      UNION \
+      (SELECT login FROM admins WHERE id = $2)\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Int(1), sql::Value::Int(2)]);
+  }
+}
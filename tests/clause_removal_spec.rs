@@ -0,0 +1,346 @@
+mod clear_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn users_page() -> sql::Select<'static> {
+    sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .where_clause("active = true")
+      .order_by("login")
+      .limit("10")
+      .offset("20")
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_values_of_the_clause() {
+    let query = users_page()
+      .clear(sql::SelectClause::OrderBy)
+      .clear(sql::SelectClause::Limit)
+      .clear(sql::SelectClause::Offset)
+      .replace_select("count(*)")
+      .as_string();
+    let expected_query = "SELECT count(*) FROM users WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_keep_the_base_builder_unchanged() {
+    let base = users_page();
+    let expected_query = base.as_string();
+    let count = base.clone().clear(sql::SelectClause::OrderBy).as_string();
+
+    assert_eq!(count.contains("ORDER BY"), false);
+    assert_eq!(base.as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_raw_sql_added_before_and_after_the_clause() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .raw_before(sql::SelectClause::Where, "/* filter */")
+      .where_clause("active = true")
+      .raw_after(sql::SelectClause::Where, "and deleted = false")
+      .raw_after(sql::SelectClause::From, "u")
+      .clear(sql::SelectClause::Where)
+      .as_string();
+    let expected_query = "SELECT * FROM users u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_raw_sql_of_a_clause_without_values() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .raw_after(sql::SelectClause::Limit, "limit 10")
+      .clear(sql::SelectClause::Limit)
+      .as_string();
+    let expected_query = "SELECT * FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_keep_the_values_of_the_raw_method() {
+    let query = sql::Delete::new()
+      .raw("/* cleanup */")
+      .delete_from("users")
+      .where_clause("active = false")
+      .clear(sql::DeleteClause::Where)
+      .as_string();
+    let expected_query = "/* cleanup */ DELETE FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_join_clauses() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users u")
+      .inner_join("orders o ON o.user_id = u.id")
+      .left_join("addresses a ON a.user_id = u.id")
+      .clear(sql::SelectClause::Join)
+      .as_string();
+    let expected_query = "SELECT * FROM users u";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_accept_new_values_for_the_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .select("login")
+      .clear(sql::SelectClause::Select)
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_select_of_the_insert_builder() {
+    let query = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(sql::Select::new().select("login").from("users"))
+      .clear(sql::InsertClause::Select)
+      .values("('foo')")
+      .as_string();
+    let expected_query = "INSERT INTO users_bk (login) VALUES ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_reset_the_modifiers_rendered_by_the_clause() {
+    let query = sql::DropTable::new()
+      .drop_table_if_exists("users")
+      .cascade()
+      .clear(sql::DropTableClause::DropTable)
+      .drop_table("orders")
+      .as_string();
+    let expected_query = "DROP TABLE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_be_available_on_the_ddl_builders() {
+    let create_table = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .constraint("users_id_key UNIQUE(id)")
+      .clear(sql::CreateTableClause::Constraint);
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .rename("TO users_bk")
+      .clear(sql::AlterTableClause::Rename)
+      .add_column("age int");
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .column("email")
+      .clear(sql::CreateIndexClause::Column)
+      .column("email");
+    let drop_index = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .clear(sql::DropIndexClause::DropIndex);
+
+    assert_eq!(create_table.as_string(), "CREATE TABLE users (id serial)");
+    assert_eq!(alter_table.as_string(), "ALTER TABLE users ADD COLUMN age int");
+    assert_eq!(
+      create_index.as_string(),
+      "CREATE INDEX users_login_idx ON users (email)"
+    );
+    assert_eq!(drop_index.as_string(), "");
+  }
+
+  #[test]
+  fn repeated_values_should_be_added_again_after_the_clause_was_cleared() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .clear(sql::UpdateClause::Set)
+      .set("active = false")
+      .set("active = false")
+      .as_string();
+    let expected_query = "UPDATE users SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod remove_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_remove_where_should_remove_the_conditions_matched_by_the_predicate() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("active = true")
+      .where_clause("login = 'foo'")
+      .where_clause("created_at > '2024-01-01'")
+      .remove_where(|condition| matches!(condition, sql::Condition::Raw(text) if text.to_string().starts_with("login")))
+      .as_string();
+    let expected_query = "SELECT * FROM users WHERE active = true AND created_at > '2024-01-01'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_remove_where_should_compare_the_grouped_conditions() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .where_clause(sql::Condition::or(["login = 'foo'", "login = 'bar'"]))
+      .where_clause("active = false")
+      .remove_where(|condition| *condition == sql::Condition::or(["login = 'foo'", "login = 'bar'"]))
+      .as_string();
+    let expected_query = "DELETE FROM users WHERE active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_remove_where_should_remove_the_where_clause_when_all_conditions_are_removed() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .where_clause("login = 'foo'")
+      .remove_where(|_| true)
+      .as_string();
+    let expected_query = "UPDATE users SET active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_remove_where_should_allow_the_removed_condition_to_be_added_again() {
+    let query = sql::Select::new()
+      .where_clause("a = 1")
+      .where_clause("b = 2")
+      .remove_where(|condition| *condition == "a = 1".into())
+      .where_clause("b = 2")
+      .where_clause("a = 1")
+      .as_string();
+    let expected_query = "WHERE b = 2 AND a = 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_remove_having_should_remove_the_conditions_matched_by_the_predicate() {
+    let query = sql::Select::new()
+      .select("login, count(*)")
+      .from("orders")
+      .group_by("login")
+      .having("count(*) > 10")
+      .having("login <> 'admin'")
+      .remove_having(|condition| *condition == "count(*) > 10".into())
+      .as_string();
+    let expected_query = "SELECT login, count(*) FROM orders GROUP BY login HAVING login <> 'admin'";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod replace_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_replace_where_should_swap_all_conditions() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("login = 'foo'")
+      .or("login = 'bar'")
+      .replace_where("login = 'baz'")
+      .as_string();
+    let expected_query = "SELECT * FROM users WHERE login = 'baz'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn replace_methods_should_keep_the_raw_sql_added_before_and_after_the_clause() {
+    let query = sql::Select::new()
+      .select("id")
+      .raw_after(sql::SelectClause::Select, ", login")
+      .from("users")
+      .replace_select("email")
+      .as_string();
+    let expected_query = "SELECT email , login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_replace_from_and_replace_order_by_should_swap_the_values() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .order_by("login")
+      .replace_from("users_bk")
+      .replace_order_by("created_at desc")
+      .as_string();
+    let expected_query = "SELECT login FROM users_bk ORDER BY created_at desc";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_replace_set_should_swap_the_assignments() {
+    let query = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .set("login = 'foo'")
+      .replace_set("active = true")
+      .replace_where("id = 1")
+      .as_string();
+    let expected_query = "UPDATE users SET active = true WHERE id = 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_replace_values_should_swap_the_rows() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .replace_values("('baz')");
+    let values = sql::Values::new().values("(1, 'one')").replace_values("(2, 'two')");
+
+    assert_eq!(insert.as_string(), "INSERT INTO users (login) VALUES ('baz')");
+    assert_eq!(values.as_string(), "VALUES (2, 'two')");
+  }
+
+  #[test]
+  fn method_replace_where_should_be_available_on_the_delete_and_create_index_builders() {
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id = 1")
+      .replace_where("id = 2");
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .where_clause("active = true")
+      .replace_where("deleted = false");
+
+    assert_eq!(delete.as_string(), "DELETE FROM users WHERE id = 2");
+    assert_eq!(
+      create_index.as_string(),
+      "CREATE INDEX users_login_idx ON users (login) WHERE deleted = false"
+    );
+  }
+}
@@ -0,0 +1,193 @@
+mod condition_tree {
+  use pretty_assertions::assert_eq;
+  use sql::Condition;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn condition_or_should_be_parenthesized_when_joined_with_other_conditions() {
+    let query = sql::Select::new()
+      .where_clause(Condition::or(["a = 1", "b = 2"]))
+      .and("c = 3")
+      .as_string();
+    let expected_query = "WHERE (a = 1 OR b = 2) AND c = 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn condition_or_should_not_be_parenthesized_when_it_is_the_only_condition() {
+    let query = sql::Select::new()
+      .where_clause(Condition::or(["a = 1", "b = 2"]))
+      .as_string();
+    let expected_query = "WHERE a = 1 OR b = 2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn condition_and_nested_in_or_should_not_be_parenthesized() {
+    let query = sql::Select::new()
+      .where_clause(Condition::or([
+        Condition::and(["a = 1", "b = 2"]),
+        Condition::raw("c = 3"),
+      ]))
+      .as_string();
+    let expected_query = "WHERE a = 1 AND b = 2 OR c = 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn condition_or_nested_in_and_should_be_parenthesized() {
+    let query = sql::Select::new()
+      .where_clause(Condition::and([
+        Condition::raw("a = 1"),
+        Condition::or(["b = 2", "c = 3"]),
+      ]))
+      .as_string();
+    let expected_query = "WHERE a = 1 AND (b = 2 OR c = 3)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn condition_not_should_parenthesize_expressions() {
+    let query = sql::Select::new()
+      .where_clause(Condition::not("deleted"))
+      .and(Condition::not("login = 'foo'"))
+      .and(Condition::not(Condition::or(["a = 1", "b = 2"])))
+      .as_string();
+    let expected_query = "WHERE NOT deleted AND NOT (login = 'foo') AND NOT (a = 1 OR b = 2)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn raw_condition_with_top_level_or_should_be_parenthesized_when_joined_with_other_conditions() {
+    let query = sql::Select::new()
+      .where_clause("a = 1 OR b = 2")
+      .and("c = 3")
+      .as_string();
+    let expected_query = "WHERE (a = 1 OR b = 2) AND c = 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn raw_condition_with_or_inside_parentheses_or_quotes_should_not_be_parenthesized() {
+    let query = sql::Select::new()
+      .where_clause("(a = 1 or b = 2)")
+      .and("title = 'this or that'")
+      .and("orders = 1")
+      .as_string();
+    let expected_query = "WHERE (a = 1 or b = 2) AND title = 'this or that' AND orders = 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn empty_conditions_should_be_ignored() {
+    let query = sql::Select::new()
+      .where_clause(Condition::and(Vec::<Condition>::new()))
+      .and(Condition::or(["", "a = 1"]))
+      .as_string();
+    let expected_query = "WHERE a = 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_having_should_accept_conditions() {
+    let query = sql::Select::new()
+      .group_by("status")
+      .having(Condition::or(["count(*) > 10", "sum(price) > 100"]))
+      .having("status <> 'draft'")
+      .as_string();
+    let expected_query = "GROUP BY status HAVING (count(*) > 10 OR sum(price) > 100) AND status <> 'draft'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_where_clause_and_and_should_not_accumulate_equal_conditions() {
+    let query = sql::Select::new()
+      .where_clause(Condition::or(["a = 1", "b = 2"]))
+      .and(Condition::or(["a = 1", "b = 2"]))
+      .as_string();
+    let expected_query = "WHERE a = 1 OR b = 2";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod or_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_or_should_join_the_previous_conditions_by_or() {
+    let query = sql::Select::new()
+      .where_clause("login = 'foo'")
+      .or("login = 'bar'")
+      .as_string();
+    let expected_query = "WHERE login = 'foo' OR login = 'bar'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_or_should_group_the_previous_conditions() {
+    let query = sql::Select::new()
+      .where_clause("a = 1")
+      .and("b = 2")
+      .or("c = 3")
+      .and("d = 4")
+      .as_string();
+    let expected_query = "WHERE (a = 1 AND b = 2 OR c = 3) AND d = 4";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_or_without_previous_conditions_should_add_the_where_clause() {
+    let query = sql::Select::new().or("login = 'foo'").as_string();
+    let expected_query = "WHERE login = 'foo'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_or_should_keep_the_bound_values_in_order() {
+    let (query, values) = sql::Select::new()
+      .where_clause_bind("login = ?", "foo")
+      .or("login is null")
+      .and_bind("active = ?", true)
+      .build();
+    let expected_query = "WHERE (login = $1 OR login is null) AND active = $2";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned()), sql::Value::Bool(true)]);
+  }
+
+  #[test]
+  fn method_or_should_be_available_on_update_and_delete() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .where_clause("login = 'foo'")
+      .or("login = 'bar'")
+      .as_string();
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("login = 'foo'")
+      .or("login = 'bar'")
+      .as_string();
+
+    assert_eq!(
+      update,
+      "UPDATE users SET active = false WHERE login = 'foo' OR login = 'bar'"
+    );
+    assert_eq!(delete, "DELETE FROM users WHERE login = 'foo' OR login = 'bar'");
+  }
+}
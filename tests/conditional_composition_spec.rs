@@ -0,0 +1,186 @@
+mod when_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_when_should_call_the_function_when_the_condition_is_true() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .when(true, |select| select.where_clause("active = true"))
+      .as_string();
+    let expected_query = "SELECT id FROM users WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_should_return_the_builder_unchanged_when_the_condition_is_false() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .when(false, |select| select.where_clause("active = true"))
+      .as_string();
+    let expected_query = "SELECT id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_should_keep_the_order_of_the_method_chaining() {
+    let query = sql::Select::new()
+      .where_clause("a = 1")
+      .when(true, |select| select.or("b = 2"))
+      .and("c = 3")
+      .as_string();
+    let expected_query = "WHERE (a = 1 OR b = 2) AND c = 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_should_be_available_on_the_insert_update_delete_and_values_builders() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .when(false, |insert| insert.values("('bar')"));
+    let update = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .when(true, |update| update.where_clause("id = 1"));
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .when(true, |delete| delete.where_clause("active = false"));
+    let values = sql::Values::new()
+      .values("(1, 'one')")
+      .when(true, |values| values.values("(2, 'two')"));
+
+    assert_eq!(insert.as_string(), "INSERT INTO users (login) VALUES ('foo')");
+    assert_eq!(update.as_string(), "UPDATE users SET active = false WHERE id = 1");
+    assert_eq!(delete.as_string(), "DELETE FROM users WHERE active = false");
+    assert_eq!(values.as_string(), "VALUES (1, 'one'), (2, 'two')");
+  }
+}
+
+mod when_some_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  struct UserFilter {
+    login: Option<String>,
+    min_age: Option<u8>,
+    limit: Option<u32>,
+  }
+
+  fn users(filter: UserFilter) -> String {
+    sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .when_some(filter.login, |select, login| {
+        select.where_clause(format!("login = '{login}'"))
+      })
+      .when_some(filter.min_age, |select, age| {
+        select.where_clause(format!("age >= {age}"))
+      })
+      .when_some(filter.limit, |select, limit| select.limit(limit.to_string()))
+      .as_string()
+  }
+
+  #[test]
+  fn method_when_some_should_call_the_function_with_the_value_of_the_option() {
+    let query = users(UserFilter {
+      login: Some("foo".to_owned()),
+      min_age: Some(18),
+      limit: None,
+    });
+    let expected_query = "SELECT id, login FROM users WHERE login = 'foo' AND age >= 18";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_some_should_return_the_builder_unchanged_when_the_option_is_none() {
+    let query = users(UserFilter {
+      login: None,
+      min_age: None,
+      limit: None,
+    });
+    let expected_query = "SELECT id, login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_some_should_accept_options_of_borrowed_values() {
+    let login = String::from("foo");
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .when_some(Some(login.as_str()), |delete, login| {
+        delete.where_clause(format!("login = '{login}'"))
+      })
+      .as_string();
+    let expected_query = "DELETE FROM users WHERE login = 'foo'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_when_some_should_bind_the_value_of_the_option() {
+    let (query, values) = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .when_some(Some("foo"), |update, login| {
+        update.where_clause_bind("login = ?", login)
+      })
+      .build();
+    let expected_query = "UPDATE users SET active = false WHERE login = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values.len(), 1);
+  }
+}
+
+mod apply_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn paginate(select: sql::Select) -> sql::Select {
+    select.order_by("id").limit("10")
+  }
+
+  #[test]
+  fn method_apply_should_call_the_function_with_the_builder() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .apply(paginate)
+      .as_string();
+    let expected_query = "SELECT id FROM users ORDER BY id LIMIT 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_apply_should_accept_closures_capturing_the_environment() {
+    let tables = ["users", "orders"];
+    let query = sql::Select::new()
+      .select("count(*)")
+      .apply(|select| tables.iter().fold(select, |select, table| select.from(*table)))
+      .as_string();
+    let expected_query = "SELECT count(*) FROM users, orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_apply_should_be_available_on_the_insert_and_values_builders() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .apply(|insert| insert.values("('foo')"));
+    let values = sql::Values::new().apply(|values| values.values("(1, 'one')"));
+
+    assert_eq!(insert.as_string(), "INSERT INTO users (login) VALUES ('foo')");
+    assert_eq!(values.as_string(), "VALUES (1, 'one')");
+  }
+}
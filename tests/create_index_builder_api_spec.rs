@@ -0,0 +1,261 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn all_clauses_concatenated_in_order() {
+  let query = sql::CreateIndex::new()
+    .raw("/* all clauses in order */")
+    .where_clause("deleted_at is null")
+    .column("login")
+    .on("users")
+    .unique()
+    .create_index_if_not_exists("users_login_key")
+    .as_string();
+
+  let expected_query = "\
+    /* all clauses in order */ \
+    CREATE UNIQUE INDEX IF NOT EXISTS users_login_key \
+    ON users (login) \
+    WHERE deleted_at is null\
+  ";
+
+  assert_eq!(query, expected_query);
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateIndex::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .debug()
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx ON users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .print()
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateIndex::new()
+      .raw("create index users_login_idx")
+      .on("users")
+      .as_string();
+    let expected_query = "create index users_login_idx ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::CreateIndex::new().raw("  /* raw one */  ").as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod create_index_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_index_should_add_the_create_index_clause() {
+    let query = sql::CreateIndex::new().create_index("users_login_idx").as_string();
+    let expected_query = "CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_index_should_override_the_current_value() {
+    let query = sql::CreateIndex::new()
+      .create_index_if_not_exists("users_login_idx")
+      .create_index("  users_name_idx  ")
+      .as_string();
+    let expected_query = "CREATE INDEX users_name_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_index_if_not_exists_should_add_the_if_not_exists_modifier() {
+    let query = sql::CreateIndex::new()
+      .create_index_if_not_exists("users_login_idx")
+      .as_string();
+    let expected_query = "CREATE INDEX IF NOT EXISTS users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_unique_should_add_the_unique_modifier() {
+    let query = sql::CreateIndex::new()
+      .unique()
+      .create_index("users_login_key")
+      .as_string();
+    let expected_query = "CREATE UNIQUE INDEX users_login_key";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_create_index_clause() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .raw_after(sql::CreateIndexClause::CreateIndex, "on only users")
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx on only users";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod on_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_should_add_the_on_clause() {
+    let query = sql::CreateIndex::new().on("users").as_string();
+    let expected_query = "ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_override_the_current_value() {
+    let query = sql::CreateIndex::new().on("users").on("  orders  ").as_string();
+    let expected_query = "ON orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_should_be_after_create_index_clause() {
+    let query = sql::CreateIndex::new()
+      .on("users")
+      .create_index("users_login_idx")
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_between_the_table_and_the_columns() {
+    let query = sql::CreateIndex::new()
+      .on("users")
+      .column("tags")
+      .raw_after(sql::CreateIndexClause::On, "using gin")
+      .as_string();
+    let expected_query = "ON users using gin (tags)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_column_should_add_the_columns_inside_parentheses() {
+    let query = sql::CreateIndex::new().column("login").as_string();
+    let expected_query = "(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateIndex::new()
+      .column("lower(login)")
+      .column("created_at desc")
+      .as_string();
+    let expected_query = "(lower(login), created_at desc)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateIndex::new().column("login").column("  login  ").as_string();
+    let expected_query = "(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_columns() {
+    let query = sql::CreateIndex::new()
+      .column("login")
+      .raw_after(sql::CreateIndexClause::Column, "include (name)")
+      .as_string();
+    let expected_query = "(login) include (name)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod where_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_where_clause_should_add_the_predicate_of_a_partial_index() {
+    let query = sql::CreateIndex::new()
+      .on("users")
+      .column("login")
+      .where_clause("deleted_at is null")
+      .as_string();
+    let expected_query = "ON users (login) WHERE deleted_at is null";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_and_should_join_the_conditions_with_and() {
+    let query = sql::CreateIndex::new()
+      .where_clause("deleted_at is null")
+      .and("active = true")
+      .as_string();
+    let expected_query = "WHERE deleted_at is null AND active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_where_clause_should_accept_conditions() {
+    let query = sql::CreateIndex::new()
+      .where_clause(sql::Condition::or(["status = 'draft'", "status = 'review'"]))
+      .and("deleted_at is null")
+      .as_string();
+    let expected_query = "WHERE (status = 'draft' OR status = 'review') AND deleted_at is null";
+
+    assert_eq!(query, expected_query);
+  }
+}
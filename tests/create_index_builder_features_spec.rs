@@ -0,0 +1,68 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn create_index_builder_should_be_displayable() {
+  let create_index = sql::CreateIndex::new()
+    .create_index("users_login_idx")
+    .on("users")
+    .column("login");
+
+  println!("{}", create_index);
+
+  let query = create_index.as_string();
+  let expected_query = "CREATE INDEX users_login_idx ON users (login)";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_index_builder_should_be_debuggable() {
+  let create_index = sql::CreateIndex::new()
+    .create_index("users_login_idx")
+    .on("users")
+    .column("login")
+    .where_clause("deleted_at is null");
+
+  println!("{:?}", create_index);
+
+  let query = create_index.as_string();
+  let expected_query = "CREATE INDEX users_login_idx ON users (login) WHERE deleted_at is null";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_index_builder_should_be_cloneable() {
+  let login_index = sql::CreateIndex::new()
+    .create_index("users_login_idx")
+    .on("users")
+    .column("login");
+  let partial_login_index = login_index.clone().where_clause("deleted_at is null");
+
+  let query = login_index.as_string();
+  let partial_query = partial_login_index.as_string();
+
+  let expected_query = "CREATE INDEX users_login_idx ON users (login)";
+  let expected_partial_query = "CREATE INDEX users_login_idx ON users (login) WHERE deleted_at is null";
+
+  assert_eq!(query, expected_query);
+  assert_eq!(partial_query, expected_partial_query);
+}
+
+#[test]
+fn create_index_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut create_index = sql::CreateIndex::new()
+    .create_index("users_login_key")
+    .on("users")
+    .column("login");
+
+  if true {
+    create_index = create_index.unique();
+  }
+
+  let query = create_index.as_string();
+  let expected_query = "CREATE UNIQUE INDEX users_login_key ON users (login)";
+
+  assert_eq!(query, expected_query);
+}
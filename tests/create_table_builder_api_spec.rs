@@ -0,0 +1,387 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn all_clauses_concatenated_in_order() {
+  let query = sql::CreateTable::new()
+    .raw("/* all clauses in order */")
+    .foreign_key("(address_id) references addresses (id)")
+    .constraint("users_login_key unique (login)")
+    .primary_key("(id)")
+    .column("id serial")
+    .column("login varchar(40) not null")
+    .column("address_id int")
+    .create_table_if_not_exists("users")
+    .as_string();
+
+  let expected_query = "\
+    /* all clauses in order */ \
+    CREATE TABLE IF NOT EXISTS users (\
+      id serial, \
+      login varchar(40) not null, \
+      address_id int, \
+      PRIMARY KEY(id), \
+      CONSTRAINT users_login_key unique (login), \
+      FOREIGN KEY(address_id) references addresses (id)\
+    )\
+  ";
+
+  assert_eq!(query, expected_query);
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateTable::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .primary_key("(id)")
+      .debug()
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial, PRIMARY KEY(id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .print()
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateTable::new()
+      .raw("create table users")
+      .column("id serial")
+      .as_string();
+    let expected_query = "create table users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateTable::new()
+      .raw("/* raw one */")
+      .raw("/* raw one */")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new().raw("  /* raw one */  ").as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod create_table_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_table_should_add_the_create_table_clause() {
+    let query = sql::CreateTable::new().create_table("users").as_string();
+    let expected_query = "CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_should_override_the_current_value() {
+    let query = sql::CreateTable::new()
+      .create_table_if_not_exists("users")
+      .create_table("orders")
+      .as_string();
+    let expected_query = "CREATE TABLE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_if_not_exists_should_add_the_if_not_exists_modifier() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .create_table_if_not_exists("orders")
+      .as_string();
+    let expected_query = "CREATE TABLE IF NOT EXISTS orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new().create_table("  users  ").as_string();
+    let expected_query = "CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_create_table_clause() {
+    let query = sql::CreateTable::new()
+      .raw_before(sql::CreateTableClause::CreateTable, "/* users table */")
+      .create_table("users")
+      .as_string();
+    let expected_query = "/* users table */ CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_create_table_clause() {
+    let query = sql::CreateTable::new()
+      .create_table("users_2024")
+      .raw_after(sql::CreateTableClause::CreateTable, "partition of users")
+      .as_string();
+    let expected_query = "CREATE TABLE users_2024 partition of users";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_column_should_add_the_column_definitions_inside_parentheses() {
+    let query = sql::CreateTable::new().column("id serial").as_string();
+    let expected_query = "(id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .column("login varchar(40)")
+      .as_string();
+    let expected_query = "(id serial, login varchar(40))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .column("id serial")
+      .as_string();
+    let expected_query = "(id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new().column("  id serial  ").as_string();
+    let expected_query = "(id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_a_raw_definition_before_the_columns() {
+    let query = sql::CreateTable::new()
+      .raw_before(sql::CreateTableClause::Column, "id serial")
+      .column("login varchar(40)")
+      .as_string();
+    let expected_query = "(id serial, login varchar(40))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_a_raw_definition_after_the_columns() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .raw_after(sql::CreateTableClause::Column, "login varchar(40)")
+      .as_string();
+    let expected_query = "(id serial, login varchar(40))";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod primary_key_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_primary_key_should_add_the_primary_key_definition() {
+    let query = sql::CreateTable::new().primary_key("(id)").as_string();
+    let expected_query = "(PRIMARY KEY(id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_primary_key_should_override_the_current_value() {
+    let query = sql::CreateTable::new()
+      .primary_key("(id)")
+      .primary_key("(login, id)")
+      .as_string();
+    let expected_query = "(PRIMARY KEY(login, id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_primary_key_should_be_after_column_clause() {
+    let query = sql::CreateTable::new()
+      .primary_key("(id)")
+      .column("id serial")
+      .as_string();
+    let expected_query = "(id serial, PRIMARY KEY(id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_a_raw_definition_after_the_primary_key() {
+    let query = sql::CreateTable::new()
+      .primary_key("(id)")
+      .raw_after(sql::CreateTableClause::PrimaryKey, "unique (login)")
+      .as_string();
+    let expected_query = "(PRIMARY KEY(id), unique (login))";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod constraint_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_constraint_should_add_the_constraint_definition() {
+    let query = sql::CreateTable::new()
+      .constraint("users_login_key unique (login)")
+      .as_string();
+    let expected_query = "(CONSTRAINT users_login_key unique (login))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_constraint_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .constraint("users_login_key unique (login)")
+      .constraint("users_age_check check (age > 0)")
+      .as_string();
+    let expected_query = "(CONSTRAINT users_login_key unique (login), CONSTRAINT users_age_check check (age > 0))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_constraint_should_be_after_primary_key_clause() {
+    let query = sql::CreateTable::new()
+      .constraint("users_login_key unique (login)")
+      .primary_key("(id)")
+      .as_string();
+    let expected_query = "(PRIMARY KEY(id), CONSTRAINT users_login_key unique (login))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_a_raw_definition_before_the_constraints() {
+    let query = sql::CreateTable::new()
+      .raw_before(sql::CreateTableClause::Constraint, "check (age > 0)")
+      .constraint("users_login_key unique (login)")
+      .as_string();
+    let expected_query = "(check (age > 0), CONSTRAINT users_login_key unique (login))";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod foreign_key_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_foreign_key_should_add_the_foreign_key_definition() {
+    let query = sql::CreateTable::new()
+      .foreign_key("(user_id) references users (id)")
+      .as_string();
+    let expected_query = "(FOREIGN KEY(user_id) references users (id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_foreign_key_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .foreign_key("(user_id) references users (id)")
+      .foreign_key("(product_id) references products (id) on delete cascade")
+      .as_string();
+    let expected_query = "\
+      (FOREIGN KEY(user_id) references users (id), \
+      FOREIGN KEY(product_id) references products (id) on delete cascade)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_foreign_key_should_be_after_constraint_clause() {
+    let query = sql::CreateTable::new()
+      .foreign_key("(user_id) references users (id)")
+      .constraint("orders_pkey primary key (id)")
+      .as_string();
+    let expected_query = "(CONSTRAINT orders_pkey primary key (id), FOREIGN KEY(user_id) references users (id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_a_raw_definition_after_the_foreign_keys() {
+    let query = sql::CreateTable::new()
+      .foreign_key("(user_id) references users (id)")
+      .raw_after(
+        sql::CreateTableClause::ForeignKey,
+        "exclude using gist (period with &&)",
+      )
+      .as_string();
+    let expected_query = "(FOREIGN KEY(user_id) references users (id), exclude using gist (period with &&))";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,103 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn create_table_builder_should_be_displayable() {
+  let create_table = sql::CreateTable::new().create_table("users").column("id serial");
+
+  println!("{}", create_table);
+
+  let query = create_table.as_string();
+  let expected_query = "CREATE TABLE users (id serial)";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_table_builder_should_be_debuggable() {
+  let create_table = sql::CreateTable::new()
+    .create_table("users")
+    .column("id serial")
+    .column("login varchar(40)");
+
+  println!("{:?}", create_table);
+
+  let query = create_table.as_string();
+  let expected_query = "CREATE TABLE users (id serial, login varchar(40))";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_table_builder_debug_should_render_one_definition_per_line() {
+  let create_table = sql::CreateTable::new()
+    .create_table("users")
+    .column("id serial")
+    .column("login varchar(40)")
+    .primary_key("(id)");
+
+  let output = format!("{:?}", create_table);
+  let expected_body = "(\n  id serial,\n  login varchar(";
+
+  assert!(output.contains(expected_body));
+}
+
+#[test]
+fn create_table_builder_should_be_cloneable() {
+  let create_users = sql::CreateTable::new()
+    .raw("/* test raw */")
+    .raw_before(sql::CreateTableClause::CreateTable, "/* test raw_before */")
+    .create_table("users")
+    .column("id serial");
+
+  let create_users_with_login = create_users.clone().column("login varchar(40)");
+
+  let query = create_users.as_string();
+  let query_with_login = create_users_with_login.as_string();
+
+  let expected_query = "/* test raw */ /* test raw_before */ CREATE TABLE users (id serial)";
+  let expected_query_with_login = "\
+    /* test raw */ /* test raw_before */ \
+    CREATE TABLE users (id serial, login varchar(40))\
+  ";
+
+  assert_eq!(query, expected_query);
+  assert_eq!(query_with_login, expected_query_with_login);
+}
+
+#[test]
+fn create_table_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut create_table = sql::CreateTable::new().create_table("users").column("id serial");
+
+  if true {
+    create_table = create_table.column("login varchar(40)");
+  }
+
+  let query = create_table.as_string();
+  let expected_query = "CREATE TABLE users (id serial, login varchar(40))";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_table_builder_should_be_composable() {
+  fn audit_columns(create_table: sql::CreateTable) -> sql::CreateTable {
+    create_table
+      .column("created_at timestamp not null default now()")
+      .column("updated_at timestamp")
+  }
+
+  let query = Some(sql::CreateTable::new().create_table("users").column("id serial"))
+    .map(audit_columns)
+    .map(|create_table| create_table.as_string())
+    .unwrap();
+  let expected_query = "\
+    CREATE TABLE users (\
+      id serial, \
+      created_at timestamp not null default now(), \
+      updated_at timestamp\
+    )\
+  ";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,305 @@
+mod placeholder_style {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn select() -> sql::Select<'static> {
+    sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .and_bind("active = ?", true)
+  }
+
+  #[test]
+  fn dialect_postgres_should_render_numbered_placeholders_with_dollar_sign() {
+    let query = select().to_sql(sql::Dialect::Postgres).unwrap();
+    let expected_query = "SELECT * FROM users WHERE login = $1 AND active = $2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn dialect_sqlite_should_render_numbered_placeholders_with_question_mark() {
+    let query = select().to_sql(sql::Dialect::Sqlite).unwrap();
+    let expected_query = "SELECT * FROM users WHERE login = ?1 AND active = ?2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn dialect_mysql_should_render_anonymous_placeholders() {
+    let query = select().to_sql(sql::Dialect::Mysql).unwrap();
+    let expected_query = "SELECT * FROM users WHERE login = ? AND active = ?";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_build_with_should_return_the_values_in_the_placeholders_order() {
+    let (query, values) = sql::Update::new()
+      .where_clause_bind("login = ?", "foo")
+      .set_bind("name = ?", "Foo")
+      .update("users")
+      .build_with(sql::Dialect::Mysql)
+      .unwrap();
+    let expected_query = "UPDATE users SET name = ? WHERE login = ?";
+    let expected_values = vec![sql::Value::Text("Foo".to_owned()), sql::Value::Text("foo".to_owned())];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, expected_values);
+  }
+
+  #[test]
+  fn positional_placeholders_written_in_the_query_should_not_be_changed() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id = $1")
+      .to_sql(sql::Dialect::Sqlite)
+      .unwrap();
+    let expected_query = "DELETE FROM users WHERE id = $1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn method_as_string_should_not_be_affected_by_the_dialects() {
+    let query = select().as_string();
+    let expected_query = "SELECT * FROM users WHERE login = $1 AND active = $2";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod quoting {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_quote_should_use_double_quotes_for_postgres_and_sqlite() {
+    assert_eq!(sql::Dialect::Postgres.quote("user"), "\"user\"");
+    assert_eq!(sql::Dialect::Sqlite.quote("user"), "\"user\"");
+  }
+
+  #[test]
+  fn method_quote_should_use_backticks_for_mysql() {
+    assert_eq!(sql::Dialect::Mysql.quote("user"), "`user`");
+  }
+
+  #[test]
+  fn method_quote_should_escape_the_quote_character() {
+    assert_eq!(sql::Dialect::Postgres.quote("my\"table"), "\"my\"\"table\"");
+    assert_eq!(sql::Dialect::Mysql.quote("my`table"), "`my``table`");
+  }
+}
+
+mod limit_and_offset {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn dialect_mysql_should_render_limit_offset_count() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit("10")
+      .offset("20")
+      .to_sql(sql::Dialect::Mysql)
+      .unwrap();
+    let expected_query = "SELECT * FROM users LIMIT 20, 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn dialects_postgres_and_sqlite_should_render_limit_and_offset_clauses() {
+    let select = sql::Select::new().select("*").from("users").limit("10").offset("20");
+    let expected_query = "SELECT * FROM users LIMIT 10 OFFSET 20";
+
+    assert_eq!(select.to_sql(sql::Dialect::Postgres).unwrap(), expected_query);
+    assert_eq!(select.to_sql(sql::Dialect::Sqlite).unwrap(), expected_query);
+  }
+}
+
+mod unsupported_clauses {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_to_sql_should_return_an_error_naming_the_unsupported_clause() {
+    let result = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_conflict("do nothing")
+      .to_sql(sql::Dialect::Mysql);
+    let expected_error = sql::BuildError::UnsupportedClause {
+      clause: sql::Clause::Insert(sql::InsertClause::OnConflict),
+      dialect: sql::Dialect::Mysql,
+    };
+
+    assert_eq!(result, Err(expected_error));
+  }
+
+  #[test]
+  fn error_message_should_name_the_clause_and_the_dialect() {
+    let error = sql::Insert::new()
+      .overriding("system value")
+      .to_sql(sql::Dialect::Sqlite)
+      .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "the clause InsertClause::Overriding is not supported by SQLite"
+    );
+  }
+
+  #[test]
+  fn method_to_sql_should_accept_the_clauses_supported_by_the_dialect() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .overriding("system value")
+      .values("('foo')")
+      .on_conflict("do nothing")
+      .to_sql(sql::Dialect::Postgres);
+
+    assert!(query.is_ok());
+  }
+
+  #[test]
+  fn method_to_sql_should_return_an_error_for_the_concurrently_clause_outside_postgres() {
+    let result = sql::CreateIndex::new()
+      .create_index("users_name_idx")
+      .concurrently()
+      .on("users")
+      .column("name")
+      .to_sql(sql::Dialect::Sqlite);
+    let expected_error = sql::BuildError::UnsupportedClause {
+      clause: sql::Clause::CreateIndex(sql::CreateIndexClause::CreateIndex),
+      dialect: sql::Dialect::Sqlite,
+    };
+
+    assert_eq!(result, Err(expected_error));
+  }
+
+  #[test]
+  fn raw_clauses_should_not_be_checked() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .raw_after(sql::InsertClause::Values, "on conflict do nothing")
+      .to_sql(sql::Dialect::Mysql)
+      .unwrap();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo') on conflict do nothing";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod unsupported_clauses_with_composition {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_to_sql_should_check_the_clauses_of_the_with_queries() {
+    let deleted = sql::Delete::new().delete_from("users").returning("id");
+    let result = sql::Select::new()
+      .with("deleted", deleted)
+      .select("*")
+      .from("deleted")
+      .to_sql(sql::Dialect::Mysql);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "the clause DeleteClause::Returning is not supported by MySQL"
+    );
+  }
+
+  #[test]
+  fn method_to_sql_should_return_an_error_for_the_returning_clause_in_mysql() {
+    let result = sql::Update::new()
+      .update("users")
+      .set("active = true")
+      .returning("id")
+      .to_sql(sql::Dialect::Mysql);
+
+    assert!(result.is_err());
+  }
+}
+
+mod compound_select {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn select() -> sql::Select<'static> {
+    sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("admins"))
+  }
+
+  #[test]
+  fn dialect_postgres_should_wrap_the_selects_in_parentheses() {
+    let query = select().to_sql(sql::Dialect::Postgres).unwrap();
+    let expected_query = "(SELECT login FROM users) UNION (SELECT login FROM admins)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn dialect_sqlite_should_not_wrap_the_selects_in_parentheses() {
+    let query = select().to_sql(sql::Dialect::Sqlite).unwrap();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod limit_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn dialect_postgres_should_not_accept_the_limit_clause_of_delete_and_update() {
+    let delete = sql::Delete::new().delete_from("users").limit("1");
+    let update = sql::Update::new().update("users").set("active = false").limit("1");
+
+    assert_eq!(
+      delete.to_sql(sql::Dialect::Postgres).unwrap_err().to_string(),
+      "the clause DeleteClause::Limit is not supported by PostgreSQL"
+    );
+    assert_eq!(
+      update.to_sql(sql::Dialect::Postgres).unwrap_err().to_string(),
+      "the clause UpdateClause::Limit is not supported by PostgreSQL"
+    );
+  }
+
+  #[test]
+  fn dialect_sqlite_should_accept_the_limit_clause_of_delete() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .limit("1")
+      .to_sql(sql::Dialect::Sqlite)
+      .unwrap();
+
+    assert_eq!(query, "DELETE FROM users LIMIT 1");
+  }
+}
+
+mod mysql_clauses {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn dialect_postgres_should_not_accept_the_on_duplicate_key_update_clause() {
+    let result = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_duplicate_key_update("login = VALUES(login)")
+      .to_sql(sql::Dialect::Postgres);
+
+    assert_eq!(
+      result.unwrap_err().to_string(),
+      "the clause InsertClause::OnDuplicateKeyUpdate is not supported by PostgreSQL"
+    );
+  }
+}
@@ -0,0 +1,146 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn all_clauses_concatenated_in_order() {
+  let query = sql::DropIndex::new()
+    .raw("/* all clauses in order */")
+    .cascade()
+    .drop_index("users_login_idx")
+    .drop_index_if_exists("orders_user_id_idx")
+    .as_string();
+  let expected_query = "/* all clauses in order */ DROP INDEX IF EXISTS users_login_idx, orders_user_id_idx CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropIndex::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropIndex::new().drop_index("users_login_idx").debug().as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropIndex::new().drop_index("users_login_idx").print().as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropIndex::new().raw("drop index users_login_idx").as_string();
+    let expected_query = "drop index users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::DropIndex::new().raw("  /* raw one */  ").as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod drop_index_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_index_should_add_the_drop_index_clause() {
+    let query = sql::DropIndex::new().drop_index("users_login_idx").as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .drop_index("orders_user_id_idx")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx, orders_user_id_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .drop_index("users_login_idx")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_should_trim_space_of_the_argument() {
+    let query = sql::DropIndex::new().drop_index("  users_login_idx  ").as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_if_exists_should_add_the_if_exists_modifier() {
+    let query = sql::DropIndex::new()
+      .drop_index_if_exists("users_login_idx")
+      .as_string();
+    let expected_query = "DROP INDEX IF EXISTS users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_modifier_after_the_indexes() {
+    let query = sql::DropIndex::new()
+      .cascade()
+      .drop_index("users_login_idx")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_drop_index_clause() {
+    let query = sql::DropIndex::new()
+      .raw_before(sql::DropIndexClause::DropIndex, "/* cleanup */")
+      .drop_index("users_login_idx")
+      .as_string();
+    let expected_query = "/* cleanup */ DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_index_clause() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .raw_after(sql::DropIndexClause::DropIndex, "restrict")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx restrict";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,52 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn drop_index_builder_should_be_displayable() {
+  let drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+  println!("{}", drop_index);
+
+  let query = drop_index.as_string();
+  let expected_query = "DROP INDEX users_login_idx";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_index_builder_should_be_debuggable() {
+  let drop_index = sql::DropIndex::new().drop_index("users_login_idx").cascade();
+
+  println!("{:?}", drop_index);
+
+  let query = drop_index.as_string();
+  let expected_query = "DROP INDEX users_login_idx CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_index_builder_should_be_cloneable() {
+  let drop_login_index = sql::DropIndex::new().drop_index("users_login_idx");
+  let drop_login_and_user_id_indexes = drop_login_index.clone().drop_index("orders_user_id_idx");
+
+  assert_eq!(drop_login_index.as_string(), "DROP INDEX users_login_idx");
+  assert_eq!(
+    drop_login_and_user_id_indexes.as_string(),
+    "DROP INDEX users_login_idx, orders_user_id_idx"
+  );
+}
+
+#[test]
+fn drop_index_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+  if true {
+    drop_index = drop_index.cascade();
+  }
+
+  let query = drop_index.as_string();
+  let expected_query = "DROP INDEX users_login_idx CASCADE";
+
+  assert_eq!(query, expected_query);
+}
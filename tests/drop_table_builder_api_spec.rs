@@ -0,0 +1,141 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn all_clauses_concatenated_in_order() {
+  let query = sql::DropTable::new()
+    .raw("/* all clauses in order */")
+    .cascade()
+    .drop_table("users")
+    .drop_table_if_exists("orders")
+    .as_string();
+  let expected_query = "/* all clauses in order */ DROP TABLE IF EXISTS users, orders CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropTable::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropTable::new().drop_table("users").debug().as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropTable::new().drop_table("users").print().as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropTable::new().raw("drop table users").as_string();
+    let expected_query = "drop table users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::DropTable::new().raw("  /* raw one */  ").as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod drop_table_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_table_should_add_the_drop_table_clause() {
+    let query = sql::DropTable::new().drop_table("users").as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .drop_table("orders")
+      .as_string();
+    let expected_query = "DROP TABLE users, orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .drop_table("users")
+      .as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_should_trim_space_of_the_argument() {
+    let query = sql::DropTable::new().drop_table("  users  ").as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_if_exists_should_add_the_if_exists_modifier() {
+    let query = sql::DropTable::new().drop_table_if_exists("users").as_string();
+    let expected_query = "DROP TABLE IF EXISTS users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_modifier_after_the_tables() {
+    let query = sql::DropTable::new().cascade().drop_table("users").as_string();
+    let expected_query = "DROP TABLE users CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_drop_table_clause() {
+    let query = sql::DropTable::new()
+      .raw_before(sql::DropTableClause::DropTable, "/* cleanup */")
+      .drop_table("users")
+      .as_string();
+    let expected_query = "/* cleanup */ DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_table_clause() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .raw_after(sql::DropTableClause::DropTable, "restrict")
+      .as_string();
+    let expected_query = "DROP TABLE users restrict";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,49 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn drop_table_builder_should_be_displayable() {
+  let drop_table = sql::DropTable::new().drop_table("users");
+
+  println!("{}", drop_table);
+
+  let query = drop_table.as_string();
+  let expected_query = "DROP TABLE users";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_table_builder_should_be_debuggable() {
+  let drop_table = sql::DropTable::new().drop_table("users").cascade();
+
+  println!("{:?}", drop_table);
+
+  let query = drop_table.as_string();
+  let expected_query = "DROP TABLE users CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_table_builder_should_be_cloneable() {
+  let drop_users = sql::DropTable::new().drop_table("users");
+  let drop_users_and_orders = drop_users.clone().drop_table("orders");
+
+  assert_eq!(drop_users.as_string(), "DROP TABLE users");
+  assert_eq!(drop_users_and_orders.as_string(), "DROP TABLE users, orders");
+}
+
+#[test]
+fn drop_table_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut drop_table = sql::DropTable::new().drop_table("users");
+
+  if true {
+    drop_table = drop_table.cascade();
+  }
+
+  let query = drop_table.as_string();
+  let expected_query = "DROP TABLE users CASCADE";
+
+  assert_eq!(query, expected_query);
+}
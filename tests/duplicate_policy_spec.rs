@@ -0,0 +1,299 @@
+mod default_policy {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn repeated_values_of_the_clauses_should_be_ignored() {
+    let query = sql::Select::new()
+      .select("id")
+      .select("id")
+      .from("users")
+      .from("users")
+      .where_clause("active = true")
+      .where_clause("active = true")
+      .order_by("id")
+      .order_by("id")
+      .as_string();
+    let expected_query = "SELECT id FROM users WHERE active = true ORDER BY id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn repeated_rows_of_the_insert_builder_should_be_kept() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('foo')")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo'), ('foo')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn repeated_rows_of_the_values_builder_should_be_kept() {
+    let query = sql::Values::new().values("(1, 'one')").values("(1, 'one')").as_string();
+    let expected_query = "VALUES (1, 'one'), (1, 'one')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn repeated_rows_with_bound_values_should_be_kept() {
+    let (query, values) = sql::Insert::new()
+      .insert_into("users (login)")
+      .values_bind("(?)", "foo")
+      .values_bind("(?)", "foo")
+      .build();
+    let expected_query = "INSERT INTO users (login) VALUES ($1), ($2)";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values.len(), 2);
+  }
+
+  #[test]
+  fn repeated_conditions_with_a_bound_nan_should_be_ignored() {
+    let (_, values) = sql::Select::new()
+      .from("scores")
+      .where_clause_bind("score <> ?", f64::NAN)
+      .where_clause_bind("score <> ?", f64::NAN)
+      .build();
+
+    assert_eq!(values.len(), 1);
+  }
+
+  #[test]
+  fn repeated_conditions_with_a_bound_zero_should_be_ignored_regardless_of_the_sign() {
+    let (_, values) = sql::Select::new()
+      .from("scores")
+      .where_clause_bind("score > ?", 0.0)
+      .where_clause_bind("score > ?", -0.0)
+      .build();
+
+    assert_eq!(values, vec![sql::Value::Float(0.0)]);
+  }
+
+  #[test]
+  fn values_of_a_builder_with_the_default_policy_should_be_accepted_by_validate() {
+    let select = sql::Select::new().select("id").select("id").from("users");
+
+    assert_eq!(select.validate(), Ok(()));
+  }
+}
+
+mod dedupe_policy {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn repeated_rows_of_the_insert_builder_should_be_ignored() {
+    let query = sql::Insert::new()
+      .duplicate_policy(sql::DuplicatePolicy::Dedupe)
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .values("('foo')")
+      .as_string();
+    let expected_query = "INSERT INTO users (login) VALUES ('foo'), ('bar')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn repeated_values_should_be_ignored_in_large_clauses() {
+    let select = (0..5_000).chain(0..5_000).fold(
+      sql::Select::new().duplicate_policy(sql::DuplicatePolicy::Dedupe),
+      |select, id| select.select(format!("c{id}")),
+    );
+    let query = select.as_string();
+
+    assert_eq!(query.matches(", ").count(), 4_999);
+    assert!(query.ends_with("c4998, c4999"));
+  }
+
+  #[test]
+  fn values_with_the_same_text_in_different_clauses_should_be_kept() {
+    let query = sql::Select::new()
+      .select("login")
+      .group_by("login")
+      .order_by("login")
+      .as_string();
+    let expected_query = "SELECT login GROUP BY login ORDER BY login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn repeated_values_should_be_ignored_after_the_conditions_were_combined_by_or() {
+    let query = sql::Select::new()
+      .where_clause("a = 1")
+      .or("b = 2")
+      .where_clause("c = 3")
+      .where_clause("c = 3")
+      .as_string();
+    let expected_query = "WHERE (a = 1 OR b = 2) AND c = 3";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn the_condition_combined_by_or_should_be_ignored_when_it_is_added_again() {
+    let query = sql::Select::new()
+      .where_clause("a = 1")
+      .or("b = 2")
+      .where_clause(sql::Condition::or(["a = 1", "b = 2"]))
+      .as_string();
+    let expected_query = "WHERE a = 1 OR b = 2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn values_removed_from_a_clause_should_be_accepted_again() {
+    let query = sql::Select::new()
+      .where_clause("a = 1")
+      .remove_where(|_| true)
+      .where_clause("b = 2")
+      .where_clause("a = 1")
+      .as_string();
+    let expected_query = "WHERE b = 2 AND a = 1";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod keep_all_policy {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn repeated_expressions_of_the_select_clause_should_be_kept() {
+    let query = sql::Select::new()
+      .duplicate_policy(sql::DuplicatePolicy::KeepAll)
+      .select("random()")
+      .select("random()")
+      .as_string();
+    let expected_query = "SELECT random(), random()";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn repeated_raw_sql_should_be_kept() {
+    let query = sql::Delete::new()
+      .duplicate_policy(sql::DuplicatePolicy::KeepAll)
+      .raw("/* one */")
+      .raw("/* one */")
+      .delete_from("users")
+      .as_string();
+    let expected_query = "/* one */ /* one */ DELETE FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn values_added_before_the_policy_should_keep_the_previous_policy() {
+    let query = sql::Select::new()
+      .select("id")
+      .select("id")
+      .duplicate_policy(sql::DuplicatePolicy::KeepAll)
+      .select("id")
+      .as_string();
+    let expected_query = "SELECT id, id";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod error_policy {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_validate_should_return_an_error_naming_the_repeated_value() {
+    let update = sql::Update::new()
+      .duplicate_policy(sql::DuplicatePolicy::Error)
+      .update("users")
+      .set("login = 'foo'")
+      .set("active = true")
+      .set("login = 'foo'");
+    let expected = sql::BuildError::DuplicateValue {
+      clause: Some(sql::Clause::Update(sql::UpdateClause::Set)),
+      value: "login = 'foo'".to_owned(),
+    };
+
+    assert_eq!(update.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_an_error_for_repeated_rows() {
+    let values = sql::Values::new()
+      .duplicate_policy(sql::DuplicatePolicy::Error)
+      .values("(1, 'one')")
+      .values("(1, 'one')");
+    let expected = sql::BuildError::DuplicateValue {
+      clause: Some(sql::Clause::Values(sql::ValuesClause::Values)),
+      value: "(1, 'one')".to_owned(),
+    };
+
+    assert_eq!(values.try_as_string(), Err(expected));
+  }
+
+  #[test]
+  fn repeated_conditions_should_be_named_by_the_rendered_condition() {
+    let delete = sql::Delete::new()
+      .duplicate_policy(sql::DuplicatePolicy::Error)
+      .delete_from("users")
+      .where_clause(sql::Condition::or(["a = 1", "b = 2"]))
+      .where_clause(sql::Condition::or(["a = 1", "b = 2"]));
+    let expected = sql::BuildError::DuplicateValue {
+      clause: Some(sql::Clause::Delete(sql::DeleteClause::Where)),
+      value: "a = 1 OR b = 2".to_owned(),
+    };
+
+    assert_eq!(delete.validate(), Err(expected));
+  }
+
+  #[test]
+  fn repeated_raw_sql_should_be_named_by_the_raw_method() {
+    let drop_table = sql::DropTable::new()
+      .duplicate_policy(sql::DuplicatePolicy::Error)
+      .raw("/* drop */")
+      .raw("/* drop */")
+      .drop_table("users");
+    let expected = sql::BuildError::DuplicateValue {
+      clause: None,
+      value: "/* drop */".to_owned(),
+    };
+
+    assert_eq!(drop_table.validate(), Err(expected));
+  }
+
+  #[test]
+  fn query_without_repeated_values_should_be_rendered() {
+    let query = sql::CreateTable::new()
+      .duplicate_policy(sql::DuplicatePolicy::Error)
+      .create_table("users")
+      .column("id serial")
+      .column("login varchar(40)")
+      .try_as_string();
+    let expected_query = "CREATE TABLE users (id serial, login varchar(40))";
+
+    assert_eq!(query, Ok(expected_query.to_owned()));
+  }
+
+  #[test]
+  fn error_should_be_displayed_with_the_clause_and_the_value() {
+    let error = sql::Select::new()
+      .duplicate_policy(sql::DuplicatePolicy::Error)
+      .select("id")
+      .select("id")
+      .validate()
+      .unwrap_err();
+    let expected = "the value `id` is defined more than once in the clause SelectClause::Select";
+
+    assert_eq!(error.to_string(), expected);
+  }
+}
@@ -0,0 +1,52 @@
+#[cfg(feature = "log")]
+mod log_sink {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+  use std::sync::Mutex;
+
+  static RECORDS: Mutex<Vec<(log::Level, String, String)>> = Mutex::new(vec![]);
+
+  struct Logger;
+
+  impl log::Log for Logger {
+    fn enabled(&self, _metadata: &log::Metadata) -> bool {
+      true
+    }
+
+    fn log(&self, record: &log::Record) {
+      let entry = (record.level(), record.target().to_owned(), record.args().to_string());
+      RECORDS.lock().unwrap().push(entry);
+    }
+
+    fn flush(&self) {}
+  }
+
+  #[test]
+  fn methods_print_to_and_debug_to_should_write_records_with_the_level_of_the_sink() {
+    let _ = log::set_logger(&Logger);
+    log::set_max_level(log::LevelFilter::Trace);
+
+    sql::Select::new()
+      .select("id")
+      .from("users")
+      .print_to(&mut sql::LogSink::new(log::Level::Info))
+      .debug_to(&mut sql::LogSink::new(log::Level::Trace));
+
+    let hr = "-- ------------------------------------------------------------------------------";
+    let records = RECORDS.lock().unwrap();
+    let expected = vec![
+      (
+        log::Level::Info,
+        "sql_query_builder".to_owned(),
+        "SELECT id FROM users".to_owned(),
+      ),
+      (
+        log::Level::Trace,
+        "sql_query_builder".to_owned(),
+        format!("{hr}\nSELECT id \nFROM users\n{hr}"),
+      ),
+    ];
+
+    assert_eq!(*records, expected);
+  }
+}
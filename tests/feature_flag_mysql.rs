@@ -0,0 +1,492 @@
+#[cfg(feature = "mysql")]
+mod insert_ignore_into_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_insert_ignore_into_should_add_the_insert_ignore_into_clause() {
+      let query = sql::Insert::new().insert_ignore_into("users (login, name)").as_string();
+      let expected_query = "INSERT IGNORE INTO users (login, name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_ignore_into_should_override_value_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .insert_ignore_into("users (login, name)")
+        .insert_ignore_into("orders (product_name, price)")
+        .as_string();
+      let expected_query = "INSERT IGNORE INTO orders (product_name, price)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_ignore_into_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new().insert_ignore_into("  users  ").as_string();
+      let expected_query = "INSERT IGNORE INTO users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_values_should_be_after_insert_ignore_into_clause() {
+      let query = sql::Insert::new()
+        .values("('foo', 'Foo')")
+        .insert_ignore_into("users (login, name)")
+        .as_string();
+      let expected_query = "INSERT IGNORE INTO users (login, name) VALUES ('foo', 'Foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_insert_ignore_into_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::InsertIgnoreInto, "/* ignore duplicates */")
+        .insert_ignore_into("users (login)")
+        .as_string();
+      let expected_query = "/* ignore duplicates */ INSERT IGNORE INTO users (login)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_insert_ignore_into_clause() {
+      let query = sql::Insert::new()
+        .insert_ignore_into("users (login)")
+        .raw_after(sql::InsertClause::InsertIgnoreInto, "values ('foo')")
+        .as_string();
+      let expected_query = "INSERT IGNORE INTO users (login) values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_validate_should_return_an_error_when_insert_into_is_also_defined() {
+      let insert = sql::Insert::new()
+        .insert_into("users (login)")
+        .insert_ignore_into("users (login)")
+        .values("('foo')");
+      let expected = sql::BuildError::ConflictingClauses {
+        clause: sql::Clause::Insert(sql::InsertClause::InsertInto),
+        other: sql::Clause::Insert(sql::InsertClause::InsertIgnoreInto),
+      };
+
+      assert_eq!(insert.validate(), Err(expected));
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod on_duplicate_key_update_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_on_duplicate_key_update_should_add_the_on_duplicate_key_update_clause() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = VALUES(name)")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE name = VALUES(name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_on_duplicate_key_update_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = VALUES(name)")
+        .on_duplicate_key_update("updated_at = now()")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE name = VALUES(name), updated_at = now()";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_on_duplicate_key_update_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("  count = count + 1  ")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE count = count + 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_on_duplicate_key_update_should_be_after_values_clause() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = VALUES(name)")
+        .insert_into("users (login, name)")
+        .values("('foo', 'Foo')")
+        .as_string();
+      let expected_query = "\
+        INSERT INTO users (login, name) \
+        VALUES ('foo', 'Foo') \
+        ON DUPLICATE KEY UPDATE name = VALUES(name)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_on_duplicate_key_update_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::OnDuplicateKeyUpdate, "values ('foo')")
+        .on_duplicate_key_update("login = VALUES(login)")
+        .as_string();
+      let expected_query = "values ('foo') ON DUPLICATE KEY UPDATE login = VALUES(login)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_on_duplicate_key_update_clause() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("login = VALUES(login)")
+        .raw_after(sql::InsertClause::OnDuplicateKeyUpdate, "/* upsert */")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE login = VALUES(login) /* upsert */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod join_clause {
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_inner_join_should_add_the_join_after_the_update_clause() {
+      let query = sql::Update::new()
+        .set("o.active = u.active")
+        .inner_join("users u ON u.login = o.owner_login")
+        .update("orders o")
+        .as_string();
+      let expected_query = "\
+        UPDATE orders o \
+        INNER JOIN users u ON u.login = o.owner_login \
+        SET o.active = u.active\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn methods_join_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Update::new()
+        .cross_join("addresses")
+        .left_join("orders ON orders.login = users.login")
+        .right_join("groups ON groups.id = users.group_id")
+        .as_string();
+      let expected_query = "\
+        CROSS JOIN addresses \
+        LEFT JOIN orders ON orders.login = users.login \
+        RIGHT JOIN groups ON groups.id = users.group_id\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_inner_join_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Update::new()
+        .inner_join("users ON users.id = orders.user_id")
+        .inner_join("users ON users.id = orders.user_id")
+        .as_string();
+      let expected_query = "INNER JOIN users ON users.id = orders.user_id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_join_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::Join, "update orders")
+        .inner_join("users ON users.id = orders.user_id")
+        .as_string();
+      let expected_query = "update orders INNER JOIN users ON users.id = orders.user_id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_join_clause() {
+      let query = sql::Update::new()
+        .inner_join("users ON users.id = orders.user_id")
+        .raw_after(sql::UpdateClause::Join, "set orders.active = false")
+        .as_string();
+      let expected_query = "INNER JOIN users ON users.id = orders.user_id set orders.active = false";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod order_by_and_limit_clauses {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_order_by_should_add_the_order_by_clause() {
+      let query = sql::Delete::new().order_by("id desc").order_by("login").as_string();
+      let expected_query = "ORDER BY id desc, login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clauses_order_by_and_limit_should_be_after_where_clause() {
+      let query = sql::Delete::new()
+        .limit("10")
+        .order_by("created_at")
+        .where_clause("active = false")
+        .delete_from("users")
+        .as_string();
+      let expected_query = "DELETE FROM users WHERE active = false ORDER BY created_at LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_order_by_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::OrderBy, "where active = false")
+        .order_by("id")
+        .as_string();
+      let expected_query = "where active = false ORDER BY id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_order_by_clause() {
+      let query = sql::Delete::new()
+        .order_by("id")
+        .raw_after(sql::DeleteClause::OrderBy, "limit 1")
+        .as_string();
+      let expected_query = "ORDER BY id limit 1";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_order_by_should_add_the_order_by_clause() {
+      let query = sql::Update::new().order_by("id desc").order_by("login").as_string();
+      let expected_query = "ORDER BY id desc, login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clauses_order_by_and_limit_should_be_after_where_clause() {
+      let query = sql::Update::new()
+        .limit("10")
+        .order_by("created_at")
+        .where_clause("active = true")
+        .set("active = false")
+        .update("users")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false WHERE active = true ORDER BY created_at LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_order_by_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::OrderBy, "where active = true")
+        .order_by("id")
+        .as_string();
+      let expected_query = "where active = true ORDER BY id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_limit_clause() {
+      let query = sql::Update::new()
+        .limit("1")
+        .raw_after(sql::UpdateClause::Limit, "/* one row */")
+        .as_string();
+      let expected_query = "LIMIT 1 /* one row */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(all(feature = "mysql", not(feature = "postgresql")))]
+mod limit_offset_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn methods_limit_and_offset_should_be_rendered_as_limit_offset_count() {
+      let query = sql::Select::new()
+        .select("*")
+        .from("users")
+        .offset("20")
+        .limit("10")
+        .as_string();
+      let expected_query = "SELECT * FROM users LIMIT 20, 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_limit_without_offset_should_keep_the_limit_clause() {
+      let query = sql::Select::new().select("*").from("users").limit("10").as_string();
+      let expected_query = "SELECT * FROM users LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_offset_should_be_kept_after_the_limit_clause() {
+      let query = sql::Select::new()
+        .limit("10")
+        .offset("20")
+        .raw_after(sql::SelectClause::Offset, "/* page 3 */")
+        .as_string();
+      let expected_query = "LIMIT 20, 10 /* page 3 */";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_offset_without_limit_should_render_the_largest_limit() {
+      let query = sql::Select::new().select("*").from("users").offset(10).as_string();
+      let expected_query = "SELECT * FROM users LIMIT 10, 18446744073709551615";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_target_table_should_return_the_table_of_the_insert_ignore_into_clause() {
+    let insert = sql::Insert::new().insert_ignore_into("users (login)");
+
+    assert_eq!(insert.target_table().unwrap(), "users");
+  }
+
+  #[test]
+  fn getters_should_return_the_mysql_clauses() {
+    let update = sql::Update::new()
+      .update("orders o")
+      .inner_join("users u ON u.login = o.owner_login")
+      .set("o.active = u.active")
+      .order_by("o.id");
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_duplicate_key_update("login = VALUES(login)");
+
+    assert_eq!(update.join_items(), ["INNER JOIN users u ON u.login = o.owner_login"]);
+    assert_eq!(update.order_by_items(), ["o.id"]);
+    assert_eq!(insert.on_duplicate_key_update_items(), ["login = VALUES(login)"]);
+  }
+}
+
+#[cfg(all(feature = "mysql", not(feature = "postgresql")))]
+mod ident_quoting {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifiers_should_be_quoted_with_backticks_when_rendered_without_a_dialect() {
+    let query = sql::Select::new()
+      .select(sql::Ident::new("userId"))
+      .from(sql::Ident::path(["shop", "order"]))
+      .as_string();
+    let expected_query = "SELECT `userId` FROM `shop`.`order`";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(all(feature = "mysql", not(feature = "postgresql")))]
+mod literal_escaping {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn literals_should_follow_mysql_when_rendered_without_a_dialect() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("files")
+      .where_clause(sql::Sql::from("path = ").push(sql::Literal::from(r"C:\home")))
+      .as_string();
+    let expected_query = r"SELECT * FROM files WHERE path = 'C:\\home'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn display_should_write_the_literal_escaped_like_mysql() {
+    let literal = sql::Literal::from("it's\n");
+
+    assert_eq!(literal.to_string(), r"'it''s\n'");
+  }
+}
+
+#[cfg(all(feature = "mysql", not(feature = "postgresql")))]
+mod placeholders {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_build_should_render_anonymous_placeholders_when_rendered_without_a_dialect() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .and_bind("active = ?", true)
+      .build();
+    let expected_query = "SELECT * FROM users WHERE login = ? AND active = ?";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned()), sql::Value::Bool(true)]);
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod limit_bind_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_limit_of_update_and_delete_should_accept_integers_and_bound_values() {
+    let update = sql::Update::new().update("users").set("active = false").limit(10);
+    let (query, values) = sql::Delete::new()
+      .delete_from("users")
+      .limit_bind(5)
+      .build_with(sql::Dialect::Mysql)
+      .unwrap();
+
+    assert_eq!(update.limit_value().unwrap(), "10");
+    assert_eq!(query, "DELETE FROM users LIMIT ?");
+    assert_eq!(values, vec![sql::Value::Int(5)]);
+  }
+}
@@ -563,6 +563,61 @@ mod with_clause {
       assert_eq!(query, expected_query);
     }
 
+    #[test]
+    fn method_with_should_accept_an_owned_name() {
+      let name = format!("{}_list", "user");
+      let query = sql::Select::new()
+        .with(name, sql::Select::new().select("login").from("users"))
+        .as_string();
+      let expected_query = "WITH user_list AS (SELECT login FROM users)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_into_owned_should_keep_the_with_clause() {
+      let name = String::from("user_list");
+      let select = sql::Select::new()
+        .with(name.as_str(), sql::Select::new().select("login").from("users"))
+        .select("login")
+        .from("user_list")
+        .into_owned();
+      drop(name);
+      let query = select.as_string();
+      let expected_query = "WITH user_list AS (SELECT login FROM users) SELECT login FROM user_list";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn builder_with_the_with_clause_should_be_moved_to_another_thread() {
+      let select = sql::Select::new()
+        .with("user_list", sql::Select::new().select("login").from("users"))
+        .select("login")
+        .from("user_list");
+      let query = std::thread::spawn(move || select.as_string()).join().unwrap();
+      let expected_query = "WITH user_list AS (SELECT login FROM users) SELECT login FROM user_list";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn builder_with_the_with_clause_should_be_shared_between_threads() {
+      let select = std::sync::Arc::new(
+        sql::Select::new()
+          .with(
+            "user_list",
+            sql::Insert::new().insert_into("users (login)").values("('foo')"),
+          )
+          .select("login")
+          .from("user_list"),
+      );
+      let shared = std::sync::Arc::clone(&select);
+      let query = std::thread::spawn(move || shared.as_string()).join().unwrap();
+
+      assert_eq!(query, select.as_string());
+    }
+
     #[test]
     fn method_with_should_add_the_with_clause() {
       let select_users = sql::Select::new().select("login").from("users");
@@ -987,6 +1042,21 @@ mod union_clause {
       assert_eq!(query, expected_query);
     }
 
+    #[test]
+    fn method_into_owned_should_keep_the_union_members() {
+      let limit = String::from("10");
+      let select = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union(sql::Select::new().select("login").from("address").limit(limit.as_str()))
+        .into_owned();
+      drop(limit);
+      let query = select.as_string();
+      let expected_query = "(SELECT login FROM users) UNION (SELECT login FROM address LIMIT 10)";
+
+      assert_eq!(query, expected_query);
+    }
+
     #[test]
     fn method_union_should_accept_inline_argument() {
       let select_users = sql::Select::new().select("login").from("users");
@@ -1050,5 +1120,285 @@ mod union_clause {
 
       assert_eq!(query, expected_query);
     }
+
+    #[test]
+    fn method_validate_should_validate_the_members_of_the_union() {
+      let select = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union(sql::Select::new().from("admins"));
+      let expected = sql::BuildError::MissingClause {
+        clause: sql::Clause::Select(sql::SelectClause::Select),
+        required_by: sql::Clause::Select(sql::SelectClause::From),
+      };
+
+      assert_eq!(select.validate(), Err(expected));
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod concurrently_modifier {
+  mod create_index_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_concurrently_should_add_the_concurrently_modifier() {
+      let query = sql::CreateIndex::new()
+        .create_index("users_login_idx")
+        .concurrently()
+        .on("users")
+        .column("login")
+        .as_string();
+      let expected_query = "CREATE INDEX CONCURRENTLY users_login_idx ON users (login)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn modifier_concurrently_should_be_before_if_not_exists_modifier() {
+      let query = sql::CreateIndex::new()
+        .create_index_if_not_exists("users_login_idx")
+        .unique()
+        .concurrently()
+        .as_string();
+      let expected_query = "CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS users_login_idx";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod drop_index_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_concurrently_should_add_the_concurrently_modifier() {
+      let query = sql::DropIndex::new()
+        .drop_index("users_login_idx")
+        .concurrently()
+        .as_string();
+      let expected_query = "DROP INDEX CONCURRENTLY users_login_idx";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn modifier_concurrently_should_be_before_if_exists_modifier() {
+      let query = sql::DropIndex::new()
+        .concurrently()
+        .drop_index_if_exists("users_login_idx")
+        .as_string();
+      let expected_query = "DROP INDEX CONCURRENTLY IF EXISTS users_login_idx";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod clear_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_clear_should_remove_the_members_of_the_union_clause() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("users_bk"))
+      .except(sql::Select::new().select("login").from("banned"))
+      .clear(sql::SelectClause::Union)
+      .as_string();
+    let expected_query = "(SELECT login FROM users) EXCEPT (SELECT login FROM banned)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_remove_the_with_clause() {
+    let query = sql::Delete::new()
+      .with("archived", sql::Select::new().select("id").from("users_bk"))
+      .delete_from("users")
+      .where_clause("id in (select id from archived)")
+      .returning("id")
+      .clear(sql::DeleteClause::With)
+      .clear(sql::DeleteClause::Returning)
+      .replace_where("active = false")
+      .as_string();
+    let expected_query = "DELETE FROM users WHERE active = false";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_clear_should_reset_the_concurrently_modifier() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .concurrently()
+      .clear(sql::CreateIndexClause::CreateIndex)
+      .create_index("users_email_idx")
+      .on("users")
+      .column("email")
+      .as_string();
+    let expected_query = "CREATE INDEX users_email_idx ON users (email)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_with_ctes_should_return_the_names_in_the_order_they_were_added() {
+    let select = sql::Select::new()
+      .with("active_users", sql::Select::new().select("id").from("users"))
+      .with("archived", sql::Delete::new().delete_from("users").returning("id"))
+      .select("id")
+      .from("active_users");
+    let names = select.with_ctes().collect::<Vec<_>>();
+
+    assert_eq!(names, ["active_users", "archived"]);
+  }
+
+  #[test]
+  fn method_with_ctes_should_be_available_on_the_builders_with_the_with_clause() {
+    let values = sql::Values::new().values("(1)");
+    let insert = sql::Insert::new().with("new_ids", values.clone()).insert_into("ids");
+    let update = sql::Update::new().with("new_ids", values.clone()).update("ids");
+    let delete = sql::Delete::new().with("new_ids", values).delete_from("ids");
+
+    assert_eq!(insert.with_ctes().collect::<Vec<_>>(), ["new_ids"]);
+    assert_eq!(update.with_ctes().collect::<Vec<_>>(), ["new_ids"]);
+    assert_eq!(delete.with_ctes().collect::<Vec<_>>(), ["new_ids"]);
+  }
+
+  #[test]
+  fn method_union_queries_should_return_the_members() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("users_bk"));
+
+    assert_eq!(select.union_queries().len(), 1);
+    assert_eq!(select.union_queries()[0].from_items(), ["users_bk"]);
+    assert!(select.except_queries().is_empty());
+    assert!(select.intersect_queries().is_empty());
+  }
+
+  #[test]
+  fn getters_should_return_the_from_and_returning_clauses() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("login = new.login")
+      .from("new")
+      .returning("id");
+    let delete = sql::Delete::new().delete_from("users").returning("id");
+    let insert = sql::Insert::new().insert_into("users (login)").returning("id");
+
+    assert_eq!(update.from_items(), ["new"]);
+    assert_eq!(update.returning_items(), ["id"]);
+    assert_eq!(delete.returning_items(), ["id"]);
+    assert_eq!(insert.returning_items(), ["id"]);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod ident_quoting {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifiers_should_be_quoted_with_double_quotes_when_rendered_without_a_dialect() {
+    let query = sql::Select::new()
+      .select(sql::Ident::new("userId"))
+      .from(sql::Ident::path(["shop", "order"]))
+      .as_string();
+    let expected_query = r#"SELECT "userId" FROM "shop"."order""#;
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod literal_escaping {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn literals_should_follow_postgres_when_rendered_without_a_dialect() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("files")
+      .where_clause(sql::Sql::from("path = ").push(sql::Literal::from(r"C:\home")))
+      .where_clause(sql::Sql::from("tags && ").push(sql::Literal::array(["a", "b"])))
+      .as_string();
+    let expected_query = r"SELECT * FROM files WHERE path = E'C:\\home' AND tags && ARRAY['a', 'b']";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod lateral_join {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_join_lateral_should_add_the_join_lateral_clause() {
+    let query = sql::Select::new()
+      .from("users u")
+      .join_lateral("(SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true")
+      .as_string();
+    let expected_query = "FROM users u JOIN LATERAL (SELECT total FROM orders WHERE user_id = u.id LIMIT 1) o ON true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_left_join_lateral_should_add_the_left_join_lateral_clause() {
+    let query = sql::Select::new()
+      .from("users u")
+      .left_join_lateral("  (SELECT 1) o ON true  ")
+      .as_string();
+    let expected_query = "FROM users u LEFT JOIN LATERAL (SELECT 1) o ON true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_lateral_should_add_the_lateral_keyword_to_the_structured_join() {
+    let last_order = sql::Select::new()
+      .select("total")
+      .from("orders o")
+      .where_clause("o.user_id = u.id")
+      .limit(1);
+    let query = sql::Select::new()
+      .select("u.login, last.total")
+      .from("users u")
+      .join(sql::Join::left(last_order).lateral().alias("last").on("true"))
+      .as_string();
+    let expected_query = "\
+      SELECT u.login, last.total \
+      FROM users u \
+      LEFT JOIN LATERAL (SELECT total FROM orders o WHERE o.user_id = u.id LIMIT 1) AS last ON true\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn lateral_joins_should_be_rejected_by_sqlite() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users u")
+      .join_lateral("(SELECT 1) o ON true");
+
+    assert!(select.to_sql(sql::Dialect::Sqlite).is_err());
+    assert!(select.to_sql(sql::Dialect::Postgres).is_ok());
   }
 }
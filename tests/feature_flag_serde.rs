@@ -0,0 +1,355 @@
+#[cfg(feature = "serde")]
+mod round_trip {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(builder: &T) -> T {
+    let json = serde_json::to_string(builder).unwrap();
+    serde_json::from_str(&json).unwrap()
+  }
+
+  #[test]
+  fn select_builder_should_be_rendered_equal_after_a_round_trip() {
+    let select = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .inner_join("orders ON orders.user_id = users.id")
+      .where_clause(sql::Condition::or(["login = 'foo'", "login = 'bar'"]))
+      .and(sql::Condition::not("deleted"))
+      .group_by("id")
+      .having("count(orders.id) > 1")
+      .order_by("login")
+      .limit("10")
+      .raw_before(sql::SelectClause::Limit, "/* page */");
+    let deserialized: sql::Select = round_trip(&select);
+
+    assert_eq!(deserialized.as_string(), select.as_string());
+  }
+
+  #[test]
+  fn insert_builder_should_be_rendered_equal_after_a_round_trip() {
+    let insert = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(sql::Select::new().select("login").from("users").limit("5"))
+      .raw_after(sql::InsertClause::InsertInto, "/* backup */");
+    let deserialized: sql::Insert = round_trip(&insert);
+
+    assert_eq!(deserialized.as_string(), insert.as_string());
+  }
+
+  #[test]
+  fn update_delete_and_values_builders_should_be_rendered_equal_after_a_round_trip() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("active = false")
+      .where_clause("login = 'foo'");
+    let delete = sql::Delete::new().delete_from("users").where_clause("active = false");
+    let values = sql::Values::new().values("(1, 'one')").values("(2, 'two')");
+
+    assert_eq!(round_trip(&update).as_string(), update.as_string());
+    assert_eq!(round_trip(&delete).as_string(), delete.as_string());
+    assert_eq!(round_trip(&values).as_string(), values.as_string());
+  }
+
+  #[test]
+  fn ddl_builders_should_be_rendered_equal_after_a_round_trip() {
+    let create_table = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .primary_key("(id)");
+    let alter_table = sql::AlterTable::new().alter_table("users").add_column("age int");
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login");
+    let drop_table = sql::DropTable::new().drop_table("users");
+    let drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+    assert_eq!(round_trip(&create_table).as_string(), create_table.as_string());
+    assert_eq!(round_trip(&alter_table).as_string(), alter_table.as_string());
+    assert_eq!(round_trip(&create_index).as_string(), create_index.as_string());
+    assert_eq!(round_trip(&drop_table).as_string(), drop_table.as_string());
+    assert_eq!(round_trip(&drop_index).as_string(), drop_index.as_string());
+  }
+
+  #[test]
+  fn duplicate_policy_should_be_kept_after_a_round_trip() {
+    let select = sql::Select::new().duplicate_policy(sql::DuplicatePolicy::KeepAll);
+    let deserialized: sql::Select = round_trip(&select);
+    let query = deserialized.select("random()").select("random()").as_string();
+
+    assert_eq!(query, "SELECT random(), random()");
+  }
+
+  #[test]
+  fn repeated_values_of_a_deserialized_builder_should_be_ignored() {
+    let select = sql::Select::new().select("id").from("users");
+    let deserialized: sql::Select = round_trip(&select);
+    let query = deserialized.select("id").as_string();
+
+    assert_eq!(query, "SELECT id FROM users");
+  }
+
+  #[test]
+  fn bound_values_should_be_kept_after_a_round_trip() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .and_bind("age > ?", 18);
+    let deserialized: sql::Select = round_trip(&select);
+
+    assert_eq!(deserialized.build(), select.build());
+  }
+}
+
+#[cfg(feature = "serde")]
+mod json_shape {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn fields_should_be_named_after_the_methods_of_the_builder() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = 'foo'")
+      .limit("10")
+      .raw_after(sql::SelectClause::From, "/* users */");
+    let json = serde_json::to_value(&select).unwrap();
+
+    assert_eq!(json["select"], json!(["id"]));
+    assert_eq!(json["from"], json!(["users"]));
+    assert_eq!(json["where"], json!([{ "Raw": "login = 'foo'" }]));
+    assert_eq!(json["limit"], json!("10"));
+    assert_eq!(json["offset"], json!(""));
+    assert_eq!(json["raw_after"], json!([["From", "/* users */"]]));
+  }
+
+  #[test]
+  fn nested_conditions_should_be_tagged_by_the_operator() {
+    let select = sql::Select::new().where_clause(sql::Condition::or([
+      sql::Condition::raw("a = 1"),
+      sql::Condition::not("b = 2"),
+    ]));
+    let json = serde_json::to_value(&select).unwrap();
+
+    assert_eq!(
+      json["where"],
+      json!([{ "Or": [{ "Raw": "a = 1" }, { "Not": { "Raw": "b = 2" } }] }])
+    );
+  }
+
+  #[test]
+  fn missing_fields_should_be_deserialized_with_the_default_value() {
+    let json = r#"{ "select": ["id"], "from": ["users"], "order_by": ["id desc"] }"#;
+    let select: sql::Select = serde_json::from_str(json).unwrap();
+    let expected_query = "SELECT id FROM users ORDER BY id desc";
+
+    assert_eq!(select.as_string(), expected_query);
+  }
+
+  #[test]
+  fn bound_values_and_identifiers_should_be_tagged_by_the_fragment() {
+    let select = sql::Select::new()
+      .select("*")
+      .from(sql::Ident::path(["public", "users"]))
+      .where_clause_bind("login = ?", "foo")
+      .and(sql::Sql::from("path = ").push(sql::Literal::from("home")));
+    let json = serde_json::to_value(&select).unwrap();
+
+    assert_eq!(json["from"], json!([[{ "ident": ["public", "users"] }]]));
+    assert_eq!(
+      json["where"],
+      json!([
+        { "Raw": ["login = ", { "bind": { "Text": "foo" } }] },
+        { "Raw": ["path = ", { "literal": { "Text": "home" } }] }
+      ])
+    );
+  }
+
+  #[test]
+  fn join_should_be_an_object_with_its_kind() {
+    let select = sql::Select::new().from("users u").join(
+      sql::Join::left(sql::Select::new().select("user_id").from("orders"))
+        .alias("o")
+        .using(["user_id"]),
+    );
+    let json = serde_json::to_value(&select).unwrap();
+
+    assert_eq!(
+      json["join"],
+      json!([{
+        "kind": "Left",
+        "lateral": false,
+        "source": { "select": serde_json::to_value(sql::Select::new().select("user_id").from("orders")).unwrap() },
+        "alias": "o",
+        "constraint": { "using": ["user_id"] }
+      }])
+    );
+  }
+
+  #[test]
+  fn typed_fragments_should_be_deserialized_from_the_documented_shape() {
+    let json = r#"{
+      "select": ["*"],
+      "from": [[{ "ident": ["users"] }]],
+      "where": [{ "Raw": ["login = ", { "bind": { "Text": "foo" } }, " AND id > ", { "literal": { "Int": 5 } }] }]
+    }"#;
+    let select: sql::Select = serde_json::from_str(json).unwrap();
+    let (query, values) = select.build_with(sql::Dialect::Postgres).unwrap();
+
+    assert_eq!(query, r#"SELECT * FROM "users" WHERE login = $1 AND id > 5"#);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+  }
+
+  #[test]
+  fn invalid_fragments_should_be_rejected_by_the_deserialization() {
+    let unknown_tag = r#"{ "where": [{ "Raw": ["a = ", { "marker": "74" }] }] }"#;
+    let empty_ident = r#"{ "from": [[{ "ident": [] }]] }"#;
+    let unknown_value = r#"{ "where": [{ "Raw": ["a = ", { "bind": { "Uuid": "1" } }] }] }"#;
+    let bound_unbound =
+      r#"{ "where": [{ "Raw": [{ "unbound": { "sql": "a = ?", "placeholders": 1, "values": 1 } }] }] }"#;
+
+    assert!(serde_json::from_str::<sql::Select>(unknown_tag).is_err());
+    assert!(serde_json::from_str::<sql::Select>(empty_ident).is_err());
+    assert!(serde_json::from_str::<sql::Select>(unknown_value).is_err());
+    assert!(serde_json::from_str::<sql::Select>(bound_unbound).is_err());
+  }
+
+  #[test]
+  fn texts_with_control_characters_should_be_rendered_as_written() {
+    let json = "{ \"select\": [\"*\"], \"where\": [{ \"Raw\": \"a = '\\u0001x'\" }] }";
+    let select: sql::Select = serde_json::from_str(json).unwrap();
+
+    assert_eq!(select.as_string(), "SELECT * WHERE a = '\u{1}x'");
+  }
+
+  #[test]
+  fn nested_select_of_the_insert_builder_should_be_an_object() {
+    let insert = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(sql::Select::new().select("login").from("users"));
+    let json = serde_json::to_value(&insert).unwrap();
+
+    assert_eq!(json["insert_into"], json!("users_bk (login)"));
+    assert_eq!(json["select"]["select"], json!(["login"]));
+    assert_eq!(json["select"]["from"], json!(["users"]));
+  }
+}
+
+#[cfg(all(feature = "serde", feature = "postgresql"))]
+mod with_and_compound_clauses {
+  use pretty_assertions::assert_eq;
+  use serde_json::json;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn queries_of_the_with_clause_should_be_tagged_by_the_builder() {
+    let select = sql::Select::new()
+      .with("active_users", sql::Select::new().select("id").from("users"))
+      .with("archived", sql::Delete::new().delete_from("users").returning("id"))
+      .select("id")
+      .from("active_users");
+    let json = serde_json::to_value(&select).unwrap();
+
+    assert_eq!(json["with"][0]["name"], json!("active_users"));
+    assert_eq!(json["with"][0]["query"]["Select"]["from"], json!(["users"]));
+    assert_eq!(json["with"][1]["name"], json!("archived"));
+    assert_eq!(json["with"][1]["query"]["Delete"]["delete_from"], json!("users"));
+  }
+
+  #[test]
+  fn parsed_queries_of_the_with_clause_should_be_tagged_by_the_builder_of_the_statement() {
+    let select = sql::Select::parse("WITH d AS (DELETE FROM orders RETURNING id) SELECT * FROM d").unwrap();
+    let json = serde_json::to_value(&select).unwrap();
+
+    assert_eq!(json["with"][0]["query"]["Delete"]["delete_from"], json!("orders"));
+  }
+
+  #[test]
+  fn with_clause_should_be_rendered_equal_after_a_round_trip() {
+    let update = sql::Update::new()
+      .with(
+        "new_values",
+        sql::Values::new().values("(1, 'foo')").values("(2, 'bar')"),
+      )
+      .with(
+        "logins",
+        sql::Insert::new()
+          .insert_into("logins (login)")
+          .values("('foo')")
+          .returning("login"),
+      )
+      .update("users")
+      .set("login = new_values.login")
+      .from("new_values");
+    let json = serde_json::to_string(&update).unwrap();
+    let deserialized: sql::Update = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.as_string(), update.as_string());
+  }
+
+  #[test]
+  fn union_members_should_be_rendered_equal_after_a_round_trip() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("users_bk"))
+      .except(sql::Select::new().select("login").from("banned"));
+    let json = serde_json::to_value(&select).unwrap();
+    let deserialized: sql::Select = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(json["union"][0]["from"], json!(["users_bk"]));
+    assert_eq!(deserialized.as_string(), select.as_string());
+  }
+
+  #[test]
+  fn parsed_with_clause_should_be_rendered_equal_after_a_round_trip() {
+    let sql = "WITH ids AS (SELECT id FROM users) SELECT id FROM ids";
+    let select = sql::Select::parse(sql).unwrap();
+    let json = serde_json::to_string(&select).unwrap();
+    let deserialized: sql::Select = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.as_string(), select.as_string());
+  }
+
+  #[test]
+  fn identifiers_should_be_rendered_equal_after_a_round_trip() {
+    let ident = sql::Ident::path(["public", "order"]);
+    let json = serde_json::to_value(&ident).unwrap();
+    let deserialized: sql::Ident = serde_json::from_value(json.clone()).unwrap();
+    let select = sql::Select::new().select("*").from(ident.clone());
+    let select_json = serde_json::to_string(&select).unwrap();
+    let deserialized_select: sql::Select = serde_json::from_str(&select_json).unwrap();
+
+    assert_eq!(json, json!({ "path": ["public", "order"] }));
+    assert_eq!(deserialized, ident);
+    assert_eq!(deserialized_select.as_string(), select.as_string());
+  }
+
+  #[test]
+  fn literals_should_be_equal_after_a_round_trip() {
+    let literal = sql::Literal::array([sql::Literal::from("it's"), sql::Literal::date("2024-01-31")]);
+    let json = serde_json::to_value(&literal).unwrap();
+    let deserialized: sql::Literal = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(json, json!({ "Array": [{ "Text": "it's" }, { "Date": "2024-01-31" }] }));
+    assert_eq!(deserialized, literal);
+  }
+
+  #[test]
+  fn limit_style_should_be_kept_after_a_round_trip() {
+    let select = sql::Select::new()
+      .limit_style(sql::LimitStyle::FetchFirst)
+      .select("*")
+      .from("users")
+      .paginate(2, 10);
+    let json = serde_json::to_value(&select).unwrap();
+    let deserialized: sql::Select = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(json["limit_style"], json!("FetchFirst"));
+    assert_eq!(deserialized.as_string(), select.as_string());
+  }
+}
@@ -0,0 +1,386 @@
+#[cfg(feature = "sqlite")]
+mod insert_or_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_insert_or_should_add_the_insert_or_clause() {
+      let query = sql::Insert::new()
+        .insert_or("replace into users (login, name)")
+        .as_string();
+      let expected_query = "INSERT OR replace into users (login, name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_or_should_override_value_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .insert_or("replace into users (login, name)")
+        .insert_or("ignore into users (login, name)")
+        .as_string();
+      let expected_query = "INSERT OR ignore into users (login, name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_or_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new().insert_or("  ignore into users  ").as_string();
+      let expected_query = "INSERT OR ignore into users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_values_should_be_after_insert_or_clause() {
+      let query = sql::Insert::new()
+        .values("('foo', 'Foo')")
+        .insert_or("replace into users (login, name)")
+        .as_string();
+      let expected_query = "INSERT OR replace into users (login, name) VALUES ('foo', 'Foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_insert_or_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::InsertOr, "/* insert or replace */")
+        .insert_or("replace into users (login)")
+        .as_string();
+      let expected_query = "/* insert or replace */ INSERT OR replace into users (login)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_insert_or_clause() {
+      let query = sql::Insert::new()
+        .insert_or("replace into users (login)")
+        .raw_after(sql::InsertClause::InsertOr, "values ('foo')")
+        .as_string();
+      let expected_query = "INSERT OR replace into users (login) values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_on_conflict_should_write_an_upsert() {
+      let query = sql::Insert::new()
+        .insert_into("users (login, name)")
+        .values("('foo', 'Foo')")
+        .on_conflict("(login) do update set name = excluded.name")
+        .returning("id")
+        .as_string();
+      let expected_query = "\
+        INSERT INTO users (login, name) \
+        VALUES ('foo', 'Foo') \
+        ON CONFLICT (login) do update set name = excluded.name \
+        RETURNING id\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod limit_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_limit_should_add_the_limit_clause() {
+      let query = sql::Delete::new().limit("10").as_string();
+      let expected_query = "LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_limit_should_override_the_current_value() {
+      let query = sql::Delete::new().limit("10").limit("20").as_string();
+      let expected_query = "LIMIT 20";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_limit_should_trim_space_of_the_argument() {
+      let query = sql::Delete::new().limit("  10  ").as_string();
+      let expected_query = "LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_limit_should_be_after_returning_clause() {
+      let query = sql::Delete::new()
+        .limit("10")
+        .delete_from("users")
+        .where_clause("active = false")
+        .returning("id")
+        .as_string();
+      let expected_query = "DELETE FROM users WHERE active = false RETURNING id LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_limit_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::Limit, "order by id")
+        .limit("10")
+        .as_string();
+      let expected_query = "order by id LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_limit_clause() {
+      let query = sql::Delete::new()
+        .limit("10")
+        .raw_after(sql::DeleteClause::Limit, "offset 5")
+        .as_string();
+      let expected_query = "LIMIT 10 offset 5";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_validate_should_return_an_error_when_the_limit_is_not_a_number() {
+      let delete = sql::Delete::new().delete_from("users").limit("ten");
+      let expected = sql::BuildError::InvalidValue {
+        clause: sql::Clause::Delete(sql::DeleteClause::Limit),
+        value: "ten".to_owned(),
+      };
+
+      assert_eq!(delete.validate(), Err(expected));
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_limit_should_add_the_limit_clause() {
+      let query = sql::Update::new().limit("10").as_string();
+      let expected_query = "LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_limit_should_override_the_current_value() {
+      let query = sql::Update::new().limit("10").limit("20").as_string();
+      let expected_query = "LIMIT 20";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_limit_should_be_after_returning_clause() {
+      let query = sql::Update::new()
+        .limit("10")
+        .update("users")
+        .set("active = false")
+        .returning("id")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false RETURNING id LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_limit_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::Limit, "order by id")
+        .limit("10")
+        .as_string();
+      let expected_query = "order by id LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_limit_clause() {
+      let query = sql::Update::new()
+        .limit("10")
+        .raw_after(sql::UpdateClause::Limit, "offset 5")
+        .as_string();
+      let expected_query = "LIMIT 10 offset 5";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod returning_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_returning_should_add_the_returning_clause() {
+    let delete = sql::Delete::new().delete_from("users").returning("id").as_string();
+    let insert = sql::Insert::new().insert_into("users").returning("id").as_string();
+    let update = sql::Update::new().update("users").returning("id").as_string();
+
+    assert_eq!(delete, "DELETE FROM users RETURNING id");
+    assert_eq!(insert, "INSERT INTO users RETURNING id");
+    assert_eq!(update, "UPDATE users RETURNING id");
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod with_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_with_should_add_the_with_clause() {
+    let logins = sql::Select::new().select("login").from("users");
+    let query = sql::Select::new()
+      .with("logins", logins)
+      .select("*")
+      .from("logins")
+      .as_string();
+    let expected_query = "WITH logins AS (SELECT login FROM users) SELECT * FROM logins";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(all(feature = "sqlite", not(feature = "postgresql"), not(feature = "mysql")))]
+mod compound_select {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_union_should_not_wrap_the_selects_in_parentheses() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("admins"))
+      .as_string();
+    let expected_query = "SELECT login FROM users UNION SELECT login FROM admins";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_except_and_intersect_should_not_wrap_the_selects_in_parentheses() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .except(sql::Select::new().select("login").from("banned"))
+      .intersect(sql::Select::new().select("login").from("active"))
+      .as_string();
+    let expected_query = "\
+      SELECT login FROM users \
+      EXCEPT SELECT login FROM banned \
+      INTERSECT SELECT login FROM active\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_and_raw_after_should_surround_the_compound_select() {
+    let query = sql::Select::new()
+      .select("login")
+      .from("users")
+      .raw_before(sql::SelectClause::Union, "/* before */")
+      .union(sql::Select::new().select("login").from("admins"))
+      .raw_after(sql::SelectClause::Union, "/* after */")
+      .as_string();
+    let expected_query = "\
+      SELECT login FROM users /* before */ \
+      UNION SELECT login FROM admins \
+      /* after */\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_target_table_should_return_the_table_of_the_insert_or_clause() {
+    let insert = sql::Insert::new().insert_or("REPLACE INTO users (login, name)");
+
+    assert_eq!(insert.target_table().unwrap(), "users");
+  }
+
+  #[test]
+  fn method_limit_value_should_return_the_limit_of_update_and_delete() {
+    let update = sql::Update::new().update("users").set("active = false").limit("10");
+    let delete = sql::Delete::new().delete_from("users").limit("5");
+
+    assert_eq!(update.limit_value().unwrap(), "10");
+    assert_eq!(delete.limit_value().unwrap(), "5");
+  }
+}
+
+#[cfg(all(feature = "sqlite", not(feature = "postgresql"), not(feature = "mysql")))]
+mod offset_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_offset_without_limit_should_render_a_negative_limit() {
+      let query = sql::Select::new().select("*").from("users").offset(10).as_string();
+      let expected_query = "SELECT * FROM users LIMIT -1 OFFSET 10";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(all(feature = "sqlite", not(feature = "postgresql"), not(feature = "mysql")))]
+mod literal_escaping {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn literals_should_follow_sqlite_when_rendered_without_a_dialect() {
+    let query = sql::Update::new()
+      .update("users")
+      .set(sql::Sql::from("active = ").push(sql::Literal::from(true)))
+      .set(sql::Sql::from("path = ").push(sql::Literal::from(r"C:\home")))
+      .as_string();
+    let expected_query = r"UPDATE users SET active = 1, path = 'C:\home'";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+#[cfg(all(feature = "sqlite", not(feature = "postgresql"), not(feature = "mysql")))]
+mod placeholders {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_build_should_render_numbered_question_marks_when_rendered_without_a_dialect() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .and_bind("active = ?", true)
+      .build();
+    let expected_query = "SELECT * FROM users WHERE login = ?1 AND active = ?2";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned()), sql::Value::Bool(true)]);
+  }
+}
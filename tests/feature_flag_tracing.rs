@@ -0,0 +1,74 @@
+#[cfg(feature = "tracing")]
+mod tracing_sink {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+  use std::sync::{Arc, Mutex};
+  use tracing::{field, span, Event, Level, Metadata, Subscriber};
+
+  #[derive(Clone, Default)]
+  struct Events(Arc<Mutex<Vec<(Level, String, String)>>>);
+
+  struct Message(String);
+
+  impl field::Visit for Message {
+    fn record_debug(&mut self, field: &field::Field, value: &dyn std::fmt::Debug) {
+      if field.name() == "message" {
+        self.0 = format!("{value:?}");
+      }
+    }
+  }
+
+  impl Subscriber for Events {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+      true
+    }
+
+    fn new_span(&self, _span: &span::Attributes) -> span::Id {
+      span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event) {
+      let mut message = Message(String::new());
+      event.record(&mut message);
+      let metadata = event.metadata();
+      let entry = (*metadata.level(), metadata.target().to_owned(), message.0);
+      self.0.lock().unwrap().push(entry);
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+  }
+
+  #[test]
+  fn method_print_to_should_write_events_with_the_level_of_the_sink() {
+    let events = Events::default();
+
+    tracing::subscriber::with_default(events.clone(), || {
+      sql::Delete::new()
+        .delete_from("users")
+        .print_to(&mut sql::TracingSink::new(Level::WARN))
+        .where_clause("id = 1")
+        .print_to(&mut sql::TracingSink::new(Level::DEBUG));
+    });
+
+    let expected = vec![
+      (
+        Level::WARN,
+        "sql_query_builder".to_owned(),
+        "DELETE FROM users".to_owned(),
+      ),
+      (
+        Level::DEBUG,
+        "sql_query_builder".to_owned(),
+        "DELETE FROM users WHERE id = 1".to_owned(),
+      ),
+    ];
+
+    assert_eq!(*events.0.lock().unwrap(), expected);
+  }
+}
@@ -0,0 +1,145 @@
+mod format_options {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn select<'a>() -> sql::Select<'a> {
+    sql::Select::new()
+      .select("id, login, name")
+      .from("users")
+      .where_clause("login = 'select from where'")
+      .order_by("name asc")
+  }
+
+  #[test]
+  fn default_options_should_render_the_same_output_of_as_string() {
+    let options = sql::FormatOptions::default();
+
+    assert_eq!(select().to_string_with(&options), select().as_string());
+  }
+
+  #[test]
+  fn keyword_case_lower_should_not_change_quotes_comments_and_qualified_names() {
+    let options = sql::FormatOptions::new().keyword_case(sql::KeywordCase::Lower);
+    let query = sql::Select::new()
+      .raw("/* SELECT ALL */")
+      .select("u.\"ORDER\", t.FROM")
+      .from("users u")
+      .where_clause("u.login = 'WHERE' AND u.active IS NOT NULL")
+      .to_string_with(&options);
+    let expected_query =
+      "/* SELECT ALL */ select u.\"ORDER\", t.FROM from users u where u.login = 'WHERE' and u.active is not null";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn keyword_case_upper_should_convert_the_keywords_of_the_arguments() {
+    let options = sql::FormatOptions::new().keyword_case(sql::KeywordCase::Upper);
+    let query = select().to_string_with(&options);
+    let expected_query = "SELECT id, login, name FROM users WHERE login = 'select from where' ORDER BY name ASC";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn line_breaks_should_render_each_clause_in_its_own_line_without_trailing_spaces() {
+    let options = sql::FormatOptions::new().line_breaks(true);
+    let query = select().to_string_with(&options);
+    let expected_query = "\
+      SELECT id, login, name\n\
+      FROM users\n\
+      WHERE login = 'select from where'\n\
+      ORDER BY name asc\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn line_breaks_should_keep_the_trailing_spaces_of_the_lines_inside_quotes() {
+    let options = sql::FormatOptions::new().line_breaks(true).max_width(16);
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("bio = 'first line   \nsecond line'")
+      .to_string_with(&options);
+    let expected_query = "SELECT id\nFROM users\nWHERE bio =\n  'first line   \nsecond line'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn indent_should_define_the_width_of_the_nested_lines() {
+    let options = sql::FormatOptions::new().line_breaks(true).indent(4);
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .column("login varchar(40)")
+      .to_string_with(&options);
+    let expected_query = "CREATE TABLE users (\n    id serial,\n    login varchar(40)\n)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn indent_should_be_ignored_without_line_breaks() {
+    let options = sql::FormatOptions::new().indent(4);
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .to_string_with(&options);
+
+    assert_eq!(query, "CREATE TABLE users (id serial)");
+  }
+
+  #[test]
+  fn max_width_should_break_the_lists_after_the_commas() {
+    let options = sql::FormatOptions::new().line_breaks(true).max_width(16);
+    let query = sql::Select::new()
+      .select("id, login, name, email")
+      .from("users")
+      .to_string_with(&options);
+    let expected_query = "SELECT id,\n  login, name,\n  email\nFROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn max_width_with_leading_commas_should_begin_the_next_line_with_the_comma() {
+    let options = sql::FormatOptions::new()
+      .line_breaks(true)
+      .max_width(16)
+      .comma_style(sql::CommaStyle::Leading);
+    let query = sql::Select::new()
+      .select("id, login, name, email")
+      .from("users")
+      .to_string_with(&options);
+    let expected_query = "SELECT id, login\n  , name, email\nFROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn max_width_should_prefer_the_breaks_outside_parentheses() {
+    let options = sql::FormatOptions::new().max_width(30);
+    let query = sql::Select::new()
+      .select("coalesce(name, login), id")
+      .from("users")
+      .to_string_with(&options);
+    let expected_query = "SELECT coalesce(name, login),\n  id FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn max_width_should_not_break_quotes_and_words_longer_than_the_width() {
+    let options = sql::FormatOptions::new().max_width(10);
+    let query = sql::Select::new()
+      .select("'a, b c, d'")
+      .from("a_very_long_table_name")
+      .to_string_with(&options);
+    let expected_query = "SELECT\n  'a, b c, d'\n  FROM\n  a_very_long_table_name";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,102 @@
+mod highlight {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  const KEYWORD: &str = "\x1b[34;1m";
+  const LITERAL: &str = "\x1b[33m";
+  const PLACEHOLDER: &str = "\x1b[0;1m";
+  const COMMENT: &str = "\x1b[32;2m";
+  const RESET: &str = "\x1b[0m";
+
+  struct Colored(String);
+
+  impl sql::Sink for Colored {
+    fn is_colored(&self) -> bool {
+      true
+    }
+
+    fn write_query(&mut self, query: &str) {
+      self.0.push_str(query);
+    }
+  }
+
+  fn highlighted(select: sql::Select) -> String {
+    let mut sink = Colored(String::new());
+    select.debug_to(&mut sink);
+    sink.0
+  }
+
+  #[test]
+  fn keywords_inside_string_literals_should_not_be_highlighted() {
+    let select = sql::Select::new().select("'SELECT FROM'").from("users");
+    let output = highlighted(select);
+
+    assert!(output.contains(&format!("{LITERAL}'SELECT FROM'{RESET}")));
+    assert!(output.contains(&format!("{KEYWORD}SELECT{RESET} {LITERAL}")));
+  }
+
+  #[test]
+  fn keywords_inside_identifiers_should_not_be_highlighted() {
+    let select = sql::Select::new()
+      .select("order_id, \"from\", u.select")
+      .from("users u");
+    let output = highlighted(select);
+
+    assert!(output.contains(" order_id, \"from\", u.select "));
+  }
+
+  #[test]
+  fn placeholders_with_more_than_one_digit_should_be_highlighted_as_a_whole() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("id in ($9, $10, $123)");
+    let output = highlighted(select);
+    let expected = format!("({PLACEHOLDER}$9{RESET}, {PLACEHOLDER}$10{RESET}, {PLACEHOLDER}$123{RESET})");
+
+    assert!(output.contains(&expected));
+  }
+
+  #[test]
+  fn comments_should_be_highlighted_without_highlighting_its_content() {
+    let select = sql::Select::new().raw("/* select from */").select("id");
+    let output = highlighted(select);
+
+    assert!(output.contains(&format!("{COMMENT}/* select from */{RESET}")));
+  }
+
+  #[test]
+  fn the_debug_format_should_be_highlighted_only_when_the_standard_output_accepts_colors() {
+    use std::io::IsTerminal;
+
+    let select = sql::Select::new().select("id").from("users").where_clause("login = $1");
+    let output = format!("{select:?}");
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| value.is_empty() == false);
+    let colored = std::io::stdout().is_terminal() && no_color == false;
+    let plain = [KEYWORD, LITERAL, PLACEHOLDER, COMMENT, RESET]
+      .iter()
+      .fold(output.clone(), |acc, code| acc.replace(code, ""));
+    let hr = "-- ------------------------------------------------------------------------------";
+    let expected = format!("\n{hr}\nSELECT id \nFROM users \nWHERE login = $1\n{hr}\n");
+
+    assert_eq!(output.contains("\x1b["), colored);
+    assert_eq!(plain, expected);
+  }
+
+  #[test]
+  fn the_output_without_the_color_codes_should_be_the_multiline_query() {
+    let select = sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = $1")
+      .limit("10");
+    let output = highlighted(select);
+    let plain = [KEYWORD, LITERAL, PLACEHOLDER, COMMENT, RESET]
+      .iter()
+      .fold(output, |acc, code| acc.replace(code, ""));
+    let hr = "-- ------------------------------------------------------------------------------";
+    let expected = format!("\n{hr}\nSELECT id \nFROM users \nWHERE login = $1 \nLIMIT 10\n{hr}\n");
+
+    assert_eq!(plain, expected);
+  }
+}
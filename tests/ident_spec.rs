@@ -0,0 +1,145 @@
+mod ident_quoting {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn identifiers_should_be_quoted_with_double_quotes_in_postgres_and_sqlite() {
+    let select = sql::Select::new()
+      .select(sql::Ident::new("userId"))
+      .from(sql::Ident::new("order"));
+    let expected_query = r#"SELECT "userId" FROM "order""#;
+
+    assert_eq!(select.to_sql(sql::Dialect::Postgres).unwrap(), expected_query);
+    assert_eq!(select.to_sql(sql::Dialect::Sqlite).unwrap(), expected_query);
+  }
+
+  #[test]
+  fn identifiers_should_be_quoted_with_backticks_in_mysql() {
+    let select = sql::Select::new()
+      .select(sql::Ident::new("userId"))
+      .from(sql::Ident::new("order"));
+    let expected_query = "SELECT `userId` FROM `order`";
+
+    assert_eq!(select.to_sql(sql::Dialect::Mysql).unwrap(), expected_query);
+  }
+
+  #[test]
+  fn quote_characters_inside_the_identifier_should_be_escaped_by_doubling_it() {
+    let delete = sql::Delete::new().delete_from(sql::Ident::new(r#"we"ird`name"#));
+
+    assert_eq!(
+      delete.to_sql(sql::Dialect::Postgres).unwrap(),
+      r#"DELETE FROM "we""ird`name""#
+    );
+    assert_eq!(
+      delete.to_sql(sql::Dialect::Mysql).unwrap(),
+      r#"DELETE FROM `we"ird``name`"#
+    );
+  }
+
+  #[test]
+  fn each_name_of_a_path_should_be_quoted_separately() {
+    let update = sql::Update::new()
+      .update(sql::Ident::path(["sales", "order"]))
+      .set("status = 'paid'");
+
+    assert_eq!(
+      update.to_sql(sql::Dialect::Postgres).unwrap(),
+      r#"UPDATE "sales"."order" SET status = 'paid'"#
+    );
+    assert_eq!(
+      update.to_sql(sql::Dialect::Mysql).unwrap(),
+      "UPDATE `sales`.`order` SET status = 'paid'"
+    );
+  }
+
+  #[test]
+  fn dots_inside_a_single_name_should_be_kept_inside_the_quotes() {
+    let ident = sql::Ident::new("my.table");
+
+    assert_eq!(ident.to_sql(sql::Dialect::Postgres), r#""my.table""#);
+  }
+
+  #[test]
+  fn schema_table_and_column_paths_should_be_accepted_by_the_select_clause() {
+    let select = sql::Select::new()
+      .select(sql::Ident::path(["public", "users", "id"]))
+      .from(sql::Ident::path(["public", "users"]));
+
+    assert_eq!(
+      select.to_sql(sql::Dialect::Sqlite).unwrap(),
+      r#"SELECT "public"."users"."id" FROM "public"."users""#
+    );
+  }
+
+  #[test]
+  fn method_insert_into_should_accept_an_identifier() {
+    let insert = sql::Insert::new().insert_into(sql::Ident::new("order")).values("(1)");
+
+    assert_eq!(
+      insert.to_sql(sql::Dialect::Postgres).unwrap(),
+      r#"INSERT INTO "order" VALUES (1)"#
+    );
+  }
+
+  #[test]
+  fn identifiers_should_be_composed_with_other_texts() {
+    let select = sql::Select::new()
+      .select("*")
+      .from(sql::Sql::from(sql::Ident::new("user")).push(" u"))
+      .where_clause_bind(sql::Sql::from("u.").push(sql::Ident::new("userId")).push(" = ?"), 1);
+
+    assert_eq!(
+      select.to_sql(sql::Dialect::Postgres).unwrap(),
+      r#"SELECT * FROM "user" u WHERE u."userId" = $1"#
+    );
+    assert_eq!(
+      select.to_sql(sql::Dialect::Mysql).unwrap(),
+      "SELECT * FROM `user` u WHERE u.`userId` = ?"
+    );
+  }
+
+  #[test]
+  fn identifiers_should_not_be_mistaken_by_placeholders() {
+    let (query, values) = sql::Select::new()
+      .select(sql::Ident::new("a?b"))
+      .from(sql::Ident::new("$1"))
+      .where_clause_bind("id = ?", 10)
+      .build_with(sql::Dialect::Postgres)
+      .unwrap();
+
+    assert_eq!(query, r#"SELECT "a?b" FROM "$1" WHERE id = $1"#);
+    assert_eq!(values, vec![sql::Value::Int(10)]);
+  }
+
+  #[test]
+  fn identifiers_with_control_characters_should_be_quoted_as_written() {
+    let query = sql::Select::new()
+      .select("*")
+      .from(sql::Ident::new("odd\u{1}name\u{2}"))
+      .where_clause_bind(sql::Sql::from(sql::Ident::new("a?b")).push(" = ?"), 1)
+      .to_sql(sql::Dialect::Mysql)
+      .unwrap();
+
+    assert_eq!(query, "SELECT * FROM `odd\u{1}name\u{2}` WHERE `a?b` = ?");
+  }
+
+  #[test]
+  fn identifiers_should_be_kept_as_typed_values_by_the_getters() {
+    let select = sql::Select::new().select(sql::Ident::new("userId"));
+
+    assert_eq!(select.select_items(), [sql::Sql::from(sql::Ident::new("userId"))]);
+  }
+
+  #[test]
+  fn identifiers_of_the_composed_builders_should_follow_the_dialect() {
+    let insert = sql::Insert::new()
+      .insert_into("users_bk")
+      .select(sql::Select::new().select("*").from(sql::Ident::new("user")));
+
+    assert_eq!(
+      insert.to_sql(sql::Dialect::Mysql).unwrap(),
+      "INSERT INTO users_bk SELECT * FROM `user`"
+    );
+  }
+}
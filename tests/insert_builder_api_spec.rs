@@ -399,12 +399,12 @@ mod values_clause {
   }
 
   #[test]
-  fn method_values_should_not_accumulate_arguments_with_the_same_content() {
+  fn method_values_should_accumulate_arguments_with_the_same_content() {
     let query = sql::Insert::new()
       .values("('bar', 'Bar')")
       .values("('bar', 'Bar')")
       .as_string();
-    let expected_query = "VALUES ('bar', 'Bar')";
+    let expected_query = "VALUES ('bar', 'Bar'), ('bar', 'Bar')";
 
     assert_eq!(query, expected_query);
   }
@@ -0,0 +1,263 @@
+mod select_getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn getters_should_return_the_values_of_each_clause() {
+    let select = sql::Select::new()
+      .select("u.id")
+      .select("count(o.id)")
+      .from("users u")
+      .inner_join("orders o ON o.user_id = u.id")
+      .where_clause("u.active = true")
+      .group_by("u.id")
+      .having("count(o.id) > 1")
+      .order_by("u.id");
+
+    assert_eq!(select.select_items(), ["u.id", "count(o.id)"]);
+    assert_eq!(select.from_items(), ["users u"]);
+    assert_eq!(select.join_items(), ["INNER JOIN orders o ON o.user_id = u.id"]);
+    assert_eq!(select.where_conditions(), [sql::Condition::raw("u.active = true")]);
+    assert_eq!(select.group_by_items(), ["u.id"]);
+    assert_eq!(select.having_conditions(), [sql::Condition::raw("count(o.id) > 1")]);
+    assert_eq!(select.order_by_items(), ["u.id"]);
+  }
+
+  #[test]
+  fn getters_of_the_clauses_not_defined_should_be_empty() {
+    let select = sql::Select::new();
+    let empty: &[&str] = &[];
+
+    assert_eq!(select.select_items(), empty);
+    assert_eq!(select.from_items(), empty);
+    assert_eq!(select.where_conditions(), &[]);
+    assert_eq!(select.limit_value(), None);
+    assert_eq!(select.offset_value(), None);
+  }
+
+  #[test]
+  fn limit_and_offset_should_be_returned_as_written() {
+    let select = sql::Select::new().select("*").from("users").limit("$1").offset("20");
+
+    assert_eq!(select.limit_value().unwrap(), "$1");
+    assert_eq!(select.offset_value().unwrap(), "20");
+  }
+
+  #[test]
+  fn conditions_joined_by_or_should_be_returned_as_a_single_condition() {
+    let select = sql::Select::new().where_clause("a = 1").or("b = 2");
+
+    assert_eq!(select.where_conditions(), [sql::Condition::or(["a = 1", "b = 2"])]);
+  }
+
+  #[test]
+  fn getters_should_reflect_the_cleared_clauses() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit("10")
+      .clear(sql::SelectClause::Limit);
+
+    assert_eq!(select.limit_value(), None);
+  }
+
+  #[test]
+  fn getters_should_be_used_to_decide_about_the_query() {
+    fn reads_table(select: &sql::Select, table: &str) -> bool {
+      select
+        .from_items()
+        .iter()
+        .any(|item| item.to_string().split_whitespace().next() == Some(table))
+    }
+    let select = sql::Select::new().select("*").from("users u").from("orders o");
+
+    assert!(reads_table(&select, "orders"));
+    assert!(reads_table(&select, "addresses") == false);
+  }
+}
+
+mod insert_getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_target_table_should_return_the_table_without_the_columns() {
+    let insert = sql::Insert::new().insert_into("users (login, name)");
+
+    assert_eq!(insert.target_table().unwrap(), "users");
+  }
+
+  #[test]
+  fn method_target_table_should_return_none_when_the_table_is_not_defined() {
+    let insert = sql::Insert::new().values("('foo')");
+
+    assert_eq!(insert.target_table(), None);
+  }
+
+  #[test]
+  fn getters_should_return_the_rows_and_the_select() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .values("('bar')")
+      .overriding("system value");
+    let insert_select = sql::Insert::new()
+      .insert_into("users_bk")
+      .select(sql::Select::new().select("*").from("users"));
+
+    assert_eq!(insert.values_items(), ["('foo')", "('bar')"]);
+    assert_eq!(insert.overriding_value().unwrap(), "system value");
+    assert_eq!(insert.on_conflict_value(), None);
+    assert!(insert.select_query().is_none());
+    assert_eq!(
+      insert_select
+        .select_query()
+        .map(|select| select.from_items()[0].to_string()),
+      Some("users".to_owned())
+    );
+  }
+}
+
+mod update_and_delete_getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn update_getters_should_return_the_values_of_each_clause() {
+    let update = sql::Update::new()
+      .update("users")
+      .set("login = 'foo'")
+      .set("active = true")
+      .where_clause("id = 1");
+
+    assert_eq!(update.target_table().unwrap(), "users");
+    assert_eq!(update.set_items(), ["login = 'foo'", "active = true"]);
+    assert_eq!(update.where_conditions(), [sql::Condition::raw("id = 1")]);
+  }
+
+  #[test]
+  fn delete_getters_should_return_the_values_of_each_clause() {
+    let delete = sql::Delete::new().delete_from("users").where_clause("active = false");
+
+    assert_eq!(delete.target_table().unwrap(), "users");
+    assert_eq!(delete.where_conditions(), [sql::Condition::raw("active = false")]);
+    assert_eq!(sql::Delete::new().target_table(), None);
+  }
+
+  #[test]
+  fn values_getter_should_return_the_rows() {
+    let values = sql::Values::new().values("(1, 'one')").values("(2, 'two')");
+
+    assert_eq!(values.values_items(), ["(1, 'one')", "(2, 'two')"]);
+  }
+}
+
+mod ddl_getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn create_table_getters_should_return_the_definitions() {
+    let create_table = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .column("login varchar(40)")
+      .primary_key("(id)")
+      .constraint("users_login_key UNIQUE(login)")
+      .foreign_key("(address_id) REFERENCES addresses(id)");
+
+    assert_eq!(create_table.target_table().unwrap(), "users");
+    assert_eq!(create_table.column_items(), ["id serial", "login varchar(40)"]);
+    assert_eq!(create_table.primary_key_value().unwrap(), "(id)");
+    assert_eq!(create_table.constraint_items(), ["users_login_key UNIQUE(login)"]);
+    assert_eq!(
+      create_table.foreign_key_items(),
+      ["(address_id) REFERENCES addresses(id)"]
+    );
+  }
+
+  #[test]
+  fn create_index_getters_should_return_the_index_and_the_table() {
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .where_clause("active = true");
+
+    assert_eq!(create_index.index_name().unwrap(), "users_login_idx");
+    assert_eq!(create_index.target_table().unwrap(), "users");
+    assert_eq!(create_index.column_items(), ["login"]);
+    assert_eq!(create_index.where_conditions(), [sql::Condition::raw("active = true")]);
+  }
+
+  #[test]
+  fn alter_and_drop_getters_should_return_the_tables() {
+    let alter_table = sql::AlterTable::new().alter_table("users").add_column("age int");
+    let drop_table = sql::DropTable::new().drop_table("users").drop_table("orders");
+    let drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+    assert_eq!(alter_table.target_table().unwrap(), "users");
+    assert_eq!(drop_table.drop_table_items(), ["users", "orders"]);
+    assert_eq!(drop_index.drop_index_items(), ["users_login_idx"]);
+  }
+}
+
+mod typed_getters {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn getters_should_return_the_bound_values_apart_from_the_text() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .limit_bind(10);
+    let update = sql::Update::new().update("users").set_bind("active = ?", true);
+
+    let sql::Condition::Raw(condition) = &select.where_conditions()[0] else {
+      panic!("expected a raw condition");
+    };
+    assert_eq!(condition.to_string(), "login = ?");
+    assert_eq!(
+      condition.values().collect::<Vec<_>>(),
+      [&sql::Value::Text("foo".to_owned())]
+    );
+    assert_eq!(select.limit_value().unwrap().to_string(), "?");
+    assert_eq!(
+      select.limit_value().unwrap().values().collect::<Vec<_>>(),
+      [&sql::Value::Int(10)]
+    );
+    assert_eq!(
+      update.set_items()[0].values().collect::<Vec<_>>(),
+      [&sql::Value::Bool(true)]
+    );
+  }
+
+  #[test]
+  fn getters_should_return_the_identifiers_as_typed_values() {
+    let select = sql::Select::new()
+      .select("*")
+      .from(sql::Ident::path(["public", "order"]));
+    let insert = sql::Insert::new().insert_into(sql::Sql::from(sql::Ident::new("user")).push(" (login)"));
+
+    assert_eq!(
+      select.from_items(),
+      [sql::Sql::from(sql::Ident::path(["public", "order"]))]
+    );
+    assert_eq!(insert.target_table(), Some(sql::Sql::from(sql::Ident::new("user"))));
+  }
+
+  #[test]
+  fn conditions_returned_by_the_getters_should_keep_the_bound_values_when_reused() {
+    let select = sql::Select::new().where_clause_bind("login = ?", "foo");
+    let (query, values) = sql::Delete::new()
+      .delete_from("users")
+      .where_clause(select.where_conditions()[0].clone())
+      .build_with(sql::Dialect::Postgres)
+      .unwrap();
+
+    assert_eq!(query, "DELETE FROM users WHERE login = $1");
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+  }
+}
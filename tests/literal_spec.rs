@@ -0,0 +1,203 @@
+mod text_literal {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn quotes_should_be_escaped_by_doubling_it_in_all_dialects() {
+    let literal = sql::Literal::from("it's");
+
+    assert_eq!(literal.to_sql(sql::Dialect::Postgres), "'it''s'");
+    assert_eq!(literal.to_sql(sql::Dialect::Sqlite), "'it''s'");
+    assert_eq!(literal.to_sql(sql::Dialect::Mysql), "'it''s'");
+  }
+
+  #[test]
+  fn postgres_should_use_the_escape_string_when_the_text_has_backslashes() {
+    let literal = sql::Literal::from(r"a\'b");
+
+    assert_eq!(literal.to_sql(sql::Dialect::Postgres), r"E'a\\''b'");
+  }
+
+  #[test]
+  fn sqlite_should_keep_the_backslashes() {
+    let literal = sql::Literal::from(r"a\b");
+
+    assert_eq!(literal.to_sql(sql::Dialect::Sqlite), r"'a\b'");
+  }
+
+  #[test]
+  fn mysql_should_escape_the_backslashes_and_the_control_characters() {
+    let literal = sql::Literal::from("a\\b\0c\nd\re\u{1a}'");
+
+    assert_eq!(literal.to_sql(sql::Dialect::Mysql), r"'a\\b\0c\nd\re\Z'''");
+  }
+
+  #[test]
+  fn an_injection_attempt_should_be_rendered_as_a_single_text() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause(sql::Sql::from("login = ").push(sql::Literal::from(r"\'; DROP TABLE users; --")));
+
+    assert_eq!(
+      select.to_sql(sql::Dialect::Postgres).unwrap(),
+      r"SELECT * FROM users WHERE login = E'\\''; DROP TABLE users; --'"
+    );
+    assert_eq!(
+      select.to_sql(sql::Dialect::Mysql).unwrap(),
+      r"SELECT * FROM users WHERE login = '\\''; DROP TABLE users; --'"
+    );
+  }
+}
+
+mod value_literals {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn numbers_should_be_rendered_as_written() {
+    assert_eq!(sql::Literal::from(-42).to_sql(sql::Dialect::Postgres), "-42");
+    assert_eq!(sql::Literal::from(1.5).to_sql(sql::Dialect::Mysql), "1.5");
+    assert_eq!(sql::Literal::from(10.0).to_sql(sql::Dialect::Sqlite), "10.0");
+  }
+
+  #[test]
+  fn special_floats_should_be_casted_in_postgres_and_null_in_the_other_dialects() {
+    assert_eq!(
+      sql::Literal::from(f64::NAN).to_sql(sql::Dialect::Postgres),
+      "'NaN'::float8"
+    );
+    assert_eq!(
+      sql::Literal::from(f64::NEG_INFINITY).to_sql(sql::Dialect::Postgres),
+      "'-Infinity'::float8"
+    );
+    assert_eq!(sql::Literal::from(f64::INFINITY).to_sql(sql::Dialect::Mysql), "NULL");
+  }
+
+  #[test]
+  fn booleans_should_be_numbers_in_sqlite() {
+    assert_eq!(sql::Literal::from(true).to_sql(sql::Dialect::Postgres), "TRUE");
+    assert_eq!(sql::Literal::from(false).to_sql(sql::Dialect::Mysql), "FALSE");
+    assert_eq!(sql::Literal::from(true).to_sql(sql::Dialect::Sqlite), "1");
+  }
+
+  #[test]
+  fn none_should_be_rendered_as_null() {
+    let literal = sql::Literal::from(None::<i32>);
+
+    assert_eq!(literal, sql::Literal::Null);
+    assert_eq!(literal.to_sql(sql::Dialect::Sqlite), "NULL");
+  }
+
+  #[test]
+  fn bytes_should_be_hex_encoded() {
+    let literal = sql::Literal::from(vec![0x00_u8, 0xff]);
+
+    assert_eq!(literal.to_sql(sql::Dialect::Postgres), r"E'\\x00ff'::bytea");
+    assert_eq!(literal.to_sql(sql::Dialect::Mysql), "X'00ff'");
+    assert_eq!(literal.to_sql(sql::Dialect::Sqlite), "X'00ff'");
+  }
+
+  #[test]
+  fn dates_should_be_typed_except_in_sqlite() {
+    let date = sql::Literal::date("2024-01-31");
+    let timestamp = sql::Literal::timestamp("2024-01-31 10:30:00");
+
+    assert_eq!(date.to_sql(sql::Dialect::Postgres), "DATE '2024-01-31'");
+    assert_eq!(date.to_sql(sql::Dialect::Sqlite), "'2024-01-31'");
+    assert_eq!(timestamp.to_sql(sql::Dialect::Mysql), "TIMESTAMP '2024-01-31 10:30:00'");
+  }
+
+  #[test]
+  fn arrays_should_render_each_item_with_the_rules_of_the_dialect() {
+    let literal = sql::Literal::array([sql::Literal::from("a'b"), sql::Literal::Null, sql::Literal::from(true)]);
+
+    assert_eq!(literal.to_sql(sql::Dialect::Postgres), "ARRAY['a''b', NULL, TRUE]");
+    assert_eq!(literal.to_sql(sql::Dialect::Sqlite), "('a''b', NULL, 1)");
+  }
+}
+
+mod display {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn display_should_write_the_literal_escaped_like_the_feature_flags() {
+    let literal = sql::Literal::from(r"it's C:\");
+
+    assert_eq!(literal.to_string(), r"E'it''s C:\\'");
+    assert_eq!(format!("{}", sql::Literal::from(true)), "TRUE");
+  }
+
+  #[test]
+  fn literals_composed_with_texts_should_keep_the_dialect_of_the_query() {
+    let condition = sql::Sql::from("active = ").push(sql::Literal::from(true));
+    let select = sql::Select::new().select("*").from("users").where_clause(condition);
+
+    assert_eq!(
+      select.to_sql(sql::Dialect::Sqlite).unwrap(),
+      "SELECT * FROM users WHERE active = 1"
+    );
+    assert_eq!(
+      select.to_sql(sql::Dialect::Postgres).unwrap(),
+      "SELECT * FROM users WHERE active = TRUE"
+    );
+  }
+}
+
+mod builders_with_literals {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn literals_should_follow_the_dialect_of_the_query() {
+    let update = sql::Update::new()
+      .update("users")
+      .set(sql::Sql::from("active = ").push(sql::Literal::from(false)))
+      .where_clause(sql::Sql::from("id IN ").push(sql::Literal::array([1, 2])));
+
+    assert_eq!(
+      update.to_sql(sql::Dialect::Sqlite).unwrap(),
+      "UPDATE users SET active = 0 WHERE id IN (1, 2)"
+    );
+    assert_eq!(
+      update.to_sql(sql::Dialect::Mysql).unwrap(),
+      "UPDATE users SET active = FALSE WHERE id IN (1, 2)"
+    );
+  }
+
+  #[test]
+  fn literals_should_be_accepted_as_values_of_the_clauses() {
+    let select = sql::Select::new().select(sql::Literal::from("it's"));
+
+    assert_eq!(select.to_sql(sql::Dialect::Postgres).unwrap(), "SELECT 'it''s'");
+  }
+
+  #[test]
+  fn literals_should_not_be_mistaken_by_placeholders() {
+    let (query, values) = sql::Insert::new()
+      .insert_into("users (login, note)")
+      .values_bind(
+        sql::Sql::from("(?, ").push(sql::Literal::from("what? $1")).push(")"),
+        "foo",
+      )
+      .build_with(sql::Dialect::Postgres)
+      .unwrap();
+
+    assert_eq!(query, "INSERT INTO users (login, note) VALUES ($1, 'what? $1')");
+    assert_eq!(values.len(), 1);
+  }
+
+  #[test]
+  fn literals_should_be_accepted_by_the_ddl_builders() {
+    let create_table = sql::CreateTable::new()
+      .create_table("users")
+      .column(sql::Sql::from("status varchar(10) DEFAULT ").push(sql::Literal::from("new")));
+
+    assert_eq!(
+      create_table.as_string(),
+      "CREATE TABLE users (status varchar(10) DEFAULT 'new')"
+    );
+  }
+}
@@ -0,0 +1,134 @@
+mod owned_arguments {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn methods_should_accept_owned_strings() {
+    let table = String::from("users");
+    let column = format!("{table}.login");
+    let query = sql::Select::new()
+      .select(column)
+      .from(table)
+      .order_by(String::from("login"))
+      .limit(String::from("10"))
+      .as_string();
+    let expected_query = "SELECT users.login FROM users ORDER BY login LIMIT 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_should_trim_the_owned_strings() {
+    let query = sql::Update::new()
+      .update(String::from("  users  "))
+      .set(String::from(" login = 'foo' "))
+      .as_string();
+    let expected_query = "UPDATE users SET login = 'foo'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn builder_should_be_constructed_from_strings_created_in_a_loop() {
+    let mut select = sql::Select::new().select("*").from("users");
+    for column in ["login", "email"] {
+      select = select.where_clause(format!("{column} is not null"));
+      select = select.order_by(format!("{column} asc"));
+    }
+    let query = select.as_string();
+    let expected_query =
+      "SELECT * FROM users WHERE login is not null AND email is not null ORDER BY login asc, email asc";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn methods_with_bound_values_should_accept_owned_strings() {
+    let column = "login";
+    let (query, values) = sql::Delete::new()
+      .delete_from(String::from("users"))
+      .where_clause_bind(format!("{column} = ?"), "foo")
+      .build();
+    let expected_query = "DELETE FROM users WHERE login = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Text("foo".to_owned())]);
+  }
+}
+
+mod into_owned_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  struct Repository {
+    query: sql::Select<'static>,
+  }
+
+  fn users_page(limit: &str) -> sql::Select<'static> {
+    sql::Select::new().select("id").from("users").limit(limit).into_owned()
+  }
+
+  #[test]
+  fn builder_should_be_returned_from_functions() {
+    let limit = String::from("10");
+    let query = users_page(&limit).as_string();
+    let expected_query = "SELECT id FROM users LIMIT 10";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn builder_should_be_stored_in_structs() {
+    let table = String::from("users");
+    let repository = Repository {
+      query: sql::Select::new().select("*").from(table.as_str()).into_owned(),
+    };
+    drop(table);
+    let query = repository.query.where_clause("id = 1").as_string();
+    let expected_query = "SELECT * FROM users WHERE id = 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_into_owned_should_keep_the_nested_select_of_the_insert_builder() {
+    let limit = String::from("5");
+    let insert = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(sql::Select::new().select("login").from("users").limit(limit.as_str()))
+      .into_owned();
+    drop(limit);
+    let query = insert.as_string();
+    let expected_query = "INSERT INTO users_bk (login) SELECT login FROM users LIMIT 5";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_into_owned_should_be_available_on_the_builders_with_borrowed_texts() {
+    let name = String::from("users");
+    let update = sql::Update::new().update(&name).set("active = false").into_owned();
+    let delete = sql::Delete::new().delete_from(&name).into_owned();
+    let create_table = sql::CreateTable::new()
+      .create_table(&name)
+      .column("id serial")
+      .into_owned();
+    let alter_table = sql::AlterTable::new()
+      .alter_table(&name)
+      .add_column("age int")
+      .into_owned();
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_id_idx")
+      .on(&name)
+      .column("id")
+      .into_owned();
+    drop(name);
+
+    assert_eq!(update.as_string(), "UPDATE users SET active = false");
+    assert_eq!(delete.as_string(), "DELETE FROM users");
+    assert_eq!(create_table.as_string(), "CREATE TABLE users (id serial)");
+    assert_eq!(alter_table.as_string(), "ALTER TABLE users ADD COLUMN age int");
+    assert_eq!(create_index.as_string(), "CREATE INDEX users_id_idx ON users (id)");
+  }
+}
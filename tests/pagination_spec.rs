@@ -0,0 +1,290 @@
+mod integer_limit_and_offset {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_limit_should_accept_integers() {
+    let per_page: u32 = 20;
+    let query = sql::Select::new().select("*").from("users").limit(per_page).as_string();
+    let expected_query = "SELECT * FROM users LIMIT 20";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_offset_should_accept_integers() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit(10)
+      .offset(30_usize)
+      .to_sql(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "SELECT * FROM users LIMIT 10 OFFSET 30";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_limit_and_offset_should_keep_accepting_texts() {
+    let limit = String::from("$1");
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit(&limit)
+      .offset("$2")
+      .to_sql(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "SELECT * FROM users LIMIT $1 OFFSET $2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn negative_integers_should_be_rejected_by_validate() {
+    let select = sql::Select::new().select("*").from("users").limit(-1);
+    let expected = sql::BuildError::InvalidValue {
+      clause: sql::Clause::Select(sql::SelectClause::Limit),
+      value: "-1".to_owned(),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+}
+
+mod bound_limit_and_offset {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn bound_values_should_be_numbered_after_the_values_of_the_where_clause() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause_bind("login = ?", "foo")
+      .limit_bind(10)
+      .offset_bind(20)
+      .build_with(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "SELECT * FROM users WHERE login = $1 LIMIT $2 OFFSET $3";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(
+      values,
+      vec![
+        sql::Value::Text("foo".to_owned()),
+        sql::Value::Int(10),
+        sql::Value::Int(20)
+      ]
+    );
+  }
+
+  #[test]
+  fn bound_values_should_follow_the_order_of_the_mysql_syntax() {
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit_bind(10)
+      .offset_bind(20)
+      .build_with(sql::Dialect::Mysql)
+      .unwrap();
+    let expected_query = "SELECT * FROM users LIMIT ?, ?";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Int(20), sql::Value::Int(10)]);
+  }
+
+  #[test]
+  fn bound_values_should_be_accepted_by_validate() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit_bind(10)
+      .offset_bind(0);
+
+    assert_eq!(select.validate(), Ok(()));
+  }
+}
+
+mod paginate_method {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_paginate_should_compute_the_offset_of_the_page() {
+    let select = sql::Select::new().select("*").from("users").paginate(3, 25);
+
+    assert_eq!(select.limit_value().unwrap(), "25");
+    assert_eq!(select.offset_value().unwrap(), "50");
+  }
+
+  #[test]
+  fn method_paginate_should_not_render_the_offset_of_the_first_page() {
+    let query = sql::Select::new().select("*").from("users").paginate(1, 25).as_string();
+    let expected_query = "SELECT * FROM users LIMIT 25";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn page_zero_should_be_the_first_page() {
+    let select = sql::Select::new().paginate(0, 10);
+
+    assert_eq!(select.limit_value().unwrap(), "10");
+    assert_eq!(select.offset_value(), None);
+  }
+
+  #[test]
+  fn method_paginate_should_override_the_previous_page() {
+    let select = sql::Select::new().paginate(5, 10).paginate(1, 10);
+
+    assert_eq!(select.offset_value(), None);
+  }
+}
+
+mod offset_without_limit {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn select() -> sql::Select<'static> {
+    sql::Select::new().select("*").from("users").offset(20)
+  }
+
+  #[test]
+  fn dialect_postgres_should_render_only_the_offset_clause() {
+    let query = select().to_sql(sql::Dialect::Postgres).unwrap();
+    let expected_query = "SELECT * FROM users OFFSET 20";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn dialect_mysql_should_render_the_largest_limit() {
+    let query = select().to_sql(sql::Dialect::Mysql).unwrap();
+    let expected_query = "SELECT * FROM users LIMIT 20, 18446744073709551615";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn dialect_sqlite_should_render_a_negative_limit() {
+    let query = select().to_sql(sql::Dialect::Sqlite).unwrap();
+    let expected_query = "SELECT * FROM users LIMIT -1 OFFSET 20";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn limit_written_with_raw_sql_should_not_be_replaced() {
+    let query = select()
+      .raw_before(sql::SelectClause::Limit, "LIMIT 10")
+      .to_sql(sql::Dialect::Sqlite)
+      .unwrap();
+    let expected_query = "SELECT * FROM users LIMIT 10 OFFSET 20";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod limit_style {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn fetch_first_style_should_render_the_offset_before_the_fetch() {
+    let query = sql::Select::new()
+      .limit_style(sql::LimitStyle::FetchFirst)
+      .select("*")
+      .from("users")
+      .order_by("id")
+      .paginate(3, 10)
+      .as_string();
+    let expected_query = "SELECT * FROM users ORDER BY id OFFSET 20 ROWS FETCH FIRST 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn fetch_first_style_should_be_rendered_for_postgres() {
+    let query = sql::Select::new()
+      .limit_style(sql::LimitStyle::FetchFirst)
+      .select("*")
+      .from("users")
+      .limit(5)
+      .to_sql(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "SELECT * FROM users FETCH FIRST 5 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn fetch_first_style_should_be_rejected_by_mysql_and_sqlite() {
+    let select = sql::Select::new()
+      .limit_style(sql::LimitStyle::FetchFirst)
+      .select("*")
+      .from("users")
+      .limit(5);
+    let expected = sql::BuildError::UnsupportedClause {
+      clause: sql::Clause::Select(sql::SelectClause::Limit),
+      dialect: sql::Dialect::Mysql,
+    };
+
+    assert_eq!(select.to_sql(sql::Dialect::Mysql), Err(expected));
+    assert!(select.to_sql(sql::Dialect::Sqlite).is_err());
+  }
+
+  #[test]
+  fn top_style_should_render_the_limit_after_the_select_keyword() {
+    let query = sql::Select::new()
+      .limit_style(sql::LimitStyle::Top)
+      .select("id, login")
+      .from("users")
+      .limit(10)
+      .as_string();
+    let expected_query = "SELECT TOP 10 id, login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn top_style_with_offset_should_be_rendered_with_the_fetch_syntax() {
+    let query = sql::Select::new()
+      .limit_style(sql::LimitStyle::Top)
+      .select("*")
+      .from("users")
+      .order_by("id")
+      .paginate(2, 10)
+      .as_string();
+    let expected_query = "SELECT * FROM users ORDER BY id OFFSET 10 ROWS FETCH FIRST 10 ROWS ONLY";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn top_style_should_be_rejected_by_all_dialects() {
+    let select = sql::Select::new()
+      .limit_style(sql::LimitStyle::Top)
+      .select("*")
+      .from("users")
+      .limit(10);
+
+    assert!(select.to_sql(sql::Dialect::Postgres).is_err());
+    assert!(select.to_sql(sql::Dialect::Mysql).is_err());
+    assert!(select.to_sql(sql::Dialect::Sqlite).is_err());
+  }
+
+  #[test]
+  fn raw_sql_of_the_limit_clause_should_be_kept_in_all_styles() {
+    let query = sql::Select::new()
+      .limit_style(sql::LimitStyle::Top)
+      .select("*")
+      .from("users")
+      .limit(10)
+      .raw_after(sql::SelectClause::Limit, "/* top */")
+      .as_string();
+    let expected_query = "SELECT TOP 10 * FROM users /* top */";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,539 @@
+mod select_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_should_split_the_statement_into_the_clauses_of_the_builder() {
+    let query = sql::Select::parse("SELECT id, login FROM users WHERE active = true")
+      .unwrap()
+      .select("name")
+      .from("address")
+      .and("login = 'foo'")
+      .as_string();
+    let expected_query = "SELECT id, login, name FROM users, address WHERE active = true AND login = 'foo'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_parse_should_be_stable_in_the_round_trip_through_as_string() {
+    let select = sql::Select::new()
+      .raw("/* report */")
+      .select("u.login, count(o.id) as orders")
+      .from("users u")
+      .inner_join("orders o ON o.user_login = u.login")
+      .left_join("address a ON a.user_login = u.login")
+      .where_clause("u.active = true")
+      .or("u.login = 'admin'")
+      .and("o.created_at > '2024-01-01'")
+      .group_by("u.login")
+      .having("count(o.id) > 10")
+      .order_by("orders desc")
+      .limit("20");
+    let query = select.as_string();
+
+    assert_eq!(sql::Select::parse(&query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn method_parse_should_accept_lowercase_keywords_and_a_semicolon_at_the_end() {
+    let query = sql::Select::parse("select * from users where login = 'foo';")
+      .unwrap()
+      .as_string();
+    let expected_query = "SELECT * FROM users WHERE login = 'foo'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_parse_should_not_split_keywords_inside_quotes_comments_and_parentheses() {
+    let sql = "\
+      SELECT id, (SELECT max(total) FROM orders WHERE orders.user_id = users.id) as max_total \
+      FROM users /* order by */ \
+      WHERE name = 'where from' \
+    ";
+    let select = sql::Select::parse(sql).unwrap();
+    let expected_query = "\
+      SELECT id, (SELECT max(total) FROM orders WHERE orders.user_id = users.id) as max_total \
+      FROM users /* order by */ \
+      WHERE name = 'where from'\
+    ";
+
+    assert_eq!(select.as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_parse_should_keep_the_or_conditions_grouped_when_a_condition_is_added() {
+    let query = sql::Select::parse("SELECT * FROM users WHERE login = 'foo' OR login = 'bar'")
+      .unwrap()
+      .and("active = true")
+      .as_string();
+    let expected_query = "SELECT * FROM users WHERE (login = 'foo' OR login = 'bar') AND active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_parse_should_keep_the_joins_as_written() {
+    let query = sql::Select::parse("SELECT * FROM users u left outer join orders o ON o.user_id = u.id")
+      .unwrap()
+      .as_string();
+    let expected_query = "SELECT * FROM users u left outer join orders o ON o.user_id = u.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_parse_should_keep_the_kind_of_the_joins() {
+    let select =
+      sql::Select::parse("SELECT * FROM users u full join orders o ON true join lateral (SELECT 1) x ON true").unwrap();
+    let join_items = select.join_items();
+
+    assert_eq!(join_items[0].kind(), sql::JoinKind::FullOuter);
+    assert_eq!(join_items[1].kind(), sql::JoinKind::Join);
+    assert_eq!(join_items[1].is_lateral(), true);
+    assert_eq!(join_items[1], "join lateral (SELECT 1) x ON true");
+    assert!(select.to_sql(sql::Dialect::Sqlite).is_err());
+  }
+
+  #[test]
+  fn method_parse_should_accept_an_empty_statement() {
+    let select = sql::Select::parse("  ").unwrap();
+
+    assert_eq!(select.as_string(), "");
+  }
+}
+
+mod insert_update_delete_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_of_insert_should_split_the_values_rows() {
+    let insert = sql::Insert::parse("INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar')")
+      .unwrap()
+      .values("('baz', 'Baz')");
+    let expected_query = "INSERT INTO users (login, name) VALUES ('foo', 'Foo'), ('bar', 'Bar'), ('baz', 'Baz')";
+
+    assert_eq!(insert.as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_parse_of_insert_should_parse_the_select_clause() {
+    let sql = "\
+      INSERT INTO users_bk (login) \
+      SELECT login FROM users WHERE active = false \
+      ON CONFLICT (login) do nothing\
+    ";
+    let insert = sql::Insert::parse(sql).unwrap();
+
+    assert_eq!(insert.as_string(), sql);
+  }
+
+  #[test]
+  fn method_parse_of_insert_should_be_stable_in_the_round_trip_through_as_string() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .overriding("system value")
+      .values("('foo')")
+      .on_conflict("(login) do update set login = excluded.login");
+    let query = insert.as_string();
+
+    assert_eq!(sql::Insert::parse(&query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn method_parse_of_update_should_split_the_assignments() {
+    let update = sql::Update::parse("UPDATE users SET name = 'Foo', active = true WHERE login = 'foo'")
+      .unwrap()
+      .set("updated_at = now()");
+    let expected_query = "UPDATE users SET name = 'Foo', active = true, updated_at = now() WHERE login = 'foo'";
+
+    assert_eq!(update.as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_parse_of_delete_should_be_stable_in_the_round_trip_through_as_string() {
+    let delete = sql::Delete::new()
+      .raw("/* cleanup */")
+      .delete_from("users")
+      .where_clause("active = false")
+      .and("created_at < now()");
+    let query = delete.as_string();
+
+    assert_eq!(sql::Delete::parse(&query).unwrap().as_string(), query);
+  }
+}
+
+mod parse_errors {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_should_return_an_error_for_a_statement_of_another_builder() {
+    let error = sql::Select::parse("UPDATE users SET active = true").unwrap_err();
+    let expected_error = sql::ParseError::UnexpectedStatement {
+      expected: "SELECT".to_owned(),
+    };
+
+    assert_eq!(error, expected_error);
+    assert_eq!(error.to_string(), "expected a statement beginning with SELECT");
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_a_repeated_clause() {
+    let error = sql::Select::parse("SELECT * FROM users WHERE a = 1 WHERE b = 2").unwrap_err();
+    let expected_error = sql::ParseError::UnexpectedClause {
+      clause: "WHERE".to_owned(),
+      position: 32,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_a_clause_out_of_order() {
+    let error = sql::Delete::parse("DELETE FROM users WHERE a = 1 DELETE FROM orders").unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "the clause DELETE FROM at position 30 is repeated or out of the order of the builder"
+    );
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_an_empty_clause() {
+    let error = sql::Update::parse("UPDATE users SET name = 'Foo' WHERE").unwrap_err();
+    let expected_error = sql::ParseError::EmptyClause {
+      clause: "WHERE".to_owned(),
+      position: 30,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_unbalanced_quotes_and_parentheses() {
+    let quote = sql::Select::parse("SELECT * FROM users WHERE login = 'foo").unwrap_err();
+    let parenthesis = sql::Select::parse("SELECT count(* FROM users").unwrap_err();
+
+    assert_eq!(quote, sql::ParseError::Unbalanced { position: 34 });
+    assert_eq!(parenthesis, sql::ParseError::Unbalanced { position: 12 });
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_multiple_statements() {
+    let error = sql::Delete::parse("DELETE FROM users; DELETE FROM orders").unwrap_err();
+
+    assert_eq!(error, sql::ParseError::MultipleStatements { position: 19 });
+  }
+
+  #[test]
+  fn method_parse_should_report_the_positions_of_the_select_clause_of_insert() {
+    let error = sql::Insert::parse("INSERT INTO users SELECT * FROM users_bk WHERE").unwrap_err();
+    let expected_error = sql::ParseError::EmptyClause {
+      clause: "WHERE".to_owned(),
+      position: 41,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_accept_the_clauses_of_all_dialects_regardless_of_the_features() {
+    let delete = sql::Delete::parse("DELETE FROM users RETURNING id").unwrap();
+    let insert = sql::Insert::parse("INSERT IGNORE INTO users (login) VALUES ('foo')").unwrap();
+
+    assert_eq!(delete.as_string(), "DELETE FROM users RETURNING id");
+    assert_eq!(insert.as_string(), "INSERT IGNORE INTO users (login) VALUES ('foo')");
+  }
+}
+
+mod with_and_combinators_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_should_be_stable_for_the_with_clause() {
+    let select = sql::Select::new()
+      .with(
+        "active_users",
+        sql::Select::new()
+          .select("*")
+          .from("users")
+          .where_clause("active = true"),
+      )
+      .with("deleted", sql::Delete::new().delete_from("orders").returning("id"))
+      .select("*")
+      .from("active_users");
+    let query = select.as_string();
+
+    assert_eq!(sql::Select::parse(&query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn method_parse_should_be_stable_for_a_delete_with_returning_in_the_with_clause() {
+    let query = "WITH d AS (DELETE FROM orders WHERE status = 'cancelled' RETURNING id) SELECT * FROM d";
+
+    assert_eq!(sql::Select::parse(query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn method_parse_should_validate_the_queries_of_the_with_clause() {
+    let error = sql::Select::parse("WITH x AS (SELECT * FROM t WHERE) SELECT * FROM x").unwrap_err();
+    let expected_error = sql::ParseError::EmptyClause {
+      clause: "WHERE".to_owned(),
+      position: 27,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_be_stable_for_the_compound_selects() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .except(sql::Select::new().select("login").from("banned"))
+      .union(sql::Select::new().select("login").from("admins"));
+    let query = select.as_string();
+
+    assert_eq!(sql::Select::parse(&query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_combinators_out_of_the_rendered_order() {
+    let error = sql::Select::parse("SELECT a FROM t UNION SELECT a FROM u EXCEPT SELECT a FROM v").unwrap_err();
+    let expected_error = sql::ParseError::UnexpectedClause {
+      clause: "EXCEPT".to_owned(),
+      position: 38,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_union_all() {
+    let error = sql::Select::parse("SELECT a FROM t UNION ALL SELECT a FROM u").unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "the clause UNION ALL at position 16 is not supported by the builder"
+    );
+  }
+
+  #[test]
+  fn method_parse_should_be_stable_for_the_returning_clause() {
+    let query = "UPDATE users SET active = false WHERE login = 'foo' RETURNING id, login";
+    let update = sql::Update::parse(query).unwrap();
+
+    assert_eq!(update.as_string(), query);
+  }
+}
+
+mod unsupported_clauses_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_should_return_an_error_for_the_locking_clauses() {
+    let error = sql::Select::parse("SELECT * FROM t WHERE a = 1 FOR UPDATE").unwrap_err();
+    let expected_error = sql::ParseError::UnsupportedClause {
+      clause: "FOR UPDATE".to_owned(),
+      position: 28,
+    };
+
+    assert_eq!(error, expected_error);
+    assert!(sql::Select::parse("SELECT * FROM t FOR SHARE").is_err());
+    assert!(sql::Select::parse("SELECT * FROM t LOCK IN SHARE MODE").is_err());
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_the_window_clause() {
+    let error = sql::Select::parse("SELECT sum(a) OVER w FROM t WINDOW w AS (ORDER BY a)").unwrap_err();
+    let expected_error = sql::ParseError::UnsupportedClause {
+      clause: "WINDOW".to_owned(),
+      position: 28,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_the_fetch_clause() {
+    let error = sql::Select::parse("SELECT * FROM t ORDER BY a FETCH FIRST 5 ROWS ONLY").unwrap_err();
+    let expected_error = sql::ParseError::UnsupportedClause {
+      clause: "FETCH FIRST".to_owned(),
+      position: 27,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_return_an_error_for_the_unsupported_clauses_of_the_nested_statements() {
+    assert!(sql::Insert::parse("INSERT INTO t (a) SELECT a FROM u FOR UPDATE").is_err());
+    assert!(sql::Select::parse("WITH x AS (SELECT * FROM t FOR UPDATE) SELECT * FROM x").is_err());
+    assert!(sql::Select::parse("SELECT * FROM t UNION SELECT * FROM u FOR UPDATE").is_err());
+  }
+
+  #[test]
+  fn method_parse_should_accept_the_keywords_of_the_unsupported_clauses_inside_expressions() {
+    let query = "SELECT substring(a FROM 1 FOR 2), 'for update' FROM t";
+
+    assert_eq!(sql::Select::parse(query).unwrap().as_string(), query);
+  }
+}
+
+/// Every clause rendered by the builders is parsed back into the same clause
+mod round_trip_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn all_clauses_of_the_select_builder_should_be_stable() {
+    let select = sql::Select::new()
+      .raw("/* report */")
+      .with("ids", sql::Select::new().select("id").from("users"))
+      .select("u.id, count(o.id)")
+      .from("users u")
+      .cross_join("settings s")
+      .full_outer_join("profiles p ON p.user_id = u.id")
+      .inner_join("orders o ON o.user_id = u.id")
+      .join_lateral("(SELECT 1) x ON true")
+      .left_join("addresses a ON a.user_id = u.id")
+      .left_join_lateral("(SELECT 2) y ON true")
+      .natural_join("accounts")
+      .right_join("groups g ON g.id = u.group_id")
+      .where_clause("u.active = true")
+      .group_by("u.id")
+      .having("count(o.id) > 1")
+      .order_by("u.id desc")
+      .limit("10")
+      .offset("20");
+    let query = select.as_string();
+    let parsed = sql::Select::parse(&query).unwrap();
+
+    assert_eq!(parsed.as_string(), query);
+    assert_eq!(parsed.join_items().len(), 8);
+  }
+
+  #[test]
+  fn all_combinators_of_the_select_builder_should_be_stable() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .except(sql::Select::new().select("login").from("banned"))
+      .intersect(sql::Select::new().select("login").from("active"))
+      .union(sql::Select::new().select("login").from("admins"));
+    let query = select.as_string();
+
+    assert_eq!(sql::Select::parse(&query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn all_clauses_of_the_insert_builder_should_be_stable() {
+    let insert_into = sql::Insert::new()
+      .with("ids", sql::Select::new().select("id").from("users"))
+      .insert_into("users (id, login)")
+      .overriding("system value")
+      .values("(1, 'foo')")
+      .on_conflict("do nothing")
+      .returning("id");
+    let insert_select = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(
+        sql::Select::new()
+          .select("login")
+          .from("users")
+          .where_clause("active = true"),
+      )
+      .on_duplicate_key_update("login = VALUES(login)");
+    let insert_or = sql::Insert::new()
+      .insert_or("REPLACE INTO users (login)")
+      .values("('foo')");
+    let insert_ignore = sql::Insert::new()
+      .insert_ignore_into("users (login)")
+      .values("('foo')")
+      .values("('bar')");
+
+    for insert in [insert_into, insert_select, insert_or, insert_ignore] {
+      let query = insert.as_string();
+
+      assert_eq!(sql::Insert::parse(&query).unwrap().as_string(), query);
+    }
+  }
+
+  #[test]
+  fn all_clauses_of_the_update_builder_should_be_stable() {
+    let update = sql::Update::new()
+      .with("ids", sql::Select::new().select("id").from("users"))
+      .update("orders o")
+      .cross_join("settings s")
+      .inner_join("users u ON u.id = o.user_id")
+      .left_join("addresses a ON a.user_id = u.id")
+      .right_join("groups g ON g.id = u.group_id")
+      .set("o.active = u.active")
+      .from("ids")
+      .where_clause("o.id = ids.id")
+      .returning("o.id")
+      .order_by("o.id")
+      .limit("10");
+    let query = update.as_string();
+
+    assert_eq!(sql::Update::parse(&query).unwrap().as_string(), query);
+  }
+
+  #[test]
+  fn all_clauses_of_the_delete_builder_should_be_stable() {
+    let delete = sql::Delete::new()
+      .with("ids", sql::Select::new().select("id").from("banned"))
+      .delete_from("users")
+      .where_clause("id IN (SELECT id FROM ids)")
+      .returning("id")
+      .order_by("id")
+      .limit("10");
+    let query = delete.as_string();
+
+    assert_eq!(sql::Delete::parse(&query).unwrap().as_string(), query);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod postgresql_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_should_return_an_error_for_an_order_by_that_would_be_moved_into_the_last_member() {
+    let error = sql::Select::parse("SELECT a FROM t UNION SELECT a FROM u ORDER BY a").unwrap_err();
+    let expected_error = sql::ParseError::UnsupportedClause {
+      clause: "ORDER BY".to_owned(),
+      position: 38,
+    };
+
+    assert_eq!(error, expected_error);
+  }
+
+  #[test]
+  fn method_parse_should_be_stable_for_the_from_clause_of_update() {
+    let query = "UPDATE users SET total = o.total FROM orders o WHERE o.user_id = users.id";
+
+    assert_eq!(sql::Update::parse(query).unwrap().as_string(), query);
+  }
+}
+
+mod mysql_parse {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_parse_should_be_stable_for_the_mysql_clauses() {
+    let insert = "INSERT IGNORE INTO users (login) VALUES ('foo') ON DUPLICATE KEY UPDATE login = VALUES(login)";
+    let update =
+      "UPDATE orders o INNER JOIN users u ON u.id = o.user_id SET o.active = u.active ORDER BY o.id LIMIT 10";
+
+    assert_eq!(sql::Insert::parse(insert).unwrap().as_string(), insert);
+    assert_eq!(sql::Update::parse(update).unwrap().as_string(), update);
+  }
+}
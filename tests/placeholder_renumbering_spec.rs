@@ -0,0 +1,223 @@
+mod placeholder_renumbering {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn placeholders_of_the_main_query_should_keep_the_original_numbers() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("login = $2")
+      .and("id = $1");
+    let map = select.placeholder_map();
+    let expected_query = "SELECT * FROM users WHERE login = $2 AND id = $1";
+
+    assert_eq!(select.as_string(), expected_query);
+    assert_eq!(map.scopes(), 1);
+    assert_eq!(map.count(0), 2);
+    assert_eq!(map.get(0, 2), Some(2));
+  }
+
+  #[test]
+  fn placeholders_of_the_insert_select_should_be_shifted_by_the_insert_placeholders() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .select(
+        sql::Select::new()
+          .select("login, name")
+          .from("users_bk")
+          .where_clause("login = $1"),
+      )
+      .on_conflict("(login) do update set name = $1");
+    let expected_query = "\
+      INSERT INTO users (login, name) \
+      SELECT login, name FROM users_bk WHERE login = $2 \
+      ON CONFLICT (login) do update set name = $1\
+    ";
+
+    assert_eq!(insert.as_string(), expected_query);
+  }
+
+  #[test]
+  fn placeholders_repeated_in_the_same_builder_should_share_the_new_number() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(
+        sql::Select::new()
+          .select("$1")
+          .from("users_bk")
+          .where_clause("login <> $1"),
+      )
+      .overriding("system value")
+      .on_conflict("do update set login = $1");
+    let expected_query = "\
+      INSERT INTO users (login) \
+      OVERRIDING system value \
+      SELECT $2 FROM users_bk WHERE login <> $2 \
+      ON CONFLICT do update set login = $1\
+    ";
+
+    assert_eq!(insert.as_string(), expected_query);
+  }
+
+  #[test]
+  fn placeholders_inside_quotes_and_identifiers_should_not_be_changed() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(
+        sql::Select::new()
+          .select("'$1', \"col$1\", price$1")
+          .from("users_bk")
+          .where_clause("login = $1"),
+      )
+      .on_conflict("do update set login = $1");
+    let expected_query = "\
+      INSERT INTO users (login) \
+      SELECT '$1', \"col$1\", price$1 FROM users_bk WHERE login = $2 \
+      ON CONFLICT do update set login = $1\
+    ";
+
+    assert_eq!(insert.as_string(), expected_query);
+  }
+
+  #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
+  fn bound_values_should_be_numbered_after_the_positional_placeholders() {
+    let (query, values) = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(
+        sql::Select::new()
+          .select("login")
+          .from("users_bk")
+          .where_clause("id = $1")
+          .and_bind("active = ?", true),
+      )
+      .build();
+    let expected_query = "INSERT INTO users (login) SELECT login FROM users_bk WHERE id = $1 AND active = $2";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Bool(true)]);
+  }
+
+  #[test]
+  fn method_arrange_should_join_the_arguments_of_each_scope_in_the_final_order() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .select(
+        sql::Select::new()
+          .select("login, $2")
+          .from("users_bk")
+          .where_clause("login = $1"),
+      )
+      .on_conflict("(login) do update set name = $1");
+    let map = insert.placeholder_map();
+
+    assert_eq!(map.scopes(), 2);
+    assert_eq!(map.get(1, 1), Some(2));
+    assert_eq!(map.get(1, 2), Some(3));
+    assert_eq!(map.get(1, 3), None);
+    assert_eq!(
+      map.arrange(vec![vec!["Foo"], vec!["foo", "Bar"]]),
+      vec!["Foo", "foo", "Bar"]
+    );
+  }
+
+  #[test]
+  #[should_panic]
+  fn method_arrange_should_panic_when_the_arguments_does_not_match_the_placeholders() {
+    let map = sql::Delete::new().where_clause("id = $1").placeholder_map();
+
+    map.arrange(vec![vec![1, 2]]);
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod placeholder_renumbering_with_composition {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn placeholders_of_the_with_clause_should_be_shifted_by_the_outer_query_placeholders() {
+    let logins = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("id in ($1)");
+    let select = sql::Select::new()
+      .with("logins", logins)
+      .select("name, price")
+      .from("orders")
+      .where_clause("owner_login in (select * from logins)")
+      .and("price > $1");
+    let expected_query = "\
+      WITH logins AS (SELECT login FROM users WHERE id in ($2)) \
+      SELECT name, price \
+      FROM orders \
+      WHERE owner_login in (select * from logins) AND price > $1\
+    ";
+
+    assert_eq!(select.as_string(), expected_query);
+  }
+
+  #[test]
+  fn placeholders_of_each_composed_builder_should_be_shifted_in_the_render_order() {
+    let select = sql::Select::new()
+      .select("login")
+      .from("users")
+      .where_clause("id = $1")
+      .union(
+        sql::Select::new()
+          .select("login")
+          .from("admins")
+          .where_clause("id = $1"),
+      )
+      .except(
+        sql::Select::new()
+          .select("login")
+          .from("banned")
+          .where_clause("id = $1"),
+      )
+      .intersect(
+        sql::Select::new()
+          .select("login")
+          .from("active")
+          .where_clause("id = $1"),
+      );
+    let expected_query = "\
+      (((SELECT login FROM users WHERE id = $1) \
+      EXCEPT \
+      (SELECT login FROM banned WHERE id = $2)) \
+      INTERSECT \
+      (SELECT login FROM active WHERE id = $3)) \
+      UNION \
+      (SELECT login FROM admins WHERE id = $4)\
+    ";
+
+    assert_eq!(select.as_string(), expected_query);
+    assert_eq!(select.placeholder_map().scopes(), 4);
+  }
+
+  #[test]
+  fn placeholders_of_nested_with_clauses_should_be_shifted_in_the_render_order() {
+    let inner = sql::Select::new().select("id").from("users").where_clause("login = $1");
+    let middle = sql::Select::new()
+      .with("inner_ids", inner)
+      .select("id")
+      .from("inner_ids")
+      .where_clause("id > $1");
+    let delete = sql::Delete::new()
+      .with("ids", middle)
+      .delete_from("orders")
+      .where_clause("owner_id in (select * from ids)")
+      .and("created_at < $1");
+    let expected_query = "\
+      WITH ids AS (\
+        WITH inner_ids AS (SELECT id FROM users WHERE login = $3) \
+        SELECT id FROM inner_ids WHERE id > $2\
+      ) \
+      DELETE FROM orders \
+      WHERE owner_id in (select * from ids) AND created_at < $1\
+    ";
+
+    assert_eq!(delete.as_string(), expected_query);
+  }
+}
@@ -254,3 +254,299 @@ mod right_join_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod full_outer_join_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_full_outer_join_should_add_the_full_outer_join_clause() {
+    let query = sql::Select::new()
+      .full_outer_join("address ON users.login = address.login")
+      .as_string();
+    let expected_query = "FULL OUTER JOIN address ON users.login = address.login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_full_outer_join_by_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().full_outer_join("  orders  ").as_string();
+    let expected_query = "FULL OUTER JOIN orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_full_outer_join_should_keep_the_order_of_the_other_joins() {
+    let query = sql::Select::new()
+      .from("users")
+      .inner_join("orders ON orders.login = users.login")
+      .full_outer_join("address ON users.login = address.login")
+      .as_string();
+    let expected_query = "\
+      FROM users \
+      INNER JOIN orders ON orders.login = users.login \
+      FULL OUTER JOIN address ON users.login = address.login\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_full_outer_join_should_be_rejected_by_mysql() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("users")
+      .full_outer_join("address ON users.login = address.login");
+    let expected = sql::BuildError::UnsupportedClause {
+      clause: sql::Clause::Select(sql::SelectClause::Join),
+      dialect: sql::Dialect::Mysql,
+    };
+
+    assert_eq!(select.to_sql(sql::Dialect::Mysql), Err(expected));
+    assert!(select.to_sql(sql::Dialect::Sqlite).is_ok());
+  }
+}
+
+mod natural_join_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_natural_join_should_add_the_natural_join_clause() {
+    let query = sql::Select::new()
+      .from("orders")
+      .natural_join("order_items")
+      .as_string();
+    let expected_query = "FROM orders NATURAL JOIN order_items";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_natural_join_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Select::new()
+      .natural_join("order_items")
+      .natural_join("order_items")
+      .as_string();
+    let expected_query = "NATURAL JOIN order_items";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod join_method {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_join_should_render_the_on_conditions_joined_by_and() {
+    let query = sql::Select::new()
+      .from("users u")
+      .join(
+        sql::Join::inner("orders o")
+          .on("o.user_id = u.id")
+          .on(sql::Condition::or(["o.status = 'paid'", "o.status = 'sent'"])),
+      )
+      .as_string();
+    let expected_query = "\
+      FROM users u \
+      INNER JOIN orders o ON o.user_id = u.id AND (o.status = 'paid' OR o.status = 'sent')\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_join_should_render_the_using_columns() {
+    let query = sql::Select::new()
+      .from("orders")
+      .join(sql::Join::left("payments").using(["order_id", " user_id "]))
+      .as_string();
+    let expected_query = "FROM orders LEFT JOIN payments USING (order_id, user_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_override_the_on_conditions() {
+    let query = sql::Select::new()
+      .join(sql::Join::right("payments").on("a = b").using(["order_id"]))
+      .as_string();
+    let expected_query = "RIGHT JOIN payments USING (order_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_join_should_accept_a_subquery_with_an_alias() {
+    let totals = sql::Select::new()
+      .select("user_id, sum(total) AS total")
+      .from("orders")
+      .group_by("user_id");
+    let query = sql::Select::new()
+      .select("u.login, t.total")
+      .from("users u")
+      .join(sql::Join::full_outer(totals).alias("t").on("t.user_id = u.id"))
+      .as_string();
+    let expected_query = "\
+      SELECT u.login, t.total \
+      FROM users u \
+      FULL OUTER JOIN (SELECT user_id, sum(total) AS total FROM orders GROUP BY user_id) AS t ON t.user_id = u.id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn placeholders_of_the_subquery_should_be_renumbered() {
+    let orders = sql::Select::new()
+      .select("user_id")
+      .from("orders")
+      .where_clause("total > $1");
+    let (query, values) = sql::Select::new()
+      .select("*")
+      .from("users u")
+      .join(sql::Join::inner(orders).alias("o").on("o.user_id = u.id"))
+      .where_clause("u.login = $1")
+      .where_clause_bind("u.active = ?", true)
+      .build_with(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = "\
+      SELECT * FROM users u \
+      INNER JOIN (SELECT user_id FROM orders WHERE total > $2) AS o ON o.user_id = u.id \
+      WHERE u.login = $1 AND u.active = $3\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(values, vec![sql::Value::Bool(true)]);
+  }
+
+  #[test]
+  fn method_join_should_accept_the_cross_and_natural_joins() {
+    let query = sql::Select::new()
+      .from("users")
+      .join(sql::Join::cross(sql::Ident::new("order")))
+      .join(sql::Join::natural("addresses"))
+      .to_sql(sql::Dialect::Postgres)
+      .unwrap();
+    let expected_query = r#"FROM users CROSS JOIN "order" NATURAL JOIN addresses"#;
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn join_items_should_return_the_joins_with_its_kind() {
+    let select = sql::Select::new()
+      .join(sql::Join::inner("orders").alias("o").on("o.user_id = u.id"))
+      .join_lateral("(SELECT 1) x ON true");
+
+    assert_eq!(
+      select.join_items(),
+      [
+        "INNER JOIN orders AS o ON o.user_id = u.id",
+        "JOIN LATERAL (SELECT 1) x ON true"
+      ]
+    );
+    assert_eq!(select.join_items()[0].kind(), sql::JoinKind::Inner);
+    assert_eq!(select.join_items()[1].kind(), sql::JoinKind::Join);
+    assert_eq!(select.join_items()[1].is_lateral(), true);
+  }
+
+  #[test]
+  fn join_items_should_keep_the_subquery_as_a_builder() {
+    let orders = sql::Select::new().select("user_id").from("orders");
+    let select = sql::Select::new().join(sql::Join::inner(orders).alias("o").on("o.user_id = u.id"));
+
+    assert_eq!(
+      select.join_items()[0].subquery().unwrap().as_string(),
+      "SELECT user_id FROM orders"
+    );
+  }
+
+  #[test]
+  fn subquery_should_be_rendered_with_the_dialect_of_the_query() {
+    let logins = sql::Select::new()
+      .select("login")
+      .from("users")
+      .union(sql::Select::new().select("login").from("admins"));
+    let select = sql::Select::new()
+      .select("*")
+      .from("orders o")
+      .join(sql::Join::inner(logins).alias("l").on("l.login = o.login"));
+
+    assert_eq!(
+      select.to_sql(sql::Dialect::Sqlite).unwrap(),
+      "SELECT * FROM orders o \
+      INNER JOIN (SELECT login FROM users UNION SELECT login FROM admins) AS l ON l.login = o.login"
+    );
+    assert_eq!(
+      select.to_sql(sql::Dialect::Postgres).unwrap(),
+      "SELECT * FROM orders o \
+      INNER JOIN ((SELECT login FROM users) UNION (SELECT login FROM admins)) AS l ON l.login = o.login"
+    );
+  }
+
+  #[test]
+  fn clauses_of_the_subquery_should_be_checked_by_the_dialect() {
+    let users = sql::Select::new()
+      .select("*")
+      .from("users u")
+      .full_outer_join("addresses a ON a.user_id = u.id");
+    let select = sql::Select::new()
+      .select("*")
+      .from("orders o")
+      .join(sql::Join::inner(users).alias("u").on("u.id = o.user_id"));
+    let expected = sql::BuildError::UnsupportedClause {
+      clause: sql::Clause::Select(sql::SelectClause::Join),
+      dialect: sql::Dialect::Mysql,
+    };
+
+    assert_eq!(select.to_sql(sql::Dialect::Mysql), Err(expected));
+  }
+
+  #[test]
+  fn subquery_should_be_validated_with_the_query() {
+    let invalid = sql::Select::new().from("orders").limit("ten");
+    let select = sql::Select::new()
+      .select("*")
+      .from("users u")
+      .join(sql::Join::inner(invalid).alias("o").on("o.user_id = u.id"));
+
+    assert!(select.validate().is_err());
+    assert!(select.try_as_string().is_err());
+  }
+
+  #[test]
+  fn subquery_should_be_indented_by_the_multiline_format() {
+    let orders = sql::Select::new().select("user_id").from("orders");
+    let query = sql::Select::new()
+      .select("*")
+      .from("users u")
+      .join(sql::Join::inner(orders).alias("o").on("o.user_id = u.id"))
+      .to_string_with(&sql::FormatOptions::new().line_breaks(true));
+    let expected_query = "\
+      SELECT *\n\
+      FROM users u\n\
+      INNER JOIN (\n  \
+        SELECT user_id\n  \
+        FROM orders\n\
+      ) AS o ON o.user_id = u.id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn cross_and_natural_joins_should_accept_an_alias_and_the_lateral_keyword() {
+    let query = sql::Select::new()
+      .from("users u")
+      .join(sql::Join::natural("addresses").alias("a"))
+      .join(sql::Join::cross(sql::Select::new().select("1")).lateral().alias("x"))
+      .as_string();
+    let expected_query = "FROM users u NATURAL JOIN addresses AS a CROSS JOIN LATERAL (SELECT 1) AS x";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -16,7 +16,12 @@ fn all_clauses_concatenated_in_order() {
     .offset("50")
     .as_string();
 
-  let expected_query = "\
+  #[cfg(not(all(feature = "mysql", not(feature = "postgresql"))))]
+  let limit_and_offset = "LIMIT 1000 OFFSET 50";
+  #[cfg(all(feature = "mysql", not(feature = "postgresql")))]
+  let limit_and_offset = "LIMIT 50, 1000";
+  let expected_query = format!(
+    "\
     /* all clauses in order */ \
     SELECT * \
     FROM user_list \
@@ -25,9 +30,9 @@ fn all_clauses_concatenated_in_order() {
     GROUP BY login \
     HAVING active = true \
     ORDER BY created_at desc \
-    LIMIT 1000 \
-    OFFSET 50\
-  ";
+    {limit_and_offset}\
+  "
+  );
 
   assert_eq!(query, expected_query);
 }
@@ -458,6 +463,7 @@ mod offset_clause {
   use pretty_assertions::assert_eq;
 
   #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
   fn method_offset_should_add_the_offset_clause() {
     let query = sql::Select::new().offset("100").as_string();
     let expected_query = "OFFSET 100";
@@ -466,6 +472,7 @@ mod offset_clause {
   }
 
   #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
   fn method_offset_should_override_the_current_value() {
     let query = sql::Select::new().offset("100").offset("200").as_string();
     let expected_query = "OFFSET 200";
@@ -474,6 +481,7 @@ mod offset_clause {
   }
 
   #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
   fn method_offset_should_trim_space_of_the_argument() {
     let query = sql::Select::new().offset("  2000  ").as_string();
     let expected_query = "OFFSET 2000";
@@ -482,6 +490,7 @@ mod offset_clause {
   }
 
   #[test]
+  #[cfg(not(all(feature = "mysql", not(feature = "postgresql"))))]
   fn clause_offset_should_be_after_limit_clause() {
     let query = sql::Select::new().limit("500").offset("100").as_string();
     let expected_query = "LIMIT 500 OFFSET 100";
@@ -501,6 +510,7 @@ mod offset_clause {
   }
 
   #[test]
+  #[cfg(any(feature = "postgresql", not(any(feature = "mysql", feature = "sqlite"))))]
   fn method_raw_after_should_add_raw_sql_after_offset_clause() {
     let query = sql::Select::new()
       .offset("10")
@@ -0,0 +1,59 @@
+mod send_sync {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  fn assert_send_sync<T: Send + Sync>() {}
+
+  #[test]
+  fn builders_should_be_send_and_sync() {
+    assert_send_sync::<sql::AlterTable>();
+    assert_send_sync::<sql::CreateIndex>();
+    assert_send_sync::<sql::CreateTable>();
+    assert_send_sync::<sql::Delete>();
+    assert_send_sync::<sql::DropIndex>();
+    assert_send_sync::<sql::DropTable>();
+    assert_send_sync::<sql::Insert>();
+    assert_send_sync::<sql::Select>();
+    assert_send_sync::<sql::Update>();
+    assert_send_sync::<sql::Values>();
+  }
+
+  #[test]
+  fn clauses_conditions_and_values_should_be_send_and_sync() {
+    assert_send_sync::<sql::Condition>();
+    assert_send_sync::<sql::Value>();
+    assert_send_sync::<sql::SelectClause>();
+    assert_send_sync::<sql::BuildError>();
+    assert_send_sync::<sql::ParseError>();
+  }
+
+  #[test]
+  fn builder_should_be_moved_to_another_thread() {
+    let select = sql::Select::new()
+      .with(
+        "active_users",
+        sql::Select::new()
+          .select("*")
+          .from("users")
+          .where_clause("active = true"),
+      )
+      .select("id")
+      .from("active_users")
+      .where_clause("login = $1")
+      .into_owned();
+    let query = std::thread::spawn(move || select.as_string()).join().unwrap();
+    let expected_query =
+      "WITH active_users AS (SELECT * FROM users WHERE active = true) SELECT id FROM active_users WHERE login = $1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn builder_should_be_stored_in_a_static() {
+    static QUERY: std::sync::OnceLock<sql::Select<'static>> = std::sync::OnceLock::new();
+    let select = QUERY.get_or_init(|| sql::Select::new().select("id").from("users"));
+    let query = std::thread::spawn(|| QUERY.get().unwrap().as_string()).join().unwrap();
+
+    assert_eq!(query, select.as_string());
+  }
+}
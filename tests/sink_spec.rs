@@ -0,0 +1,125 @@
+mod io_sink {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_print_to_should_write_the_query_in_one_line_without_colors() {
+    let mut output = vec![];
+    sql::Select::new()
+      .select("id")
+      .from("users")
+      .where_clause("login = $1")
+      .print_to(&mut sql::IoSink::new(&mut output));
+
+    assert_eq!(
+      String::from_utf8(output).unwrap(),
+      "SELECT id FROM users WHERE login = $1\n"
+    );
+  }
+
+  #[test]
+  fn method_debug_to_should_write_the_query_in_the_multiline_layout() {
+    let mut output = vec![];
+    sql::Delete::new()
+      .delete_from("users")
+      .where_clause("id = 1")
+      .debug_to(&mut sql::IoSink::new(&mut output));
+    let hr = "-- ------------------------------------------------------------------------------";
+    let expected = format!("\n{hr}\nDELETE FROM users \nWHERE id = 1\n{hr}\n\n");
+
+    assert_eq!(String::from_utf8(output).unwrap(), expected);
+  }
+
+  #[test]
+  fn method_colored_should_enable_the_colors_unless_no_color_is_defined() {
+    let mut output = vec![];
+    sql::Select::new()
+      .select("id")
+      .print_to(&mut sql::IoSink::new(&mut output).colored(true));
+    let output = String::from_utf8(output).unwrap();
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| value.is_empty() == false);
+
+    assert_eq!(output.contains("\x1b["), no_color == false);
+  }
+
+  #[test]
+  fn methods_debug_to_and_print_to_should_keep_the_chain_of_the_builder() {
+    let mut sink = sql::IoSink::new(vec![]);
+    let query = sql::Update::new()
+      .update("users")
+      .print_to(&mut sink)
+      .set("login = 'foo'")
+      .debug_to(&mut sink)
+      .as_string();
+    let output = String::from_utf8(sink.into_inner()).unwrap();
+
+    assert_eq!(query, "UPDATE users SET login = 'foo'");
+    assert!(output.starts_with("UPDATE users\n\n-- ---"));
+  }
+
+  #[test]
+  fn method_take_error_should_return_the_first_error_of_the_writer() {
+    struct Broken;
+
+    impl std::io::Write for Broken {
+      fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("broken pipe"))
+      }
+
+      fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+      }
+    }
+
+    let mut sink = sql::IoSink::new(Broken);
+    sql::DropTable::new().drop_table("users").print_to(&mut sink);
+
+    assert_eq!(sink.take_error().unwrap().to_string(), "broken pipe");
+    assert!(sink.take_error().is_none());
+  }
+}
+
+mod fmt_sink {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_print_to_should_accumulate_the_queries_in_the_writer() {
+    let mut output = String::new();
+    let mut sink = sql::FmtSink::new(&mut output);
+    sql::Insert::new()
+      .insert_into("users (login)")
+      .print_to(&mut sink)
+      .values("('foo')")
+      .print_to(&mut sink);
+
+    assert_eq!(
+      output,
+      "INSERT INTO users (login)\nINSERT INTO users (login) VALUES ('foo')\n"
+    );
+  }
+
+  #[test]
+  fn custom_sinks_should_receive_the_query_without_colors_when_not_colored() {
+    struct Queries(Vec<String>);
+
+    impl sql::Sink for Queries {
+      fn is_colored(&self) -> bool {
+        false
+      }
+
+      fn write_query(&mut self, query: &str) {
+        self.0.push(query.to_owned())
+      }
+    }
+
+    let mut sink = Queries(vec![]);
+    sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .print_to(&mut sink);
+
+    assert_eq!(sink.0, vec!["CREATE INDEX users_login_idx ON users (login)"]);
+  }
+}
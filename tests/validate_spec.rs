@@ -0,0 +1,352 @@
+mod select_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_validate_should_accept_a_complete_query() {
+    let select = sql::Select::new()
+      .select("id, login")
+      .from("users")
+      .inner_join("orders on orders.user_id = users.id")
+      .where_clause("login = $1")
+      .limit("$2")
+      .offset("100");
+
+    assert_eq!(select.validate(), Ok(()));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_limit_is_not_a_number() {
+    let select = sql::Select::new().select("id").from("users").limit("abc");
+    let expected = sql::BuildError::InvalidValue {
+      clause: sql::Clause::Select(sql::SelectClause::Limit),
+      value: "abc".to_owned(),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_offset_is_not_a_number() {
+    let select = sql::Select::new().select("id").from("users").offset("-10");
+    let expected = sql::BuildError::InvalidValue {
+      clause: sql::Clause::Select(sql::SelectClause::Offset),
+      value: "-10".to_owned(),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_accept_the_placeholders_as_limit() {
+    let select = sql::Select::new().select("id").from("users").limit("?");
+
+    assert_eq!(select.validate(), Ok(()));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_select_clause_is_missing() {
+    let select = sql::Select::new().from("users").where_clause("login = 'foo'");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Select(sql::SelectClause::Select),
+      required_by: sql::Clause::Select(sql::SelectClause::From),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_a_join_is_defined_without_from() {
+    let select = sql::Select::new()
+      .select("*")
+      .left_join("orders on orders.user_id = users.id");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Select(sql::SelectClause::From),
+      required_by: sql::Clause::Select(sql::SelectClause::Join),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_not_report_missing_clauses_when_the_query_has_raw_sql() {
+    let select = sql::Select::new()
+      .raw("select u.id from users u")
+      .where_clause("u.login = 'foo'");
+
+    assert_eq!(select.validate(), Ok(()));
+  }
+
+  #[test]
+  fn method_validate_should_report_the_missing_clauses_the_raw_sql_does_not_define() {
+    let select = sql::Select::new()
+      .raw("/* users by login */")
+      .from("users")
+      .where_clause("login = 'foo'");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Select(sql::SelectClause::Select),
+      required_by: sql::Clause::Select(sql::SelectClause::From),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_not_find_the_keywords_inside_quotes_of_the_raw_sql() {
+    let select = sql::Select::new()
+      .select("*")
+      .raw_after(sql::SelectClause::Select, "'from'")
+      .inner_join("orders on orders.user_id = users.id");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Select(sql::SelectClause::From),
+      required_by: sql::Clause::Select(sql::SelectClause::Join),
+    };
+
+    assert_eq!(select.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_accept_the_raw_sql_added_at_the_missing_clause() {
+    let select = sql::Select::new()
+      .raw_before(sql::SelectClause::From, "select id")
+      .from("users");
+
+    assert_eq!(select.validate(), Ok(()));
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_it_is_valid() {
+    let query = sql::Select::new()
+      .select("id")
+      .from("users")
+      .limit("10")
+      .try_as_string();
+
+    assert_eq!(query, Ok("SELECT id FROM users LIMIT 10".to_owned()));
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_error_when_the_query_is_invalid() {
+    let error = sql::Select::new()
+      .select("id")
+      .from("users")
+      .limit("abc")
+      .try_as_string()
+      .unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "the value `abc` is not valid for the clause SelectClause::Limit"
+    );
+  }
+}
+
+mod insert_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_validate_should_return_an_error_when_values_and_select_are_defined() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .select(sql::Select::new().select("login").from("users_bk"));
+    let expected = sql::BuildError::ConflictingClauses {
+      clause: sql::Clause::Insert(sql::InsertClause::Values),
+      other: sql::Clause::Insert(sql::InsertClause::Select),
+    };
+
+    assert_eq!(insert.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_insert_into_clause_is_missing() {
+    let insert = sql::Insert::new().values("('foo')");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Insert(sql::InsertClause::InsertInto),
+      required_by: sql::Clause::Insert(sql::InsertClause::Values),
+    };
+
+    assert_eq!(insert.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_values_clause_is_missing() {
+    let insert = sql::Insert::new().insert_into("users (login)");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Insert(sql::InsertClause::Values),
+      required_by: sql::Clause::Insert(sql::InsertClause::InsertInto),
+    };
+
+    assert_eq!(insert.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_validate_the_select_of_the_insert() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .select(sql::Select::new().select("login").from("users_bk").limit("all of them"));
+    let expected = sql::BuildError::InvalidValue {
+      clause: sql::Clause::Select(sql::SelectClause::Limit),
+      value: "all of them".to_owned(),
+    };
+
+    assert_eq!(insert.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_query_when_it_is_valid() {
+    let query = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .try_as_string();
+
+    assert_eq!(query, Ok("INSERT INTO users (login) VALUES ('foo')".to_owned()));
+  }
+}
+
+mod update_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_update_clause_is_missing() {
+    let update = sql::Update::new().set("login = 'foo'").where_clause("id = 1");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Update(sql::UpdateClause::Update),
+      required_by: sql::Clause::Update(sql::UpdateClause::Set),
+    };
+
+    assert_eq!(update.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_set_clause_is_missing() {
+    let update = sql::Update::new().update("users").where_clause("id = 1");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Update(sql::UpdateClause::Set),
+      required_by: sql::Clause::Update(sql::UpdateClause::Update),
+    };
+
+    assert_eq!(update.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_not_report_missing_clauses_when_the_query_has_raw_sql() {
+    let update = sql::Update::new()
+      .update("users")
+      .raw_after(sql::UpdateClause::Update, "set login = 'foo'");
+
+    assert_eq!(update.validate(), Ok(()));
+  }
+
+  #[test]
+  fn method_validate_should_report_the_missing_set_clause_when_the_raw_sql_does_not_define_it() {
+    let update = sql::Update::new()
+      .update("users")
+      .raw_after(sql::UpdateClause::Where, "-- deactivate")
+      .where_clause("id = 1");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Update(sql::UpdateClause::Set),
+      required_by: sql::Clause::Update(sql::UpdateClause::Update),
+    };
+
+    assert_eq!(update.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_try_as_string_should_return_the_error_when_the_query_is_invalid() {
+    let error = sql::Update::new().set("login = 'foo'").try_as_string().unwrap_err();
+
+    assert_eq!(
+      error.to_string(),
+      "the clause UpdateClause::Update is required by the clause UpdateClause::Set"
+    );
+  }
+}
+
+mod delete_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_validate_should_return_an_error_when_the_delete_from_clause_is_missing() {
+    let delete = sql::Delete::new().where_clause("login = 'foo'");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::Delete(sql::DeleteClause::DeleteFrom),
+      required_by: sql::Clause::Delete(sql::DeleteClause::Where),
+    };
+
+    assert_eq!(delete.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_should_accept_a_complete_query() {
+    let delete = sql::Delete::new().delete_from("users").where_clause("login = 'foo'");
+
+    assert_eq!(delete.validate(), Ok(()));
+  }
+}
+
+mod ddl_validate {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_validate_of_create_table_should_return_an_error_when_the_definitions_are_missing() {
+    let create_table = sql::CreateTable::new().create_table("users");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::CreateTable(sql::CreateTableClause::Column),
+      required_by: sql::Clause::CreateTable(sql::CreateTableClause::CreateTable),
+    };
+
+    assert_eq!(create_table.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_of_create_table_should_accept_a_table_with_only_constraints() {
+    let create_table = sql::CreateTable::new()
+      .create_table("users_roles")
+      .primary_key("(user_id, role_id)");
+
+    assert_eq!(create_table.validate(), Ok(()));
+  }
+
+  #[test]
+  fn method_validate_of_alter_table_should_return_an_error_when_the_table_is_missing() {
+    let alter_table = sql::AlterTable::new().drop_column("age");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::AlterTable(sql::AlterTableClause::AlterTable),
+      required_by: sql::Clause::AlterTable(sql::AlterTableClause::DropColumn),
+    };
+
+    assert_eq!(alter_table.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_validate_of_create_index_should_return_an_error_when_the_columns_are_missing() {
+    let create_index = sql::CreateIndex::new().create_index("users_login_idx").on("users");
+    let expected = sql::BuildError::MissingClause {
+      clause: sql::Clause::CreateIndex(sql::CreateIndexClause::Column),
+      required_by: sql::Clause::CreateIndex(sql::CreateIndexClause::On),
+    };
+
+    assert_eq!(create_index.validate(), Err(expected));
+  }
+
+  #[test]
+  fn method_try_as_string_of_the_builders_without_dependent_clauses_should_always_succeed() {
+    assert_eq!(
+      sql::DropTable::new().drop_table("users").try_as_string(),
+      Ok("DROP TABLE users".to_owned())
+    );
+    assert_eq!(
+      sql::DropIndex::new().drop_index("users_login_idx").try_as_string(),
+      Ok("DROP INDEX users_login_idx".to_owned())
+    );
+    assert_eq!(
+      sql::Values::new().values("(1, 'one')").try_as_string(),
+      Ok("VALUES (1, 'one')".to_owned())
+    );
+  }
+}